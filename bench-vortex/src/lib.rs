@@ -24,6 +24,7 @@ use vortex_sampling_compressor::compressors::alp_rd::ALPRDCompressor;
 use vortex_sampling_compressor::compressors::bitpacked::BITPACK_WITH_PATCHES;
 use vortex_sampling_compressor::compressors::date_time_parts::DateTimePartsCompressor;
 use vortex_sampling_compressor::compressors::dict::DictCompressor;
+use vortex_sampling_compressor::compressors::extension::ExtensionCompressor;
 use vortex_sampling_compressor::compressors::fsst::FSSTCompressor;
 use vortex_sampling_compressor::compressors::r#for::FoRCompressor;
 use vortex_sampling_compressor::compressors::roaring_bool::RoaringBoolCompressor;
@@ -54,6 +55,7 @@ lazy_static! {
         &ALPCompressor as CompressorRef<'static>,
         &ALPRDCompressor,
         &DictCompressor,
+        &ExtensionCompressor,
         &BITPACK_WITH_PATCHES,
         &FoRCompressor,
         &FSSTCompressor,
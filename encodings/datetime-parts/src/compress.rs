@@ -11,11 +11,15 @@ pub struct TemporalParts {
     pub subseconds: Array,
 }
 
-/// Compress a `TemporalArray` into day, second, and subsecond components.
+/// Compress a timestamp or duration `TemporalArray` into day, second, and subsecond components.
 ///
 /// Splitting the components by granularity creates more small values, which enables better
 /// cascading compression.
 pub fn split_temporal(array: TemporalArray) -> VortexResult<TemporalParts> {
+    let Some(time_unit) = array.temporal_metadata().time_unit() else {
+        vortex_bail!(InvalidArgument: "Cannot compress interval data");
+    };
+
     let temporal_values = array.temporal_values().into_primitive()?;
     let validity = temporal_values.validity().clone();
 
@@ -26,7 +30,7 @@ pub fn split_temporal(array: TemporalArray) -> VortexResult<TemporalParts> {
     )?
     .as_primitive();
 
-    let divisor = match array.temporal_metadata().time_unit() {
+    let divisor = match time_unit {
         TimeUnit::Ns => 1_000_000_000,
         TimeUnit::Us => 1_000_000,
         TimeUnit::Ms => 1_000,
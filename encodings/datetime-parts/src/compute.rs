@@ -1,17 +1,23 @@
 use itertools::Itertools as _;
-use vortex::array::{PrimitiveArray, TemporalArray};
+use vortex::array::{ConstantArray, PrimitiveArray, TemporalArray};
 use vortex::compute::unary::{scalar_at, ScalarAtFn};
-use vortex::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
+use vortex::compute::{
+    and, compare, or, slice, take, ArrayCompute, MaybeCompareFn, Operator, SliceFn, TakeFn,
+};
 use vortex::validity::ArrayValidity;
 use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
 use vortex_datetime_dtype::{TemporalMetadata, TimeUnit};
 use vortex_dtype::{DType, PType};
 use vortex_error::{vortex_bail, VortexResult, VortexUnwrap as _};
-use vortex_scalar::Scalar;
+use vortex_scalar::{ExtScalar, Scalar};
 
 use crate::DateTimePartsArray;
 
 impl ArrayCompute for DateTimePartsArray {
+    fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        MaybeCompareFn::maybe_compare(self, other, operator)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -25,6 +31,93 @@ impl ArrayCompute for DateTimePartsArray {
     }
 }
 
+impl MaybeCompareFn for DateTimePartsArray {
+    /// Compare against a constant by splitting it into parts and comparing the parts
+    /// lexicographically, which orders them like the values they encode.
+    fn maybe_compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        let value = ConstantArray::try_from(other)
+            .ok()
+            .map(|c| c.owned_scalar())
+            .filter(|s| !s.is_null())?;
+        let DType::Extension(ext, _) = self.dtype() else {
+            return None;
+        };
+        let (TemporalMetadata::Timestamp(time_unit, _) | TemporalMetadata::Duration(time_unit)) =
+            TemporalMetadata::try_from(ext).ok()?
+        else {
+            return None;
+        };
+        let value = ExtScalar::try_from(&value)
+            .ok()?
+            .value()
+            .as_pvalue()
+            .ok()
+            .flatten()
+            .and_then(|p| i64::try_from(p).ok())?;
+        let divisor = match time_unit {
+            TimeUnit::Ns => 1_000_000_000,
+            TimeUnit::Us => 1_000_000,
+            TimeUnit::Ms => 1_000,
+            TimeUnit::S => 1,
+            TimeUnit::D => return None,
+        };
+
+        // Same split as `split_temporal`
+        let parts = [
+            (self.days(), value / (86_400 * divisor)),
+            (self.seconds(), (value % (86_400 * divisor)) / divisor),
+            (self.subsecond(), value % divisor),
+        ];
+        // Parts that don't fit the type of their column are left to the decoded values
+        let parts = parts
+            .into_iter()
+            .map(|(array, part)| {
+                Scalar::primitive(part, array.dtype().nullability())
+                    .cast(array.dtype())
+                    .ok()
+                    .map(|part| (array, part))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(compare_parts(&parts, operator))
+    }
+}
+
+/// Lexicographically compare the parts of a value, the most significant part first.
+fn compare_parts(parts: &[(Array, Scalar)], operator: Operator) -> VortexResult<Array> {
+    let Some(((array, part), rest)) = parts.split_first() else {
+        vortex_bail!("Can't compare without any parts");
+    };
+    let part = ConstantArray::new(part.clone(), array.len());
+    if rest.is_empty() {
+        return compare(array, part, operator);
+    }
+
+    match operator {
+        Operator::Eq => and(
+            compare(array, part, Operator::Eq)?,
+            compare_parts(rest, operator)?,
+        ),
+        Operator::NotEq => or(
+            compare(array, part, Operator::NotEq)?,
+            compare_parts(rest, operator)?,
+        ),
+        Operator::Gt | Operator::Gte | Operator::Lt | Operator::Lte => {
+            let strict = match operator {
+                Operator::Gt | Operator::Gte => Operator::Gt,
+                _ => Operator::Lt,
+            };
+            or(
+                compare(array, &part, strict)?,
+                and(
+                    compare(array, part, Operator::Eq)?,
+                    compare_parts(rest, operator)?,
+                )?,
+            )
+        }
+    }
+}
+
 impl TakeFn for DateTimePartsArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
         Ok(Self::try_new(
@@ -58,8 +151,10 @@ impl ScalarAtFn for DateTimePartsArray {
             );
         };
 
-        let TemporalMetadata::Timestamp(time_unit, _) = TemporalMetadata::try_from(&ext)? else {
-            vortex_bail!("Metadata must be Timestamp, found {}", ext.id());
+        let (TemporalMetadata::Timestamp(time_unit, _) | TemporalMetadata::Duration(time_unit)) =
+            TemporalMetadata::try_from(&ext)?
+        else {
+            vortex_bail!("Metadata must be Timestamp or Duration, found {}", ext.id());
         };
 
         if !self.is_valid(index) {
@@ -94,7 +189,7 @@ impl ScalarAtFn for DateTimePartsArray {
     }
 }
 
-/// Decode an [Array] into a [TemporalArray].
+/// Decode an [Array] into a timestamp or duration [TemporalArray].
 ///
 /// Enforces that the passed array is actually a [DateTimePartsArray] with proper metadata.
 pub fn decode_to_temporal(array: &DateTimePartsArray) -> VortexResult<TemporalArray> {
@@ -106,7 +201,13 @@ pub fn decode_to_temporal(array: &DateTimePartsArray) -> VortexResult<TemporalAr
         vortex_bail!(ComputeError: "must decode TemporalMetadata from extension metadata");
     };
 
-    let divisor = match temporal_metadata.time_unit() {
+    let (TemporalMetadata::Timestamp(time_unit, _) | TemporalMetadata::Duration(time_unit)) =
+        temporal_metadata
+    else {
+        vortex_bail!(ComputeError: "can only decode timestamps and durations, found {}", ext.id());
+    };
+
+    let divisor = match time_unit {
         TimeUnit::Ns => 1_000_000_000,
         TimeUnit::Us => 1_000_000,
         TimeUnit::Ms => 1_000,
@@ -126,20 +227,26 @@ pub fn decode_to_temporal(array: &DateTimePartsArray) -> VortexResult<TemporalAr
         .map(|((d, s), ss)| d * 86_400 * divisor + s * divisor + ss)
         .collect::<Vec<_>>();
 
-    Ok(TemporalArray::new_timestamp(
-        PrimitiveArray::from_vec(values, array.validity().clone()).into_array(),
-        temporal_metadata.time_unit(),
-        temporal_metadata.time_zone().map(ToString::to_string),
-    ))
+    let values = PrimitiveArray::from_vec(values, array.validity().clone()).into_array();
+    Ok(match temporal_metadata {
+        TemporalMetadata::Duration(_) => TemporalArray::new_duration(values, time_unit),
+        _ => TemporalArray::new_timestamp(
+            values,
+            time_unit,
+            temporal_metadata.time_zone().map(ToString::to_string),
+        ),
+    })
 }
 
 #[cfg(test)]
 mod test {
-    use vortex::array::{PrimitiveArray, TemporalArray};
-    use vortex::validity::Validity;
+    use vortex::array::{ConstantArray, PrimitiveArray, TemporalArray};
+    use vortex::compute::{compare, Operator};
+    use vortex::validity::{ArrayValidity, Validity};
     use vortex::{IntoArray, IntoArrayVariant};
     use vortex_datetime_dtype::TimeUnit;
-    use vortex_dtype::DType;
+    use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
 
     use crate::compute::decode_to_temporal;
     use crate::{split_temporal, DateTimePartsArray, TemporalParts};
@@ -204,4 +311,114 @@ mod test {
         assert_eq!(primitive_values.maybe_null_slice::<i64>(), raw_values);
         assert_eq!(primitive_values.validity(), validity);
     }
+
+    #[test]
+    fn test_roundtrip_duration() {
+        let raw_values = vec![-86_400_001i64, 0, 90_061_001];
+        let duration = TemporalArray::new_duration(
+            PrimitiveArray::from(raw_values.clone()).into_array(),
+            TimeUnit::Ms,
+        );
+
+        let TemporalParts {
+            days,
+            seconds,
+            subseconds,
+        } = split_temporal(duration.clone()).unwrap();
+        let parts = DateTimePartsArray::try_new(
+            DType::Extension(duration.ext_dtype().clone(), Nullability::NonNullable),
+            days,
+            seconds,
+            subseconds,
+        )
+        .unwrap();
+
+        let decoded = decode_to_temporal(&parts).unwrap();
+        assert_eq!(decoded.temporal_metadata(), duration.temporal_metadata());
+        assert_eq!(
+            decoded
+                .temporal_values()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i64>(),
+            raw_values.as_slice()
+        );
+    }
+
+    #[test]
+    fn compare_parts() {
+        let raw_values = vec![
+            -86_400_001i64,
+            -1,
+            0,
+            1_000,
+            86_400_000,
+            90_061_001,
+            90_061_002,
+        ];
+        let validity = Validity::from(vec![true, true, false, true, true, true, true]);
+        let timestamps = TemporalArray::new_timestamp(
+            PrimitiveArray::from_vec(raw_values.clone(), validity.clone()).into_array(),
+            TimeUnit::Ms,
+            None,
+        );
+        let TemporalParts {
+            days,
+            seconds,
+            subseconds,
+        } = split_temporal(timestamps.clone()).unwrap();
+        let parts = DateTimePartsArray::try_new(
+            DType::Extension(timestamps.ext_dtype().clone(), Nullability::Nullable),
+            days,
+            seconds,
+            subseconds,
+        )
+        .unwrap()
+        .into_array();
+
+        for literal in [
+            -86_400_001i64,
+            -5,
+            0,
+            1_000,
+            90_061_001,
+            90_061_003,
+            1_000 * 86_400_000,
+        ] {
+            let constant = ConstantArray::new(
+                Scalar::extension(
+                    timestamps.ext_dtype().clone(),
+                    Scalar::primitive(literal, Nullability::NonNullable),
+                ),
+                parts.len(),
+            );
+            for operator in [
+                Operator::Eq,
+                Operator::NotEq,
+                Operator::Gt,
+                Operator::Gte,
+                Operator::Lt,
+                Operator::Lte,
+            ] {
+                let compared = compare(&parts, &constant, operator)
+                    .unwrap()
+                    .into_bool()
+                    .unwrap();
+                let expected = raw_values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| validity.is_valid(i).then(|| operator.to_fn()(*v, literal)))
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    (0..parts.len())
+                        .map(|i| compared
+                            .is_valid(i)
+                            .then(|| compared.boolean_buffer().value(i)))
+                        .collect::<Vec<_>>(),
+                    expected,
+                    "{operator} {literal}"
+                );
+            }
+        }
+    }
 }
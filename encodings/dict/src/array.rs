@@ -1,19 +1,14 @@
 use std::fmt::{Debug, Display};
 
-use arrow_buffer::BooleanBuffer;
 use serde::{Deserialize, Serialize};
 use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
-use vortex::array::BoolArray;
 use vortex::compute::take;
 use vortex::compute::unary::scalar_at;
 use vortex::encoding::ids;
 use vortex::stats::StatsSet;
 use vortex::validity::{ArrayValidity, LogicalValidity};
-use vortex::{
-    impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArray, IntoArrayVariant,
-    IntoCanonical,
-};
-use vortex_dtype::{match_each_integer_ptype, DType, PType};
+use vortex::{impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArray, IntoCanonical};
+use vortex_dtype::{DType, PType};
 use vortex_error::{vortex_bail, vortex_panic, VortexExpect as _, VortexResult};
 
 impl_encoding!("vortex.dict", ids::DICT, Dict);
@@ -96,18 +91,15 @@ impl ArrayValidity for DictArray {
 
     fn logical_validity(&self) -> LogicalValidity {
         if self.dtype().is_nullable() {
-            let primitive_codes = self
-                .codes()
-                .into_primitive()
-                .vortex_expect("Failed to convert DictArray codes to primitive array");
-            match_each_integer_ptype!(primitive_codes.ptype(), |$P| {
-                let is_valid = primitive_codes
-                    .maybe_null_slice::<$P>();
-                let is_valid_buffer = BooleanBuffer::collect_bool(is_valid.len(), |idx| {
-                    is_valid[idx] != 0
-                });
-                LogicalValidity::Array(BoolArray::from(is_valid_buffer).into_array())
-            })
+            // Any value may be null, so expand the validity of the values through the codes
+            let values_validity = self
+                .values()
+                .with_dyn(|a| a.logical_validity())
+                .into_array();
+            LogicalValidity::Array(
+                take(values_validity, self.codes())
+                    .vortex_expect("Failed to take DictArray values validity with codes"),
+            )
         } else {
             LogicalValidity::AllValid(self.len())
         }
@@ -0,0 +1,327 @@
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    ArrowDictionaryKeyType, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type, UInt32Type,
+    UInt64Type, UInt8Type,
+};
+use arrow_array::{
+    new_null_array, Array as ArrowArray, ArrayRef as ArrowArrayRef, DictionaryArray,
+    PrimitiveArray as ArrowPrimitiveArray, RecordBatch,
+};
+use arrow_buffer::{NullBuffer, ScalarBuffer};
+use arrow_schema::DataType;
+use num_traits::AsPrimitive;
+use vortex::array::{PrimitiveArray, StructArray};
+use vortex::arrow::FromArrowArray;
+use vortex::validity::{ArrayValidity, Validity};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
+use vortex_dtype::{match_each_unsigned_integer_ptype, NativePType, PType};
+use vortex_error::{vortex_bail, vortex_panic, VortexError, VortexExpect as _, VortexResult};
+
+use crate::{DictArray, NULL_CODE};
+
+impl<K: ArrowDictionaryKeyType> FromArrowArray<&DictionaryArray<K>> for DictArray
+where
+    K::Native: NativePType + AsPrimitive<u64>,
+{
+    /// Convert an Arrow dictionary array without decoding it.
+    ///
+    /// The codes keep the width of the Arrow keys. Nullable dictionaries reserve [`NULL_CODE`]
+    /// for null values, so a null is prepended to the Arrow values and every other code is shifted
+    /// by one, widening the codes only if the shifted codes no longer fit. Keys pointing at null
+    /// values are mapped to the null code as well.
+    fn from_arrow(value: &DictionaryArray<K>, nullable: bool) -> Self {
+        let keys = value.keys();
+        let values = value.values();
+
+        if !nullable {
+            assert!(
+                keys.null_count() == 0 && values.null_count() == 0,
+                "Non-nullable Arrow dictionary contains nulls"
+            );
+            // Valid keys are never negative, so signed keys can be reinterpreted as unsigned.
+            let codes = PrimitiveArray::new(
+                keys.values().inner().clone().into(),
+                K::Native::PTYPE,
+                Validity::NonNullable,
+            )
+            .reinterpret_cast(K::Native::PTYPE.to_unsigned());
+            return DictArray::try_new(
+                codes.into_array(),
+                Array::from_arrow(values.clone(), false),
+            )
+            .vortex_expect("Failed to convert Arrow DictionaryArray to Vortex DictArray");
+        }
+
+        let code_ptype = shifted_code_ptype(K::Native::PTYPE, values.len());
+        let codes = match_each_unsigned_integer_ptype!(code_ptype, |$C| {
+            shifted_codes::<K, $C>(keys, values.as_ref())
+        });
+        let values = arrow_select::concat::concat(&[
+            new_null_array(values.data_type(), 1).as_ref(),
+            values.as_ref(),
+        ])
+        .map_err(VortexError::from)
+        .vortex_expect("Failed to prepend null to Arrow dictionary values");
+
+        DictArray::try_new(codes.into_array(), Array::from_arrow(values, true))
+            .vortex_expect("Failed to convert Arrow DictionaryArray to Vortex DictArray")
+    }
+}
+
+/// The narrowest unsigned type at least as wide as the keys that can hold codes up to `max_code`.
+fn shifted_code_ptype(key_ptype: PType, max_code: usize) -> PType {
+    [PType::U8, PType::U16, PType::U32]
+        .into_iter()
+        .filter(|ptype| ptype.byte_width() >= key_ptype.byte_width())
+        .find(|ptype| {
+            let bits = ptype.bit_width() as u32;
+            (max_code as u64) < (1u64 << bits)
+        })
+        .unwrap_or(PType::U64)
+}
+
+fn shifted_codes<K, C>(keys: &ArrowPrimitiveArray<K>, values: &dyn ArrowArray) -> PrimitiveArray
+where
+    K: ArrowDictionaryKeyType,
+    K::Native: AsPrimitive<u64>,
+    C: NativePType + 'static,
+    u64: AsPrimitive<C>,
+{
+    let null_code: C = NULL_CODE.as_();
+    PrimitiveArray::from_vec(
+        keys.iter()
+            .map(|k| match k {
+                Some(k) if values.is_valid(k.as_() as usize) => (k.as_() + 1).as_(),
+                _ => null_code,
+            })
+            .collect::<Vec<C>>(),
+        Validity::NonNullable,
+    )
+}
+
+/// Convert an Arrow array into a Vortex array, keeping dictionary-encoded arrays as a
+/// [`DictArray`] rather than decoding them as [`FromArrowArray`] for [`Array`] does.
+pub fn from_arrow_preserving_dict(array: ArrowArrayRef, nullable: bool) -> Array {
+    macro_rules! dict {
+        ($K:ty) => {
+            DictArray::from_arrow(array.as_dictionary::<$K>(), nullable).into_array()
+        };
+    }
+
+    match array.data_type() {
+        DataType::Dictionary(key_type, _) => match key_type.as_ref() {
+            DataType::Int8 => dict!(Int8Type),
+            DataType::Int16 => dict!(Int16Type),
+            DataType::Int32 => dict!(Int32Type),
+            DataType::Int64 => dict!(Int64Type),
+            DataType::UInt8 => dict!(UInt8Type),
+            DataType::UInt16 => dict!(UInt16Type),
+            DataType::UInt32 => dict!(UInt32Type),
+            DataType::UInt64 => dict!(UInt64Type),
+            _ => vortex_panic!("Invalid dictionary key type {}", key_type),
+        },
+        _ => Array::from_arrow(array, nullable),
+    }
+}
+
+/// Convert an Arrow [`RecordBatch`] into a Vortex struct array, keeping dictionary-encoded
+/// columns as [`DictArray`]s.
+pub fn record_batch_preserving_dict(batch: RecordBatch) -> VortexResult<Array> {
+    Ok(StructArray::try_new(
+        batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str().into())
+            .collect::<Vec<_>>()
+            .into(),
+        batch
+            .columns()
+            .iter()
+            .zip(batch.schema().fields())
+            .map(|(array, field)| from_arrow_preserving_dict(array.clone(), field.is_nullable()))
+            .collect(),
+        batch.num_rows(),
+        Validity::NonNullable, // Must match FromArrowType<SchemaRef> for DType
+    )?
+    .into_array())
+}
+
+impl DictArray {
+    /// Convert into an Arrow [`DictionaryArray`] without decoding the values.
+    ///
+    /// The keys use the unsigned integer type of the codes, and the values are the canonical Arrow
+    /// form of the dictionary values.
+    pub fn into_arrow_dictionary(self) -> VortexResult<ArrowArrayRef> {
+        let codes = self.codes().into_primitive()?;
+        let nulls = self
+            .dtype()
+            .is_nullable()
+            .then(|| self.logical_validity().to_null_buffer())
+            .transpose()?
+            .flatten();
+        let values = self.values().into_canonical()?.into_arrow()?;
+
+        match codes.ptype() {
+            PType::U8 => dictionary::<UInt8Type>(codes, nulls, values),
+            PType::U16 => dictionary::<UInt16Type>(codes, nulls, values),
+            PType::U32 => dictionary::<UInt32Type>(codes, nulls, values),
+            PType::U64 => dictionary::<UInt64Type>(codes, nulls, values),
+            ptype => vortex_bail!("Invalid dictionary codes type {}", ptype),
+        }
+    }
+}
+
+fn dictionary<K: ArrowDictionaryKeyType>(
+    codes: PrimitiveArray,
+    nulls: Option<NullBuffer>,
+    values: ArrowArrayRef,
+) -> VortexResult<ArrowArrayRef> {
+    let len = codes.len();
+    let keys = ArrowPrimitiveArray::<K>::new(
+        ScalarBuffer::new(codes.into_buffer().into_arrow(), 0, len),
+        nulls,
+    );
+    Ok(Arc::new(DictionaryArray::<K>::try_new(keys, values)?))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, Int8Type, UInt32Type, UInt8Type};
+    use arrow_array::{
+        Array as _, ArrayRef, DictionaryArray, Int32Array, RecordBatch, StringArray, UInt8Array,
+    };
+    use arrow_schema::{DataType, Field, Schema};
+    use vortex::accessor::ArrayAccessor;
+    use vortex::arrow::FromArrowArray;
+    use vortex::{Array, ArrayDType, IntoArrayVariant, IntoCanonical};
+    use vortex_dtype::PType;
+
+    use crate::{from_arrow_preserving_dict, record_batch_preserving_dict, DictArray};
+
+    fn arrow_dict() -> DictionaryArray<Int32Type> {
+        DictionaryArray::new(
+            Int32Array::from(vec![Some(1), None, Some(0), Some(2), Some(1)]),
+            Arc::new(StringArray::from(vec![Some("a"), Some("b"), None])),
+        )
+    }
+
+    fn strings(array: Array) -> Vec<Option<String>> {
+        array
+            .into_canonical()
+            .unwrap()
+            .into_varbinview()
+            .unwrap()
+            .with_iterator(|iter| {
+                iter.map(|s| s.map(|b| String::from_utf8(b.to_vec()).unwrap()))
+                    .collect()
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn import_nullable_dict() {
+        let dict = DictArray::from_arrow(&arrow_dict(), true);
+        assert_eq!(dict.values().len(), 4);
+        assert_eq!(
+            dict.codes()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u32>(),
+            &[2, 0, 1, 0, 2]
+        );
+        assert_eq!(
+            strings(dict.into()),
+            vec![
+                Some("b".to_string()),
+                None,
+                Some("a".to_string()),
+                None,
+                Some("b".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn import_non_nullable_dict() {
+        let arrow: DictionaryArray<Int32Type> = vec!["x", "y", "x"].into_iter().collect();
+        let array = from_arrow_preserving_dict(Arc::new(arrow.clone()), false);
+        assert!(DictArray::try_from(&array).is_ok());
+        assert!(!array.dtype().is_nullable());
+        assert_eq!(
+            strings(array),
+            vec![
+                Some("x".to_string()),
+                Some("y".to_string()),
+                Some("x".to_string())
+            ]
+        );
+
+        // Without vortex-dict, dictionaries are decoded.
+        let decoded = Array::from_arrow(Arc::new(arrow) as ArrayRef, false);
+        assert!(DictArray::try_from(&decoded).is_err());
+        assert_eq!(strings(decoded).len(), 3);
+    }
+
+    #[test]
+    fn import_keeps_key_width() {
+        let arrow: DictionaryArray<Int8Type> = vec!["x", "y", "x"].into_iter().collect();
+        let dict = DictArray::from_arrow(&arrow, false);
+        assert_eq!(
+            dict.codes()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u8>(),
+            &[0, 1, 0]
+        );
+
+        // 255 values still fit into u8 codes once shifted for the null code, 256 don't.
+        for (num_values, ptype) in [(255, PType::U8), (256, PType::U16)] {
+            let arrow = DictionaryArray::<UInt8Type>::new(
+                UInt8Array::from(vec![0, 254]),
+                Arc::new(Int32Array::from_iter_values(0..num_values)),
+            );
+            let dict = DictArray::from_arrow(&arrow, true);
+            let codes = dict.codes().into_primitive().unwrap();
+            assert_eq!(codes.ptype(), ptype);
+            assert_eq!(codes.len(), 2);
+        }
+    }
+
+    #[test]
+    fn export_dict() {
+        let dict = DictArray::from_arrow(&arrow_dict(), true);
+        let arrow = dict.into_arrow_dictionary().unwrap();
+        let arrow = arrow.as_dictionary::<UInt32Type>();
+        assert_eq!(arrow.len(), 5);
+        assert_eq!(arrow.null_count(), 2);
+        let decoded = arrow_cast::cast(arrow, &DataType::Utf8).unwrap();
+        assert_eq!(
+            decoded.as_string::<i32>().iter().collect::<Vec<_>>(),
+            vec![Some("b"), None, Some("a"), None, Some("b")]
+        );
+    }
+
+    #[test]
+    fn record_batch_with_dict() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new(
+                "col",
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                true,
+            )])),
+            vec![Arc::new(arrow_dict())],
+        )
+        .unwrap();
+        let array = record_batch_preserving_dict(batch).unwrap();
+        let field = array
+            .with_dyn(|a| a.as_struct_array().and_then(|s| s.field(0)))
+            .unwrap();
+        assert!(DictArray::try_from(&field).is_ok());
+    }
+}
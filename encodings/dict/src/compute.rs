@@ -1,12 +1,52 @@
+use vortex::array::{BoolArray, ConstantArray, PrimitiveArray};
 use vortex::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
-use vortex::compute::{filter, slice, take, ArrayCompute, FilterFn, SliceFn, TakeFn};
-use vortex::{Array, IntoArray};
-use vortex_error::{VortexExpect, VortexResult};
+use vortex::compute::{
+    argsort, arithmetic, compare, filter, like, min_max, slice, sort, take, ArgsortFn,
+    ArithmeticOperator, ArithmeticOptions, ArrayCompute, CountValidFn, FilterFn, LikeFn,
+    LikeOptions, MaybeCompareFn, MinMaxFn, MinMaxResult, Operator, SliceFn, SortFn, SortOptions,
+    SumAccumulator, SumFn, TakeFn,
+};
+use vortex::validity::ArrayValidity;
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::{match_each_integer_ptype, match_each_native_ptype};
+use vortex_error::{vortex_err, VortexExpect, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::DictArray;
 
 impl ArrayCompute for DictArray {
+    fn arithmetic(
+        &self,
+        other: &Array,
+        operator: ArithmeticOperator,
+        options: ArithmeticOptions,
+    ) -> Option<VortexResult<Array>> {
+        // Apply the operation to each distinct value once, keeping the codes
+        let constant = ConstantArray::try_from(other).ok()?;
+        Some(
+            arithmetic(
+                self.values(),
+                ConstantArray::new(constant.owned_scalar(), self.values().len()),
+                operator,
+                options,
+            )
+            .and_then(|values| Self::try_new(self.codes(), values))
+            .map(|a| a.into_array()),
+        )
+    }
+
+    fn argsort(&self) -> Option<&dyn ArgsortFn> {
+        Some(self)
+    }
+
+    fn count_valid(&self) -> Option<&dyn CountValidFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -15,6 +55,14 @@ impl ArrayCompute for DictArray {
         Some(self)
     }
 
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
@@ -22,6 +70,131 @@ impl ArrayCompute for DictArray {
     fn filter(&self) -> Option<&dyn FilterFn> {
         Some(self)
     }
+
+    fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        MaybeCompareFn::maybe_compare(self, other, operator)
+    }
+
+    fn like(&self) -> Option<&dyn LikeFn> {
+        Some(self)
+    }
+}
+
+impl MaybeCompareFn for DictArray {
+    fn maybe_compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        // Compare each distinct value once and expand the result through the codes
+        let constant = ConstantArray::try_from(other).ok()?;
+        Some(
+            compare(
+                self.values(),
+                ConstantArray::new(constant.owned_scalar(), self.values().len()),
+                operator,
+            )
+            .and_then(|compared| take(compared, self.codes())),
+        )
+    }
+}
+
+impl LikeFn for DictArray {
+    fn like(&self, pattern: &str, options: LikeOptions) -> VortexResult<Array> {
+        // Match each distinct value once and expand the result through the codes
+        take(like(self.values(), pattern, options)?, self.codes())
+    }
+}
+
+impl DictArray {
+    /// Number of times each value of the dictionary is referenced by the codes.
+    fn code_counts(&self) -> VortexResult<Vec<usize>> {
+        let codes = self.codes().into_primitive()?;
+        let num_values = self.values().len();
+        let mut counts = vec![0; num_values];
+        match_each_integer_ptype!(codes.ptype(), |$C| {
+            for &code in codes.maybe_null_slice::<$C>() {
+                *counts
+                    .get_mut(code as usize)
+                    .ok_or_else(|| vortex_err!(OutOfBounds: code as usize, 0, num_values))? += 1;
+            }
+        });
+        Ok(counts)
+    }
+
+    /// Sort the values, returning their order and the codes remapped to the sorted values.
+    ///
+    /// Ordering the remapped codes orders the elements of the array, with the null values ranked
+    /// where `options` places them.
+    fn sorted_codes(&self, options: SortOptions) -> VortexResult<(Array, Array)> {
+        let order = argsort(self.values(), options)?.into_primitive()?;
+        let mut ranks = vec![0u64; order.len()];
+        for (rank, &idx) in order.maybe_null_slice::<u64>().iter().enumerate() {
+            ranks[idx as usize] = rank as u64;
+        }
+        let codes = take(PrimitiveArray::from(ranks), self.codes())?;
+        Ok((order.into_array(), codes))
+    }
+}
+
+impl ArgsortFn for DictArray {
+    fn argsort(&self, options: SortOptions) -> VortexResult<Array> {
+        let (_, codes) = self.sorted_codes(options)?;
+        argsort(codes, SortOptions::default())
+    }
+}
+
+impl SortFn for DictArray {
+    fn sort(&self, options: SortOptions) -> VortexResult<Array> {
+        let (order, codes) = self.sorted_codes(options)?;
+        Self::try_new(
+            sort(codes, SortOptions::default())?,
+            take(self.values(), order)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl SumFn for DictArray {
+    fn sum(&self) -> VortexResult<Scalar> {
+        let values = self.values().into_primitive()?;
+        let mut acc = SumAccumulator::try_new(self.dtype())?;
+        match_each_native_ptype!(values.ptype(), |$T| {
+            for (idx, (value, count)) in values
+                .maybe_null_slice::<$T>()
+                .iter()
+                .zip(self.code_counts()?)
+                .enumerate()
+            {
+                if values.is_valid(idx) {
+                    acc.add_repeated(*value, count)?;
+                }
+            }
+        });
+        Ok(acc.finish())
+    }
+}
+
+impl MinMaxFn for DictArray {
+    fn min_max(&self) -> VortexResult<Option<MinMaxResult>> {
+        // Only consider the values that are referenced by the codes
+        let referenced = BoolArray::from(
+            self.code_counts()?
+                .into_iter()
+                .map(|count| count > 0)
+                .collect::<Vec<_>>(),
+        );
+        min_max(filter(self.values(), referenced.as_ref())?)
+    }
+}
+
+impl CountValidFn for DictArray {
+    fn count_valid(&self) -> VortexResult<usize> {
+        let values = self.values();
+        Ok(self
+            .code_counts()?
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| values.with_dyn(|a| a.is_valid(*idx)))
+            .map(|(_, count)| count)
+            .sum())
+    }
 }
 
 impl ScalarAtFn for DictArray {
@@ -66,12 +239,20 @@ impl SliceFn for DictArray {
 
 #[cfg(test)]
 mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
     use vortex::accessor::ArrayAccessor;
-    use vortex::array::{PrimitiveArray, VarBinViewArray};
-    use vortex::{IntoArray, IntoArrayVariant, ToArray};
+    use vortex::array::{ConstantArray, PrimitiveArray, VarBinViewArray};
+    use vortex::compute::unary::scalar_at;
+    use vortex::compute::{
+        argsort, arithmetic_scalar, compare, count_valid, like, min_max, slice, sort, sum, take,
+        ArithmeticOperator, ArithmeticOptions, LikeOptions, Operator, SortOptions,
+    };
+    use vortex::{ArrayDef, IntoArray, IntoArrayVariant, IntoCanonical, ToArray};
     use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
 
-    use crate::{dict_encode_typed_primitive, dict_encode_varbinview, DictArray};
+    use crate::{dict_encode_typed_primitive, dict_encode_varbinview, Dict, DictArray};
 
     #[test]
     fn flatten_nullable_primitive() {
@@ -112,4 +293,257 @@ mod test {
                 .unwrap(),
         );
     }
+
+    #[test]
+    fn compare_nullable_primitive() {
+        let reference = PrimitiveArray::from_nullable_vec(vec![
+            Some(42),
+            Some(-9),
+            None,
+            Some(42),
+            None,
+            Some(-9),
+        ]);
+        let (codes, values) = dict_encode_typed_primitive::<i32>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let compared = compare(
+            dict.as_ref(),
+            ConstantArray::new(Scalar::primitive(42, Nullability::Nullable), dict.len()),
+            Operator::Eq,
+        )
+        .unwrap();
+        assert_eq!(
+            (0..compared.len())
+                .map(|i| scalar_at(&compared, i).unwrap().value().as_bool().unwrap())
+                .collect::<Vec<_>>(),
+            vec![Some(true), Some(false), None, Some(true), None, Some(false)]
+        );
+    }
+
+    #[test]
+    fn like_nullable_varbin() {
+        let reference = VarBinViewArray::from_iter(
+            vec![
+                Some("apple"),
+                Some("banana"),
+                None,
+                Some("apple"),
+                Some("avocado"),
+            ],
+            DType::Utf8(Nullability::Nullable),
+        );
+        let (codes, values) = dict_encode_varbinview(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let matched = like(dict.as_ref(), "a%", LikeOptions::default()).unwrap();
+        assert_eq!(
+            (0..matched.len())
+                .map(|i| scalar_at(&matched, i).unwrap().value().as_bool().unwrap())
+                .collect::<Vec<_>>(),
+            vec![Some(true), Some(false), None, Some(true), Some(true)]
+        );
+    }
+
+    #[test]
+    fn arithmetic_nullable_primitive() {
+        let reference = PrimitiveArray::from_nullable_vec(vec![Some(42), Some(-9), None, Some(42)]);
+        let (codes, values) = dict_encode_typed_primitive::<i32>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let multiplied = arithmetic_scalar(
+            dict.as_ref(),
+            &2.into(),
+            ArithmeticOperator::Mul,
+            ArithmeticOptions::default(),
+        )
+        .unwrap();
+        assert!(multiplied.is_encoding(Dict::ID));
+        assert_eq!(
+            (0..multiplied.len())
+                .map(|i| i32::try_from(&scalar_at(&multiplied, i).unwrap()).ok())
+                .collect::<Vec<_>>(),
+            vec![Some(84), Some(-18), None, Some(84)]
+        );
+    }
+
+    #[test]
+    fn aggregates_nullable_primitive() {
+        let reference = PrimitiveArray::from_nullable_vec(vec![
+            Some(42),
+            Some(-9),
+            None,
+            Some(42),
+            Some(7),
+            None,
+        ]);
+        let (codes, values) = dict_encode_typed_primitive::<i32>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+
+        assert_eq!(
+            sum(dict.as_ref()).unwrap(),
+            Scalar::primitive(82i64, Nullability::Nullable)
+        );
+        assert_eq!(count_valid(dict.as_ref()).unwrap(), 4);
+        let result = min_max(dict.as_ref()).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(-9));
+        assert_eq!(result.max, Scalar::from(42));
+
+        // -9 and 7 remain in the dictionary but aren't referenced by the slice
+        let sliced = slice(dict.as_ref(), 2, 4).unwrap();
+        assert_eq!(
+            sum(&sliced).unwrap(),
+            Scalar::primitive(42i64, Nullability::Nullable)
+        );
+        assert_eq!(count_valid(&sliced).unwrap(), 1);
+        let result = min_max(&sliced).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(42));
+        assert_eq!(result.max, Scalar::from(42));
+    }
+
+    #[test]
+    fn sort_nullable_varbin() {
+        let reference = VarBinViewArray::from_iter_nullable_str([
+            Some("pear"),
+            None,
+            Some("apple"),
+            Some("pear"),
+            Some("fig"),
+        ]);
+        let (codes, values) = dict_encode_varbinview(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+
+        let sorted = sort(dict.as_ref(), options).unwrap();
+        assert_eq!(sorted.encoding().id(), Dict::ID);
+        assert_eq!(
+            (0..sorted.len())
+                .map(|i| {
+                    scalar_at(&sorted, i)
+                        .unwrap()
+                        .value()
+                        .as_buffer_string()
+                        .unwrap()
+                        .map(|s| s.to_string())
+                })
+                .collect::<Vec<_>>(),
+            vec![
+                Some("pear".to_string()),
+                Some("pear".to_string()),
+                Some("fig".to_string()),
+                Some("apple".to_string()),
+                None
+            ]
+        );
+
+        let indices = argsort(dict.as_ref(), options)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        let indices = indices.maybe_null_slice::<u64>();
+        assert!(indices == [0, 3, 4, 2, 1] || indices == [3, 0, 4, 2, 1]);
+    }
+
+    #[test]
+    fn sort_nullable_primitive() {
+        let reference =
+            PrimitiveArray::from_nullable_vec(vec![Some(5), None, Some(-2), None, Some(0)]);
+        let (codes, values) = dict_encode_typed_primitive::<i32>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+
+        for (options, expected) in [
+            (
+                SortOptions::default(),
+                vec![Some(-2), Some(0), Some(5), None, None],
+            ),
+            (
+                SortOptions {
+                    descending: true,
+                    nulls_first: true,
+                },
+                vec![None, None, Some(5), Some(0), Some(-2)],
+            ),
+        ] {
+            let sorted = sort(dict.as_ref(), options).unwrap();
+            assert_eq!(sorted.encoding().id(), Dict::ID);
+            assert_eq!(
+                sorted
+                    .with_dyn(|a| a.logical_validity())
+                    .into_array()
+                    .into_bool()
+                    .unwrap()
+                    .boolean_buffer()
+                    .iter()
+                    .collect::<Vec<_>>(),
+                expected.iter().map(Option::is_some).collect::<Vec<_>>()
+            );
+            let arrow = sorted.into_canonical().unwrap().into_arrow().unwrap();
+            assert_eq!(
+                arrow.as_primitive::<Int32Type>().iter().collect::<Vec<_>>(),
+                expected
+            );
+
+            let indices = argsort(dict.as_ref(), options).unwrap();
+            assert_eq!(
+                take(reference.as_ref(), &indices)
+                    .unwrap()
+                    .into_canonical()
+                    .unwrap()
+                    .into_arrow()
+                    .unwrap()
+                    .as_primitive::<Int32Type>()
+                    .iter()
+                    .collect::<Vec<_>>(),
+                expected
+            );
+        }
+    }
+    #[test]
+    fn sort_values_without_leading_null() {
+        // Neither value is null, and the null value of the second dictionary isn't the first one
+        for (codes, values, expected) in [
+            (
+                vec![0u32, 1, 0, 1],
+                vec![Some(5), Some(1)],
+                vec![Some(1), Some(1), Some(5), Some(5)],
+            ),
+            (
+                vec![0u32, 1, 2, 1],
+                vec![Some(5), None, Some(1)],
+                vec![Some(1), Some(5), None, None],
+            ),
+        ] {
+            let dict = DictArray::try_new(
+                PrimitiveArray::from(codes).into_array(),
+                PrimitiveArray::from_nullable_vec(values).into_array(),
+            )
+            .unwrap();
+
+            let sorted = sort(dict.as_ref(), SortOptions::default()).unwrap();
+            let arrow = sorted.into_canonical().unwrap().into_arrow().unwrap();
+            assert_eq!(
+                arrow.as_primitive::<Int32Type>().iter().collect::<Vec<_>>(),
+                expected
+            );
+
+            let options = SortOptions {
+                descending: true,
+                nulls_first: true,
+            };
+            let indices = argsort(dict.as_ref(), options).unwrap();
+            let reversed = take(dict.as_ref(), &indices)
+                .unwrap()
+                .into_canonical()
+                .unwrap()
+                .into_arrow()
+                .unwrap();
+            assert_eq!(
+                reversed
+                    .as_primitive::<Int32Type>()
+                    .iter()
+                    .collect::<Vec<_>>(),
+                expected.iter().rev().copied().collect::<Vec<_>>()
+            );
+        }
+    }
 }
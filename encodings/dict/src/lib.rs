@@ -3,9 +3,11 @@
 //! Expose a [DictArray] which is zero-copy equivalent to Arrow's
 //! [DictionaryArray](https://docs.rs/arrow/latest/arrow/array/struct.DictionaryArray.html).
 pub use array::*;
+pub use arrow::*;
 pub use compress::*;
 
 mod array;
+mod arrow;
 mod compress;
 mod compute;
 mod stats;
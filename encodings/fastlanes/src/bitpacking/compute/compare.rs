@@ -0,0 +1,175 @@
+use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder};
+use fastlanes::BitPacking;
+use num_traits::AsPrimitive;
+use vortex::array::{BoolArray, ConstantArray, SparseArray};
+use vortex::compute::{compare, MaybeCompareFn, Operator};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::{match_each_unsigned_integer_ptype, NativePType};
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::BitPackedArray;
+
+impl MaybeCompareFn for BitPackedArray {
+    fn maybe_compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        let value = ConstantArray::try_from(other)
+            .ok()
+            .map(|c| c.owned_scalar())
+            .filter(|s| !s.is_null())?;
+
+        // Literals that aren't representable in the array's type are left to the canonical compare
+        let value = value.cast(self.dtype()).ok()?;
+        let unsigned = match_each_unsigned_integer_ptype!(self.ptype(), |$P| {
+            <$P>::try_from(&value).ok()?.as_()
+        });
+
+        Some(compare_packed(self, &value, unsigned, operator))
+    }
+}
+
+/// Compare the packed values chunk by chunk without unpacking the whole array, then apply the
+/// comparison to the patches.
+fn compare_packed(
+    array: &BitPackedArray,
+    value: &Scalar,
+    unsigned: u64,
+    operator: Operator,
+) -> VortexResult<Array> {
+    let max_packed = match array.bit_width() {
+        0 => 0,
+        bit_width => u64::MAX >> (64 - bit_width as u32),
+    };
+
+    let compared = if unsigned <= max_packed {
+        match_each_unsigned_integer_ptype!(array.ptype(), |$P| {
+            compare_unpacked::<$P>(array, unsigned.as_(), operator)
+        })
+    } else {
+        // Every packed value is smaller than the literal, only patches can be equal or greater
+        let result = match operator {
+            Operator::Eq | Operator::Gt | Operator::Gte => false,
+            Operator::NotEq | Operator::Lt | Operator::Lte => true,
+        };
+        if result {
+            BooleanBuffer::new_set(array.len())
+        } else {
+            BooleanBuffer::new_unset(array.len())
+        }
+    };
+
+    let compared = match array.patches() {
+        None => compared,
+        Some(patches) => {
+            let patches = SparseArray::try_from(patches)?;
+            let patch_values = patches.values();
+            let patches_compared = compare(
+                &patch_values,
+                ConstantArray::new(value.clone(), patch_values.len()),
+                operator,
+            )?
+            .into_bool()?
+            .boolean_buffer();
+
+            let mut builder = BooleanBufferBuilder::new(array.len());
+            builder.append_buffer(&compared);
+            for (i, idx) in patches.resolved_indices().into_iter().enumerate() {
+                builder.set_bit(idx, patches_compared.value(i));
+            }
+            builder.finish()
+        }
+    };
+
+    Ok(BoolArray::try_new(compared, array.validity())?.into_array())
+}
+
+fn compare_unpacked<P: NativePType + BitPacking>(
+    array: &BitPackedArray,
+    value: P,
+    operator: Operator,
+) -> BooleanBuffer {
+    let bit_width = array.bit_width() as usize;
+    let offset = array.offset() as usize;
+    let length = array.len();
+    let packed = array.packed_slice::<P>();
+    let elems_per_chunk = 128 * bit_width / size_of::<P>();
+    let num_chunks = (offset + length + 1023) / 1024;
+    let cmp = operator.to_fn::<P>();
+
+    let mut builder = BooleanBufferBuilder::new(length);
+    let mut decoded = [P::zero(); 1024];
+    for i in 0..num_chunks {
+        if bit_width > 0 {
+            let chunk = &packed[i * elems_per_chunk..][0..elems_per_chunk];
+            // SAFETY: the chunk holds exactly the 1024 values packed with `bit_width` bits
+            unsafe { BitPacking::unchecked_unpack(bit_width, chunk, &mut decoded) };
+        }
+        let start = if i == 0 { offset } else { 0 };
+        let end = (offset + length - i * 1024).min(1024);
+        decoded[start..end]
+            .iter()
+            .for_each(|v| builder.append(cmp(*v, value)));
+    }
+    builder.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{ConstantArray, PrimitiveArray};
+    use vortex::compute::{compare, slice, Operator};
+    use vortex::validity::{ArrayValidity, Validity};
+    use vortex::IntoArrayVariant;
+
+    use crate::BitPackedArray;
+
+    #[test]
+    fn compare_packed() {
+        let values = (0u32..3000)
+            .map(|i| match i % 500 {
+                0 => 1000 + i,
+                499 => 64,
+                _ => i % 64,
+            })
+            .collect::<Vec<_>>();
+        let validity = Validity::from((0..3000).map(|i| i % 7 != 3).collect::<Vec<_>>());
+        let packed = BitPackedArray::encode(
+            PrimitiveArray::from_vec(values.clone(), validity.clone()).as_ref(),
+            6,
+        )
+        .unwrap();
+        assert!(packed.patches().is_some());
+        let sliced = slice(packed.as_ref(), 100, 2600).unwrap();
+        assert_eq!(BitPackedArray::try_from(&sliced).unwrap().offset(), 100);
+
+        for literal in [0u32, 17, 63, 64, 1500, 5000] {
+            for operator in [
+                Operator::Eq,
+                Operator::NotEq,
+                Operator::Gt,
+                Operator::Gte,
+                Operator::Lt,
+                Operator::Lte,
+            ] {
+                let compared =
+                    compare(&sliced, ConstantArray::new(literal, sliced.len()), operator)
+                        .unwrap()
+                        .into_bool()
+                        .unwrap();
+                let buffer = compared.boolean_buffer();
+                let expected = (100..2600)
+                    .map(|i| {
+                        validity
+                            .is_valid(i)
+                            .then(|| operator.to_fn()(values[i], literal))
+                    })
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    (0..sliced.len())
+                        .map(|i| compared.is_valid(i).then(|| buffer.value(i)))
+                        .collect::<Vec<_>>(),
+                    expected,
+                    "{operator} {literal}"
+                );
+            }
+        }
+    }
+}
@@ -1,14 +1,21 @@
 use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::{ArrayCompute, SearchSortedFn, SliceFn, TakeFn};
+use vortex::compute::{ArrayCompute, MaybeCompareFn, Operator, SearchSortedFn, SliceFn, TakeFn};
+use vortex::Array;
+use vortex_error::VortexResult;
 
 use crate::BitPackedArray;
 
+mod compare;
 mod scalar_at;
 mod search_sorted;
 mod slice;
 mod take;
 
 impl ArrayCompute for BitPackedArray {
+    fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        MaybeCompareFn::maybe_compare(self, other, operator)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
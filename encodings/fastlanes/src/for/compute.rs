@@ -1,19 +1,44 @@
 use std::ops::{AddAssign, Shl, Shr};
 
-use num_traits::{WrappingAdd, WrappingSub};
+use num_traits::{PrimInt, WrappingAdd, WrappingSub};
+use vortex::array::ConstantArray;
 use vortex::compute::unary::{scalar_at_unchecked, ScalarAtFn};
 use vortex::compute::{
-    filter, search_sorted, slice, take, ArrayCompute, FilterFn, SearchResult, SearchSortedFn,
+    compare, filter, search_sorted, slice, take, ArithmeticOperator, ArithmeticOptions,
+    ArrayCompute, FilterFn, MaybeCompareFn, Operator, SearchResult, SearchSortedFn,
     SearchSortedSide, SliceFn, TakeFn,
 };
+use vortex::stats::{ArrayStatistics, Stat};
 use vortex::{Array, ArrayDType, IntoArray};
 use vortex_dtype::{match_each_integer_ptype, NativePType};
 use vortex_error::{VortexError, VortexExpect as _, VortexResult, VortexUnwrap as _};
 use vortex_scalar::{PValue, Scalar};
 
-use crate::FoRArray;
+use crate::{BitPackedArray, FoRArray};
 
 impl ArrayCompute for FoRArray {
+    fn arithmetic(
+        &self,
+        other: &Array,
+        operator: ArithmeticOperator,
+        _options: ArithmeticOptions,
+    ) -> Option<VortexResult<Array>> {
+        let value = ConstantArray::try_from(other)
+            .ok()
+            .map(|c| c.owned_scalar())
+            .filter(|s| !s.is_null())?;
+        let negate = match operator {
+            ArithmeticOperator::Add => false,
+            ArithmeticOperator::Sub => true,
+            _ => return None,
+        };
+
+        match_each_integer_ptype!(self.ptype(), |$P| {
+            shift_reference::<$P>(self, &value, negate)
+        })
+        .transpose()
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -33,6 +58,89 @@ impl ArrayCompute for FoRArray {
     fn filter(&self) -> Option<&dyn FilterFn> {
         Some(self)
     }
+
+    fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        MaybeCompareFn::maybe_compare(self, other, operator)
+    }
+}
+
+impl MaybeCompareFn for FoRArray {
+    fn maybe_compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        let value = ConstantArray::try_from(other)
+            .ok()
+            .map(|c| c.owned_scalar())
+            .filter(|s| !s.is_null())?;
+
+        Some(match_each_integer_ptype!(self.ptype(), |$P| {
+            compare_typed::<$P>(self, &value, operator)
+        }))
+    }
+}
+
+/// Adding a constant to every value only moves the reference, as long as none of the values can
+/// overflow. Returns `None` if that can't be guaranteed from the width of the encoded values.
+fn shift_reference<T>(array: &FoRArray, value: &Scalar, negate: bool) -> VortexResult<Option<Array>>
+where
+    T: NativePType
+        + PrimInt
+        + for<'a> TryFrom<&'a Scalar, Error = VortexError>
+        + TryFrom<PValue, Error = VortexError>
+        + Into<PValue>,
+{
+    let Some(max_encoded) = max_encoded(array) else {
+        return Ok(None);
+    };
+    let reference: T = array
+        .reference()
+        .as_pvalue()?
+        .vortex_expect("Reference value cannot be null")
+        .as_primitive::<T>()?;
+    let value: T = value.cast(array.dtype())?.as_ref().try_into()?;
+
+    // Every 64 bit integer fits into an i128, so only the encoded range can overflow
+    let (Some(reference), Some(value)) = (reference.to_i128(), value.to_i128()) else {
+        return Ok(None);
+    };
+    let delta = if negate { -value } else { value };
+    let Some(max) = i128::from(max_encoded)
+        .checked_mul(1 << array.shift())
+        .and_then(|range| range.checked_add(reference))
+    else {
+        return Ok(None);
+    };
+
+    match (
+        <T as num_traits::NumCast>::from(reference + delta),
+        <T as num_traits::NumCast>::from(max + delta),
+    ) {
+        (Some(new_reference), Some(_)) => FoRArray::try_new(
+            array.encoded(),
+            Scalar::primitive(new_reference, array.dtype().nullability()),
+            array.shift(),
+        )
+        .map(|a| Some(a.into_array())),
+        _ => Ok(None),
+    }
+}
+
+/// Upper bound of the encoded values, from their statistics or the width they are packed into.
+fn max_encoded(array: &FoRArray) -> Option<u64> {
+    let encoded = array.encoded();
+    if let Some(max) = encoded.statistics().get_as_cast::<u64>(Stat::Max) {
+        return Some(max);
+    }
+
+    if let Ok(bitpacked) = BitPackedArray::try_from(&encoded) {
+        if bitpacked.patches().is_none() {
+            return Some(
+                u64::MAX
+                    .checked_shr(64 - bitpacked.bit_width() as u32)
+                    .unwrap_or(0),
+            );
+        }
+    }
+
+    encoded.statistics().compute_as_cast::<u64>(Stat::Max)
 }
 
 impl TakeFn for FoRArray {
@@ -162,13 +270,66 @@ where
     )
 }
 
+/// Compare the encoded values against the literal translated into the compressed space.
+fn compare_typed<T>(array: &FoRArray, value: &Scalar, operator: Operator) -> VortexResult<Array>
+where
+    T: NativePType
+        + for<'a> TryFrom<&'a Scalar, Error = VortexError>
+        + TryFrom<PValue, Error = VortexError>
+        + Shr<u8, Output = T>
+        + Shl<u8, Output = T>
+        + WrappingSub
+        + WrappingAdd
+        + Into<PValue>,
+{
+    let min: T = array
+        .reference()
+        .as_pvalue()?
+        .vortex_expect("Reference value cannot be null")
+        .as_primitive::<T>()?;
+    let primitive_value: T = value.cast(array.dtype())?.as_ref().try_into()?;
+
+    let encoded_value = primitive_value.wrapping_sub(&min) >> array.shift();
+    let decoded_value = (encoded_value << array.shift()).wrapping_add(&min);
+
+    // Values smaller than the reference sort before every encoded value, values that aren't
+    // representable sort between two consecutive encoded values. Neither can be equal to any element.
+    let (operator, target) = if primitive_value < min {
+        match operator {
+            Operator::Eq | Operator::Lt | Operator::Lte => (Operator::Lt, T::zero()),
+            Operator::NotEq | Operator::Gt | Operator::Gte => (Operator::Gte, T::zero()),
+        }
+    } else if decoded_value != primitive_value {
+        match operator {
+            Operator::Eq => (Operator::Lt, T::zero()),
+            Operator::NotEq => (Operator::Gte, T::zero()),
+            Operator::Gt | Operator::Gte => (Operator::Gt, encoded_value),
+            Operator::Lt | Operator::Lte => (Operator::Lte, encoded_value),
+        }
+    } else {
+        (operator, encoded_value)
+    };
+
+    let target_scalar = Scalar::primitive(target, value.dtype().nullability())
+        .reinterpret_cast(array.ptype().to_unsigned());
+    compare(
+        array.encoded(),
+        ConstantArray::new(target_scalar, array.len()),
+        operator,
+    )
+}
+
 #[cfg(test)]
 mod test {
-    use vortex::array::PrimitiveArray;
+    use vortex::array::{ConstantArray, PrimitiveArray};
     use vortex::compute::unary::scalar_at;
-    use vortex::compute::{search_sorted, SearchResult, SearchSortedSide};
+    use vortex::compute::{
+        arithmetic_scalar, compare, search_sorted, ArithmeticOperator, ArithmeticOptions, Operator,
+        SearchResult, SearchSortedSide,
+    };
+    use vortex::{ArrayDef, IntoArray, IntoArrayVariant};
 
-    use crate::{for_compress, FoRArray};
+    use crate::{bitpack_encode, for_compress, FoR, FoRArray};
 
     #[test]
     fn for_scalar_at() {
@@ -277,4 +438,127 @@ mod test {
             SearchResult::NotFound(4)
         );
     }
+
+    #[test]
+    fn for_compare() {
+        let values = vec![-100, 1100, 1500, 1900];
+        let for_arr = for_compress(&PrimitiveArray::from(values.clone())).unwrap();
+        assert!(FoRArray::try_from(&for_arr).unwrap().shift() > 0);
+
+        for literal in [-200, -100, 1000, 1100, 1300, 1500, 1900, 2000] {
+            for operator in [
+                Operator::Eq,
+                Operator::NotEq,
+                Operator::Gt,
+                Operator::Gte,
+                Operator::Lt,
+                Operator::Lte,
+            ] {
+                let compared = compare(&for_arr, ConstantArray::new(literal, 4), operator)
+                    .unwrap()
+                    .into_bool()
+                    .unwrap();
+                assert_eq!(
+                    compared.boolean_buffer().iter().collect::<Vec<_>>(),
+                    values
+                        .iter()
+                        .map(|v| operator.to_fn()(*v, literal))
+                        .collect::<Vec<_>>(),
+                    "{operator} {literal}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn for_add_scalar() {
+        let for_arr = for_compress(&PrimitiveArray::from(vec![-100, 1100, 1500, 1900])).unwrap();
+
+        let added = arithmetic_scalar(
+            &for_arr,
+            &1000.into(),
+            ArithmeticOperator::Add,
+            ArithmeticOptions::default(),
+        )
+        .unwrap();
+        assert!(added.is_encoding(FoR::ID));
+        assert_eq!(
+            added.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            vec![900, 2100, 2500, 2900]
+        );
+
+        let subtracted = arithmetic_scalar(
+            &for_arr,
+            &(-5).into(),
+            ArithmeticOperator::Sub,
+            ArithmeticOptions::default(),
+        )
+        .unwrap();
+        assert!(subtracted.is_encoding(FoR::ID));
+        assert_eq!(
+            subtracted
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            vec![-95, 1105, 1505, 1905]
+        );
+    }
+
+    #[test]
+    fn for_add_scalar_overflow() {
+        let for_arr =
+            FoRArray::try_from(for_compress(&PrimitiveArray::from(vec![200u8, 210, 250])).unwrap())
+                .unwrap();
+        let packed = bitpack_encode(for_arr.encoded().into_primitive().unwrap(), 6).unwrap();
+        let for_arr = FoRArray::try_new(
+            packed.into_array(),
+            for_arr.owned_reference_scalar(),
+            for_arr.shift(),
+        )
+        .unwrap();
+
+        // The bit width alone bounds the values to 263, so the reference can't be moved up
+        let added = arithmetic_scalar(
+            for_arr.as_ref(),
+            &1u8.into(),
+            ArithmeticOperator::Add,
+            ArithmeticOptions::default(),
+        )
+        .unwrap();
+        assert!(!added.is_encoding(FoR::ID));
+        assert_eq!(
+            added.into_primitive().unwrap().maybe_null_slice::<u8>(),
+            vec![201, 211, 251]
+        );
+        arithmetic_scalar(
+            for_arr.as_ref(),
+            &10u8.into(),
+            ArithmeticOperator::Add,
+            ArithmeticOptions::default(),
+        )
+        .expect_err("Expected overflow");
+        arithmetic_scalar(
+            for_arr.as_ref(),
+            &201u8.into(),
+            ArithmeticOperator::Sub,
+            ArithmeticOptions::default(),
+        )
+        .expect_err("Expected overflow");
+
+        let subtracted = arithmetic_scalar(
+            for_arr.as_ref(),
+            &200u8.into(),
+            ArithmeticOperator::Sub,
+            ArithmeticOptions::default(),
+        )
+        .unwrap();
+        assert!(subtracted.is_encoding(FoR::ID));
+        assert_eq!(
+            subtracted
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u8>(),
+            vec![0, 10, 50]
+        );
+    }
 }
@@ -1,10 +1,12 @@
-use fsst::Symbol;
-use vortex::array::{varbin_scalar, ConstantArray};
+use fsst::{Symbol, ESCAPE_CODE};
+use vortex::accessor::ArrayAccessor;
+use vortex::array::{varbin_scalar, BoolArray, ConstantArray, VarBinArray};
 use vortex::compute::unary::{scalar_at_unchecked, ScalarAtFn};
 use vortex::compute::{
-    compare, filter, slice, take, ArrayCompute, FilterFn, MaybeCompareFn, Operator, SliceFn, TakeFn,
+    compare, filter, like, like_literal_prefix, slice, take, ArrayCompute, FilterFn, LikeFn,
+    LikeOptions, MaybeCompareFn, Operator, SliceFn, TakeFn,
 };
-use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
 use vortex_error::{vortex_err, VortexResult, VortexUnwrap};
@@ -21,6 +23,10 @@ impl ArrayCompute for FSSTArray {
         Some(self)
     }
 
+    fn like(&self) -> Option<&dyn LikeFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -96,6 +102,64 @@ fn compare_fsst_constant(
     }
 }
 
+impl LikeFn for FSSTArray {
+    fn like(&self, pattern: &str, options: LikeOptions) -> VortexResult<Array> {
+        match like_literal_prefix(pattern) {
+            Some(prefix) if !options.case_insensitive && !options.regex => {
+                like_fsst_prefix(self, prefix.as_bytes(), options.negated)
+            }
+            _ => like(
+                self.clone().into_canonical()?.into_varbinview()?,
+                pattern,
+                options,
+            ),
+        }
+    }
+}
+
+/// Specialized `LIKE 'prefix%'` implementation that matches the prefix against the compressed
+/// codes, expanding one symbol at a time and stopping as soon as the prefix is decided.
+fn like_fsst_prefix(array: &FSSTArray, prefix: &[u8], negated: bool) -> VortexResult<Array> {
+    let symbols = array.symbols().into_primitive()?;
+    let symbols_u64 = symbols.maybe_null_slice::<u64>();
+
+    let symbol_lens = array.symbol_lengths().into_primitive()?;
+    let symbol_lens_u8 = symbol_lens.maybe_null_slice::<u8>();
+
+    let starts_with = |codes: &[u8]| {
+        let mut remaining = prefix;
+        let mut codes = codes.iter();
+        while !remaining.is_empty() {
+            let Some(&code) = codes.next() else {
+                return false;
+            };
+            let matched = if code == ESCAPE_CODE {
+                match codes.next() {
+                    Some(&literal) => literal == remaining[0],
+                    None => false,
+                }
+                .then_some(1)
+            } else {
+                let symbol = symbols_u64[code as usize].to_le_bytes();
+                let len = (symbol_lens_u8[code as usize] as usize).min(remaining.len());
+                (symbol[..len] == remaining[..len]).then_some(len)
+            };
+            match matched {
+                Some(len) => remaining = &remaining[len..],
+                None => return false,
+            }
+        }
+        true
+    };
+
+    let matches = VarBinArray::try_from(array.codes())?.with_iterator(|iter| {
+        iter.map(|codes| codes.is_some_and(starts_with) != negated)
+            .collect::<Vec<_>>()
+    })?;
+
+    Ok(BoolArray::from_vec(matches, array.validity()).into_array())
+}
+
 impl SliceFn for FSSTArray {
     fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
         // Slicing an FSST array leaves the symbol table unmodified,
@@ -161,8 +225,8 @@ impl FilterFn for FSSTArray {
 #[cfg(test)]
 mod tests {
     use vortex::array::{ConstantArray, VarBinArray};
-    use vortex::compute::unary::scalar_at_unchecked;
-    use vortex::compute::{MaybeCompareFn, Operator};
+    use vortex::compute::unary::{scalar_at, scalar_at_unchecked};
+    use vortex::compute::{like, LikeOptions, MaybeCompareFn, Operator};
     use vortex::{IntoArray, IntoArrayVariant};
     use vortex_dtype::{DType, Nullability};
     use vortex_scalar::Scalar;
@@ -228,4 +292,81 @@ mod tests {
             assert!(scalar_at_unchecked(&noteq_null, idx).is_null());
         }
     }
+
+    #[test]
+    fn test_like_fsst() {
+        let lhs = VarBinArray::from_iter(
+            [
+                Some("https://vortex.dev"),
+                None,
+                Some("http://example.com"),
+                Some("https://spiraldb.com"),
+                Some("ftp"),
+            ],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .into_array();
+        let compressor = fsst_train_compressor(&lhs).unwrap();
+        let lhs = fsst_compress(&lhs, &compressor).unwrap().into_array();
+
+        let like_values = |pattern: &str, options: LikeOptions| {
+            let result = like(&lhs, pattern, options).unwrap();
+            (0..result.len())
+                .map(|i| scalar_at(&result, i).unwrap().value().as_bool().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        // Prefix patterns are matched against the compressed codes
+        assert_eq!(
+            like_values("https://%", LikeOptions::default()),
+            vec![Some(true), None, Some(false), Some(true), Some(false)]
+        );
+        assert_eq!(
+            like_values(
+                "http%",
+                LikeOptions {
+                    negated: true,
+                    case_insensitive: false,
+                    regex: false,
+                }
+            ),
+            vec![Some(false), None, Some(false), Some(false), Some(true)]
+        );
+
+        // Everything else is matched on the decompressed strings
+        assert_eq!(
+            like_values(
+                "%.COM",
+                LikeOptions {
+                    negated: false,
+                    case_insensitive: true,
+                    regex: false,
+                }
+            ),
+            vec![Some(false), None, Some(true), Some(true), Some(false)]
+        );
+
+        // `%` has no special meaning in regular expressions
+        assert_eq!(
+            like_values(
+                "^https?://[a-z]+%",
+                LikeOptions {
+                    regex: true,
+                    ..LikeOptions::default()
+                }
+            ),
+            vec![Some(false), None, Some(false), Some(false), Some(false)]
+        );
+        assert_eq!(
+            like_values(
+                "^HTTP://",
+                LikeOptions {
+                    negated: true,
+                    case_insensitive: true,
+                    regex: true,
+                }
+            ),
+            vec![Some(true), None, Some(false), Some(true), Some(true)]
+        );
+    }
 }
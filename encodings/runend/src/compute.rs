@@ -1,15 +1,30 @@
-use vortex::array::{ConstantArray, PrimitiveArray, SparseArray};
+use std::cmp::min;
+use std::ops::Range;
+
+use vortex::array::{BoolArray, ConstantArray, PrimitiveArray, SparseArray};
 use vortex::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
-use vortex::compute::{filter, slice, take, ArrayCompute, SliceFn, TakeFn};
-use vortex::validity::Validity;
-use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
-use vortex_dtype::match_each_integer_ptype;
+use vortex::compute::{
+    argsort, compare, filter, min_max, slice, sort, sum, take, ArgsortFn, ArrayCompute,
+    MaybeCompareFn, MinMaxFn, MinMaxResult, Operator, SliceFn, SortFn, SortOptions, SumAccumulator,
+    SumFn, TakeFn,
+};
+use vortex::validity::{ArrayValidity, Validity};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
+use vortex_dtype::{match_each_integer_ptype, match_each_native_ptype};
 use vortex_error::{VortexExpect as _, VortexResult};
 use vortex_scalar::{Scalar, ScalarValue};
 
 use crate::RunEndArray;
 
 impl ArrayCompute for RunEndArray {
+    fn argsort(&self) -> Option<&dyn ArgsortFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -18,9 +33,181 @@ impl ArrayCompute for RunEndArray {
         Some(self)
     }
 
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
+
+    fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        MaybeCompareFn::maybe_compare(self, other, operator)
+    }
+}
+
+impl MaybeCompareFn for RunEndArray {
+    fn maybe_compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        let constant = ConstantArray::try_from(other)
+            .ok()
+            .filter(|c| !c.scalar_value().is_null())?;
+
+        // Compare every run value once and repeat the outcome over the run
+        Some(
+            compare(
+                self.values(),
+                ConstantArray::new(constant.owned_scalar(), self.values().len()),
+                operator,
+            )
+            .and_then(|compared| {
+                let compared = compared.into_bool()?;
+                let mut decoded = Vec::with_capacity(self.len());
+                for (end, value) in self
+                    .trimmed_ends()?
+                    .into_iter()
+                    .zip(compared.boolean_buffer().iter())
+                {
+                    decoded.extend(std::iter::repeat(value).take(end - decoded.len()));
+                }
+                Ok(BoolArray::from_vec(decoded, self.validity()).into_array())
+            }),
+        )
+    }
+}
+
+impl RunEndArray {
+    /// Run ends relative to the start of this array, clamped to its length.
+    fn trimmed_ends(&self) -> VortexResult<Vec<usize>> {
+        let ends = self.ends().into_primitive()?;
+        Ok(match_each_integer_ptype!(ends.ptype(), |$E| {
+            ends.maybe_null_slice::<$E>()
+                .iter()
+                .map(|e| min(*e as usize - self.offset(), self.len()))
+                .collect::<Vec<_>>()
+        }))
+    }
+
+    /// Order of the runs when sorting their values, together with the trimmed ends.
+    fn sorted_runs(&self, options: SortOptions) -> VortexResult<(Vec<usize>, Vec<usize>)> {
+        let order = argsort(self.values(), options)?.into_primitive()?;
+        let order = match_each_integer_ptype!(order.ptype(), |$I| {
+            order
+                .maybe_null_slice::<$I>()
+                .iter()
+                .map(|&run| run as usize)
+                .collect::<Vec<_>>()
+        });
+        Ok((order, self.trimmed_ends()?))
+    }
+}
+
+/// Range of elements covered by a run, given the trimmed ends.
+fn run_range(ends: &[usize], run: usize) -> Range<usize> {
+    let start = if run == 0 { 0 } else { ends[run - 1] };
+    start..ends[run]
+}
+
+impl ArgsortFn for RunEndArray {
+    fn argsort(&self, options: SortOptions) -> VortexResult<Array> {
+        if !self.logical_validity().all_valid() {
+            return argsort(Array::from(self.clone().into_canonical()?), options);
+        }
+
+        // Sort the runs and emit the indices covered by each of them
+        let (order, ends) = self.sorted_runs(options)?;
+        let indices = order
+            .into_iter()
+            .flat_map(|run| run_range(&ends, run))
+            .map(|idx| idx as u64)
+            .collect::<Vec<_>>();
+        Ok(PrimitiveArray::from(indices).into_array())
+    }
+}
+
+impl SortFn for RunEndArray {
+    fn sort(&self, options: SortOptions) -> VortexResult<Array> {
+        if !self.logical_validity().all_valid() {
+            return sort(Array::from(self.clone().into_canonical()?), options);
+        }
+
+        // Reorder the runs, dropping those that don't cover any element of a sliced array
+        let (order, ends) = self.sorted_runs(options)?;
+        let mut sorted_ends = Vec::with_capacity(order.len());
+        let mut sorted_runs = Vec::with_capacity(order.len());
+        let mut end = 0;
+        for run in order {
+            let run_len = run_range(&ends, run).len();
+            if run_len > 0 {
+                end += run_len;
+                sorted_ends.push(end as u64);
+                sorted_runs.push(run as u64);
+            }
+        }
+
+        Self::try_new(
+            PrimitiveArray::from(sorted_ends).into_array(),
+            take(self.values(), PrimitiveArray::from(sorted_runs).as_ref())?,
+            // Every element is valid, so the validity doesn't depend on the order
+            self.validity(),
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl SumFn for RunEndArray {
+    fn sum(&self) -> VortexResult<Scalar> {
+        if !self.logical_validity().all_valid() {
+            return sum(self.clone().into_canonical()?.into_primitive()?);
+        }
+
+        // Each run contributes its value once for every element it covers
+        let values = self.values().into_primitive()?;
+        let mut acc = SumAccumulator::try_new(self.dtype())?;
+        let mut start = 0;
+        match_each_native_ptype!(values.ptype(), |$T| {
+            for (idx, (end, value)) in self
+                .trimmed_ends()?
+                .into_iter()
+                .zip(values.maybe_null_slice::<$T>())
+                .enumerate()
+            {
+                if values.is_valid(idx) {
+                    acc.add_repeated(*value, end - start)?;
+                }
+                start = end;
+            }
+        });
+        Ok(acc.finish())
+    }
+}
+
+impl MinMaxFn for RunEndArray {
+    fn min_max(&self) -> VortexResult<Option<MinMaxResult>> {
+        if !self.logical_validity().all_valid() {
+            return min_max(self.clone().into_canonical()?.into_primitive()?);
+        }
+
+        // Sliced arrays can keep runs that don't cover any element at either end
+        let mut start = 0;
+        let non_empty = self
+            .trimmed_ends()?
+            .into_iter()
+            .map(|end| {
+                let len = end - start;
+                start = end;
+                len > 0
+            })
+            .collect::<Vec<_>>();
+        if non_empty.iter().all(|&n| n) {
+            min_max(self.values())
+        } else {
+            min_max(filter(self.values(), BoolArray::from(non_empty).as_ref())?)
+        }
+    }
 }
 
 impl ScalarAtFn for RunEndArray {
@@ -112,15 +299,17 @@ impl SliceFn for RunEndArray {
 
 #[cfg(test)]
 mod test {
-    use vortex::array::{BoolArray, PrimitiveArray};
+    use vortex::array::{BoolArray, ConstantArray, PrimitiveArray};
     use vortex::compute::unary::{scalar_at, try_cast};
-    use vortex::compute::{slice, take};
+    use vortex::compute::{
+        argsort, compare, min_max, slice, sort, sum, take, Operator, SortOptions,
+    };
     use vortex::validity::{ArrayValidity, Validity};
-    use vortex::{ArrayDType, IntoArray, IntoArrayVariant, ToArray};
+    use vortex::{ArrayDType, ArrayDef, IntoArray, IntoArrayVariant, ToArray};
     use vortex_dtype::{DType, Nullability, PType};
     use vortex_scalar::Scalar;
 
-    use crate::RunEndArray;
+    use crate::{RunEnd, RunEndArray};
 
     fn ree_array() -> RunEndArray {
         RunEndArray::encode(
@@ -351,4 +540,139 @@ mod test {
         assert_eq!(scalar_at(taken.as_ref(), 1).unwrap(), 2.into());
         assert_eq!(scalar_at(taken.as_ref(), 2).unwrap(), 5.into());
     }
+
+    #[test]
+    fn compare_sliced() {
+        let sliced = slice(ree_array().as_ref(), 2, 10).unwrap();
+        let compared = compare(&sliced, ConstantArray::new(4, sliced.len()), Operator::Gte)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            compared.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, true, true, false, false, true, true]
+        );
+    }
+
+    #[test]
+    fn compare_with_nulls() {
+        let array = RunEndArray::try_new(
+            PrimitiveArray::from(vec![3u32, 6, 8, 12]).into_array(),
+            PrimitiveArray::from_vec(vec![1, 4, 2, 5], Validity::AllValid).into_array(),
+            Validity::from(vec![
+                false, false, false, false, true, true, false, false, false, false, true, true,
+            ]),
+        )
+        .unwrap();
+        let compared = compare(
+            array.as_ref(),
+            ConstantArray::new(Scalar::primitive(4, Nullability::Nullable), array.len()),
+            Operator::Eq,
+        )
+        .unwrap();
+        assert_eq!(
+            (0..compared.len())
+                .map(|i| scalar_at(&compared, i).unwrap().value().as_bool().unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                None,
+                None,
+                None,
+                None,
+                Some(true),
+                Some(true),
+                None,
+                None,
+                None,
+                None,
+                Some(false),
+                Some(false)
+            ]
+        );
+    }
+
+    #[test]
+    fn ree_aggregates() {
+        // [1, 1, 1, 4, 4, 4, 2, 2, 5, 5, 5, 5]
+        let array = ree_array();
+        assert_eq!(
+            sum(array.as_ref()).unwrap(),
+            Scalar::primitive(39i64, Nullability::Nullable)
+        );
+        let result = min_max(array.as_ref()).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(1));
+        assert_eq!(result.max, Scalar::from(5));
+
+        // [4, 4, 2], keeping the runs of 1 and 5 that don't cover any element
+        let sliced = slice(array.as_ref(), 4, 7).unwrap();
+        assert_eq!(
+            sum(&sliced).unwrap(),
+            Scalar::primitive(10i64, Nullability::Nullable)
+        );
+        let result = min_max(&sliced).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(2));
+        assert_eq!(result.max, Scalar::from(4));
+    }
+
+    #[test]
+    fn ree_aggregates_nullable() {
+        let array = RunEndArray::try_new(
+            PrimitiveArray::from(vec![2u32, 4, 6]).into_array(),
+            PrimitiveArray::from_vec(vec![10, 1, 20], Validity::AllValid).into_array(),
+            Validity::Array(
+                BoolArray::from(vec![true, true, true, false, true, true]).into_array(),
+            ),
+        )
+        .unwrap();
+        assert_eq!(
+            sum(array.as_ref()).unwrap(),
+            Scalar::primitive(61i64, Nullability::Nullable)
+        );
+        let result = min_max(array.as_ref()).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(1));
+        assert_eq!(result.max, Scalar::from(20));
+    }
+
+    #[test]
+    fn ree_sort() {
+        // [1, 1, 1, 4, 4, 4, 2, 2, 5, 5, 5, 5] sliced to [1, 4, 4, 4, 2]
+        let sliced = slice(ree_array().as_ref(), 2, 7).unwrap();
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+
+        let sorted = sort(&sliced, options).unwrap();
+        assert_eq!(sorted.encoding().id(), RunEnd::ID);
+        assert_eq!(
+            sorted.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[4, 4, 4, 2, 1]
+        );
+
+        let indices = argsort(&sliced, options).unwrap();
+        assert_eq!(
+            indices.into_primitive().unwrap().maybe_null_slice::<u64>(),
+            &[1, 2, 3, 4, 0]
+        );
+    }
+
+    #[test]
+    fn ree_sort_nullable() {
+        let array = RunEndArray::try_new(
+            PrimitiveArray::from(vec![2u32, 4, 6]).into_array(),
+            PrimitiveArray::from_vec(vec![10, 1, 20], Validity::AllValid).into_array(),
+            Validity::Array(
+                BoolArray::from(vec![true, true, true, false, true, true]).into_array(),
+            ),
+        )
+        .unwrap();
+        let sorted = sort(array.as_ref(), SortOptions::default()).unwrap();
+        assert_eq!(
+            (0..sorted.len())
+                .map(|i| scalar_at(&sorted, i).unwrap().value().as_pvalue().unwrap())
+                .map(|v| v.and_then(|v| v.as_i32()))
+                .collect::<Vec<_>>(),
+            vec![Some(1), Some(10), Some(10), Some(20), Some(20), None]
+        );
+    }
 }
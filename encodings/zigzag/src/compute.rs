@@ -1,5 +1,6 @@
+use vortex::array::ConstantArray;
 use vortex::compute::unary::{scalar_at_unchecked, ScalarAtFn};
-use vortex::compute::{slice, ArrayCompute, SliceFn};
+use vortex::compute::{compare, slice, ArrayCompute, MaybeCompareFn, Operator, SliceFn};
 use vortex::{Array, ArrayDType, IntoArray};
 use vortex_dtype::match_each_unsigned_integer_ptype;
 use vortex_error::{vortex_err, VortexResult, VortexUnwrap as _};
@@ -16,6 +17,44 @@ impl ArrayCompute for ZigZagArray {
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
+
+    fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        MaybeCompareFn::maybe_compare(self, other, operator)
+    }
+}
+
+impl MaybeCompareFn for ZigZagArray {
+    fn maybe_compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        // ZigZag encoding doesn't preserve order, only equality can be evaluated on encoded values
+        if !matches!(operator, Operator::Eq | Operator::NotEq) {
+            return None;
+        }
+        let value = ConstantArray::try_from(other)
+            .ok()
+            .map(|c| c.owned_scalar())
+            .filter(|s| !s.is_null())?;
+
+        Some(self.encode_scalar(&value).and_then(|encoded| {
+            compare(
+                self.encoded(),
+                ConstantArray::new(encoded, self.len()),
+                operator,
+            )
+        }))
+    }
+}
+
+impl ZigZagArray {
+    fn encode_scalar(&self, value: &Scalar) -> VortexResult<Scalar> {
+        let value = value.cast(self.dtype())?;
+        match_each_unsigned_integer_ptype!(self.ptype().to_unsigned(), |$P| {
+            let value: <$P as ZigZagEncoded>::Int = value.as_ref().try_into()?;
+            Ok(Scalar::primitive(
+                <<$P as ZigZagEncoded>::Int>::encode(value),
+                self.dtype().nullability(),
+            ))
+        })
+    }
 }
 
 impl ScalarAtFn for ZigZagArray {
@@ -73,11 +112,11 @@ impl SliceFn for ZigZagArray {
 
 #[cfg(test)]
 mod tests {
-    use vortex::array::PrimitiveArray;
+    use vortex::array::{ConstantArray, PrimitiveArray};
     use vortex::compute::unary::scalar_at;
-    use vortex::compute::{search_sorted, SearchResult, SearchSortedSide};
+    use vortex::compute::{compare, search_sorted, Operator, SearchResult, SearchSortedSide};
     use vortex::validity::Validity;
-    use vortex::IntoArray;
+    use vortex::{IntoArray, IntoArrayVariant};
     use vortex_dtype::Nullability;
     use vortex_scalar::Scalar;
 
@@ -104,4 +143,23 @@ mod tests {
             Scalar::primitive(-160, Nullability::Nullable)
         );
     }
+
+    #[test]
+    pub fn compare_eq() {
+        let zigzag =
+            ZigZagArray::encode(&PrimitiveArray::from(vec![-189, -160, 1, -160]).into_array())
+                .unwrap();
+        let compared = compare(
+            &zigzag,
+            ConstantArray::new(-160, zigzag.len()),
+            Operator::Eq,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            compared.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, false, true]
+        );
+    }
 }
@@ -30,6 +30,15 @@ class VortexDataset(pyarrow.dataset.Dataset):
     def from_path(path: str):
         return VortexDataset(_lib_dataset.dataset_from_path(path))
 
+    @staticmethod
+    def from_glob_url(url: str, hive_partitioning: bool = False):
+        """Read every file matching a glob, such as ``s3://bucket/table/**/*.vortex``, as one dataset.
+
+        ``*`` matches within a directory and ``**`` across directories. With ``hive_partitioning``,
+        ``key=value`` directories become string columns that follow the columns of the files.
+        """
+        return VortexDataset(_lib_dataset.dataset_from_glob_url(url, hive_partitioning=hive_partitioning))
+
     @property
     def schema(self) -> pa.Schema:
         return self._dataset.schema()
@@ -407,6 +416,10 @@ def from_url(url: str) -> VortexDataset:
     return VortexDataset(_lib_dataset.dataset_from_url(url))
 
 
+def from_glob_url(url: str, hive_partitioning: bool = False) -> VortexDataset:
+    return VortexDataset.from_glob_url(url, hive_partitioning=hive_partitioning)
+
+
 class VortexScanner(pa.dataset.Scanner):
     """A PyArrow Dataset Scanner that reads from a Vortex Array.
 
@@ -9,15 +9,16 @@ use pyo3::prelude::*;
 use pyo3::types::{PyLong, PyString};
 use tokio::fs::File;
 use vortex::arrow::infer_schema;
-use vortex::Array;
+use vortex::stream::{ArrayStream, ArrayStreamExt};
+use vortex::{Array, IntoArray};
 use vortex_dtype::field::Field;
 use vortex_dtype::DType;
 use vortex_error::VortexResult;
 use vortex_sampling_compressor::ALL_COMPRESSORS_CONTEXT;
-use vortex_serde::io::{ObjectStoreReadAt, VortexReadAt};
+use vortex_serde::io::{object_store_from_url, ObjectStoreReadAt, VortexReadAt};
 use vortex_serde::layouts::{
     LayoutBatchStream, LayoutContext, LayoutDescriptorReader, LayoutDeserializer,
-    LayoutReaderBuilder, Projection, RowFilter, VortexRecordBatchReader,
+    LayoutReaderBuilder, Projection, RowFilter, VortexDataset, VortexRecordBatchReader,
 };
 
 use crate::expr::PyExpr;
@@ -71,7 +72,7 @@ pub async fn read_dtype_from_reader<T: VortexReadAt + Unpin>(reader: T) -> Vorte
     .dtype()
 }
 
-fn projection_from_python(columns: Option<Vec<Bound<PyAny>>>) -> PyResult<Projection> {
+pub(crate) fn projection_from_python(columns: Option<Vec<Bound<PyAny>>>) -> PyResult<Projection> {
     fn field_from_pyany(field: &Bound<PyAny>) -> PyResult<Field> {
         if field.clone().is_instance_of::<PyString>() {
             Ok(Field::Name(
@@ -98,7 +99,7 @@ fn projection_from_python(columns: Option<Vec<Bound<PyAny>>>) -> PyResult<Projec
     })
 }
 
-fn row_filter_from_python(row_filter: Option<&Bound<PyExpr>>) -> Option<RowFilter> {
+pub(crate) fn row_filter_from_python(row_filter: Option<&Bound<PyExpr>>) -> Option<RowFilter> {
     row_filter.map(|x| RowFilter::new(x.borrow().unwrap().clone()))
 }
 
@@ -270,6 +271,82 @@ impl ObjectStoreUrlDataset {
     }
 }
 
+#[pyclass(name = "ObjectStoreGlobDataset", module = "io")]
+pub struct ObjectStoreGlobDataset {
+    dataset: VortexDataset,
+    schema: SchemaRef,
+}
+
+impl ObjectStoreGlobDataset {
+    pub async fn try_new(url: String, hive_partitioning: bool) -> VortexResult<Self> {
+        let (object_store, pattern) = object_store_from_url(&url)?;
+        let layout_serde = LayoutDeserializer::new(
+            ALL_COMPRESSORS_CONTEXT.clone(),
+            LayoutContext::default().into(),
+        );
+        let dataset = if hive_partitioning {
+            VortexDataset::try_new_partitioned(object_store, pattern.as_ref(), None, layout_serde)
+                .await?
+        } else {
+            VortexDataset::try_new_glob(object_store, pattern.as_ref(), layout_serde).await?
+        };
+        let schema = Arc::new(infer_schema(dataset.dtype())?);
+
+        Ok(Self { dataset, schema })
+    }
+
+    fn stream(
+        &self,
+        columns: Option<Vec<Bound<'_, PyAny>>>,
+        batch_size: Option<usize>,
+        row_filter: Option<&Bound<'_, PyExpr>>,
+    ) -> PyResult<impl ArrayStream + Unpin> {
+        let mut builder = self
+            .dataset
+            .reader()
+            .with_projection(projection_from_python(columns)?);
+        if let Some(batch_size) = batch_size {
+            builder = builder.with_batch_size(batch_size);
+        }
+        if let Some(row_filter) = row_filter_from_python(row_filter) {
+            builder = builder.with_row_filter(row_filter);
+        }
+        Ok(builder.build()?)
+    }
+}
+
+#[pymethods]
+impl ObjectStoreGlobDataset {
+    fn schema(self_: PyRef<Self>) -> PyResult<PyObject> {
+        self_.schema.clone().to_pyarrow(self_.py())
+    }
+
+    #[pyo3(signature = (*, columns=None, batch_size=None, row_filter=None))]
+    pub fn to_array(
+        &self,
+        columns: Option<Vec<Bound<'_, PyAny>>>,
+        batch_size: Option<usize>,
+        row_filter: Option<&Bound<'_, PyExpr>>,
+    ) -> PyResult<PyArray> {
+        let stream = self.stream(columns, batch_size, row_filter)?;
+        let inner = TOKIO_RUNTIME.block_on(stream.collect_chunked())?;
+        Ok(PyArray::new(inner.into_array()))
+    }
+
+    #[pyo3(signature = (*, columns=None, batch_size=None, row_filter=None))]
+    pub fn to_record_batch_reader(
+        self_: PyRef<Self>,
+        columns: Option<Vec<Bound<'_, PyAny>>>,
+        batch_size: Option<usize>,
+        row_filter: Option<&Bound<'_, PyExpr>>,
+    ) -> PyResult<PyObject> {
+        let stream = self_.stream(columns, batch_size, row_filter)?;
+        let record_batch_reader: Box<dyn RecordBatchReader + Send> =
+            Box::new(VortexRecordBatchReader::try_new(stream, &*TOKIO_RUNTIME)?);
+        record_batch_reader.into_pyarrow(self_.py())
+    }
+}
+
 #[pyfunction]
 pub fn dataset_from_url(url: Bound<PyString>) -> PyResult<ObjectStoreUrlDataset> {
     Ok(TOKIO_RUNTIME.block_on(ObjectStoreUrlDataset::try_new(url.extract()?))?)
@@ -279,3 +356,15 @@ pub fn dataset_from_url(url: Bound<PyString>) -> PyResult<ObjectStoreUrlDataset>
 pub fn dataset_from_path(path: Bound<PyString>) -> PyResult<TokioFileDataset> {
     Ok(TOKIO_RUNTIME.block_on(TokioFileDataset::try_new(path.extract()?))?)
 }
+
+#[pyfunction]
+#[pyo3(signature = (url, *, hive_partitioning=false))]
+pub fn dataset_from_glob_url(
+    url: Bound<PyString>,
+    hive_partitioning: bool,
+) -> PyResult<ObjectStoreGlobDataset> {
+    Ok(TOKIO_RUNTIME.block_on(ObjectStoreGlobDataset::try_new(
+        url.extract()?,
+        hive_partitioning,
+    ))?)
+}
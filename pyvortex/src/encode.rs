@@ -6,8 +6,9 @@ use arrow::record_batch::RecordBatchReader;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use vortex::array::ChunkedArray;
-use vortex::arrow::{FromArrowArray, FromArrowType};
-use vortex::{Array, IntoArray};
+use vortex::arrow::FromArrowType;
+use vortex::IntoArray;
+use vortex_dict::{from_arrow_preserving_dict, record_batch_preserving_dict};
 use vortex_dtype::DType;
 use vortex_error::{VortexError, VortexResult};
 
@@ -24,7 +25,7 @@ pub fn _encode<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyArray>> {
     if obj.is_instance(&pa_array)? {
         let arrow_array = ArrowArrayData::from_pyarrow_bound(obj).map(make_array)?;
         let is_nullable = arrow_array.is_nullable();
-        let enc_array = Array::from_arrow(arrow_array, is_nullable);
+        let enc_array = from_arrow_preserving_dict(arrow_array, is_nullable);
         Bound::new(obj.py(), PyArray::new(enc_array))
     } else if obj.is_instance(&chunked_array)? {
         let chunks: Vec<Bound<PyAny>> = obj.getattr("chunks")?.extract()?;
@@ -33,7 +34,7 @@ pub fn _encode<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyArray>> {
             .map(|a| {
                 ArrowArrayData::from_pyarrow_bound(a)
                     .map(make_array)
-                    .map(|a| Array::from_arrow(a, false))
+                    .map(|a| from_arrow_preserving_dict(a, false))
             })
             .collect::<PyResult<Vec<_>>>()?;
         let dtype: DType = obj
@@ -50,7 +51,7 @@ pub fn _encode<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyArray>> {
         let chunks = array_stream
             .into_iter()
             .map(|b| b.map_err(VortexError::ArrowError))
-            .map(|b| b.and_then(Array::try_from))
+            .map(|b| b.and_then(record_batch_preserving_dict))
             .collect::<VortexResult<Vec<_>>>()?;
         Bound::new(
             obj.py(),
@@ -1,18 +1,29 @@
-use std::path::Path;
+use std::io;
+use std::path::{Path, PathBuf};
 
+use bytes::BytesMut;
+use log::warn;
+use object_store::WriteMultipart;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::pyfunction;
-use pyo3::types::PyString;
+use pyo3::types::{PyBytes, PyString};
 use tokio::fs::File;
 use vortex::Array;
+use vortex_buffer::io_buf::IoBuf;
+use vortex_error::VortexResult;
 use vortex_sampling_compressor::SamplingCompressor;
+use vortex_serde::io::{object_store_from_url, ObjectStoreWriter, VortexReadAt, VortexWrite};
 use vortex_serde::layouts::LayoutWriter;
 
-use crate::dataset::{ObjectStoreUrlDataset, TokioFileDataset};
+use crate::dataset::{
+    projection_from_python, read_array_from_reader, row_filter_from_python, ObjectStoreUrlDataset,
+    TokioFileDataset,
+};
 use crate::expr::PyExpr;
 use crate::{PyArray, TOKIO_RUNTIME};
 
-/// Read a vortex struct array from the local filesystem.
+/// Read a vortex array from the local filesystem.
 ///
 /// Parameters
 /// ----------
@@ -110,7 +121,7 @@ use crate::{PyArray, TOKIO_RUNTIME};
 /// >>> # e = vortex.io.read_path("a.vortex", projection = ["age", 1, "age"])
 /// >>> # e.to_arrow_array()
 ///
-/// TODO(DK): Top-level nullness does not work.
+/// Read an array with top-level nulls:
 ///
 /// >>> a = vortex.array([
 /// ...     {'name': 'Joseph', 'age': 25},
@@ -121,8 +132,29 @@ use crate::{PyArray, TOKIO_RUNTIME};
 /// ...     {'name': None, 'age': None},
 /// ... ])
 /// >>> vortex.io.write_path(a, "a.vortex")
-/// >>> # b = vortex.io.read_path("a.vortex")
-/// >>> # b.to_arrow_array()
+/// >>> b = vortex.io.read_path("a.vortex")
+/// >>> b.to_arrow_array().is_valid()
+/// <pyarrow.lib.BooleanArray object at ...>
+/// [
+///   true,
+///   true,
+///   true,
+///   false,
+///   true,
+///   true
+/// ]
+///
+/// Read an array that isn't a struct:
+///
+/// >>> vortex.io.write_path(vortex.array([1, 2, None, 4]), "a.vortex")
+/// >>> vortex.io.read_path("a.vortex").to_arrow_array()
+/// <pyarrow.lib.Int64Array object at ...>
+/// [
+///   1,
+///   2,
+///   null,
+///   4
+/// ]
 ///
 #[pyfunction]
 #[pyo3(signature = (path, *, projection = None, row_filter = None))]
@@ -131,7 +163,15 @@ pub fn read_path(
     projection: Option<Vec<Bound<PyAny>>>,
     row_filter: Option<&Bound<PyExpr>>,
 ) -> PyResult<PyArray> {
-    let dataset = TOKIO_RUNTIME.block_on(TokioFileDataset::try_new(path.extract()?))?;
+    read_local(path.extract()?, projection, row_filter)
+}
+
+fn read_local(
+    path: String,
+    projection: Option<Vec<Bound<PyAny>>>,
+    row_filter: Option<&Bound<PyExpr>>,
+) -> PyResult<PyArray> {
+    let dataset = TOKIO_RUNTIME.block_on(TokioFileDataset::try_new(path))?;
     dataset.to_array(projection, None, row_filter)
 }
 
@@ -187,12 +227,63 @@ pub fn read_url(
     dataset.to_array(projection, None, row_filter)
 }
 
-/// Write a vortex struct array to the local filesystem.
+/// Read a vortex array from a local path or a Python file-like object.
+///
+/// .. seealso::
+///     :func:`.read_path`
+///
+/// Parameters
+/// ----------
+/// f : :class:`str` ``|`` :class:`os.PathLike` ``|`` file-like
+///     The file path, or an object with ``read`` and ``seek`` methods such as
+///     :class:`io.BytesIO`.
+/// projection : :class:`list` [ :class:`str` ``|`` :class:`int` ]
+///     The columns to read identified either by their index or name.
+/// row_filter : :class:`.Expr`
+///     Keep only the rows for which this expression evaluates to true.
+///
+/// Examples
+/// --------
+///
+/// Round-trip an array through an in-memory buffer:
+///
+/// >>> import io
+/// >>> buf = io.BytesIO()
+/// >>> vortex.io.write(vortex.array([1, 2, None, 4]), buf)
+/// >>> vortex.io.read(buf).to_arrow_array()
+/// <pyarrow.lib.Int64Array object at ...>
+/// [
+///   1,
+///   2,
+///   null,
+///   4
+/// ]
+///
+#[pyfunction]
+#[pyo3(signature = (f, *, projection = None, row_filter = None))]
+pub fn read(
+    f: &Bound<'_, PyAny>,
+    projection: Option<Vec<Bound<PyAny>>>,
+    row_filter: Option<&Bound<PyExpr>>,
+) -> PyResult<PyArray> {
+    if let Ok(path) = f.extract::<PathBuf>() {
+        return read_local(path_to_string(path)?, projection, row_filter);
+    }
+
+    let reader = PyFileReadAt::try_new(f)?;
+    let projection = projection_from_python(projection)?;
+    let row_filter = row_filter_from_python(row_filter);
+    let array =
+        TOKIO_RUNTIME.block_on(read_array_from_reader(reader, projection, None, row_filter))?;
+    Ok(PyArray::new(array))
+}
+
+/// Write a vortex array to the local filesystem.
 ///
 /// Parameters
 /// ----------
 /// array : :class:`~vortex.encoding.Array`
-///     The array. Must be an array of structures.
+///     The array. Arrays of structures are written with one column per field.
 ///
 /// f : :class:`str`
 ///     The file path.
@@ -221,22 +312,256 @@ pub fn write_path(
     f: &Bound<'_, PyString>,
     compress: bool,
 ) -> PyResult<()> {
-    async fn run(array: &Array, fname: &str) -> PyResult<()> {
-        let file = File::create(Path::new(fname)).await?;
-        let mut writer = LayoutWriter::new(file);
+    write_local(array, Path::new(f.to_str()?), compress)
+}
+
+fn write_local(array: &Bound<'_, PyArray>, path: &Path, compress: bool) -> PyResult<()> {
+    let array = array_to_write(array, compress)?;
+    TOKIO_RUNTIME.block_on(async move { write_array(array, File::create(path).await?).await })?;
+    Ok(())
+}
+
+/// Write a vortex array to a local path or a Python file-like object.
+///
+/// .. seealso::
+///     :func:`.write_path`
+///
+/// Parameters
+/// ----------
+/// array : :class:`~vortex.encoding.Array`
+///     The array. Arrays of structures are written with one column per field.
+///
+/// f : :class:`str` ``|`` :class:`os.PathLike` ``|`` file-like
+///     The file path, or an object with a ``write`` method such as :class:`io.BytesIO`.
+///
+/// compress : :class:`bool`
+///     Compress the array before writing, defaults to ``True``.
+///
+/// Examples
+/// --------
+///
+/// Write an array to an in-memory buffer:
+///
+/// >>> import io
+/// >>> buf = io.BytesIO()
+/// >>> vortex.io.write(vortex.array([{'x': 1}, {'x': 2}]), buf)
+/// >>> buf.getvalue()[-4:]
+/// b'VRTX'
+///
+#[pyfunction]
+#[pyo3(signature = (array, f, *, compress=true))]
+pub fn write(array: &Bound<'_, PyArray>, f: &Bound<'_, PyAny>, compress: bool) -> PyResult<()> {
+    if let Ok(path) = f.extract::<PathBuf>() {
+        return write_local(array, &path, compress);
+    }
+    if !f.hasattr("write")? {
+        return Err(PyTypeError::new_err(format!(
+            "write: expected a path or an object with a write method, but found: {}.",
+            f.get_type()
+        )));
+    }
+
+    let array = array_to_write(array, compress)?;
+    TOKIO_RUNTIME.block_on(async move {
+        let mut writer = write_array(array, PyFileWrite::new(f)).await?;
+        writer.flush().await?;
+        VortexResult::Ok(())
+    })?;
+    Ok(())
+}
+
+/// Write a vortex array to a URL.
+///
+/// .. seealso::
+///     :func:`.read_url`
+///
+/// Parameters
+/// ----------
+/// array : :class:`~vortex.encoding.Array`
+///     The array. Arrays of structures are written with one column per field.
+///
+/// url : :class:`str`
+///     The URL to write to, any scheme supported by :func:`.read_url`.
+///
+/// compress : :class:`bool`
+///     Compress the array before writing, defaults to ``True``.
+///
+/// Examples
+/// --------
+///
+/// Write an array to an S3 URL:
+///
+/// >>> vortex.io.write_url(vortex.array([1, 2, 3]), "s3://bucket/path/to/dataset.vortex")  # doctest: +SKIP
+///
+/// Write an array to a local file URL:
+///
+/// >>> vortex.io.write_url(vortex.array([1, 2, 3]), "file:/path/to/dataset.vortex")  # doctest: +SKIP
+///
+#[pyfunction]
+#[pyo3(signature = (array, url, *, compress=true))]
+pub fn write_url(
+    array: &Bound<'_, PyArray>,
+    url: &Bound<'_, PyString>,
+    compress: bool,
+) -> PyResult<()> {
+    let array = array_to_write(array, compress)?;
+    let (object_store, location) = object_store_from_url(url.to_str()?)?;
+    TOKIO_RUNTIME.block_on(async move {
+        let mut writer = ObjectStoreWriter::new(WriteMultipart::new_with_chunk_size(
+            object_store.put_multipart(&location).await?,
+            10 * 1024 * 1024,
+        ));
+        if let Err(e) = write_array(array, &mut writer).await {
+            // Don't leave an incomplete upload behind
+            if let Err(abort_err) = writer.abort().await {
+                warn!("Failed to abort upload of {location}: {abort_err}");
+            }
+            return Err(e);
+        }
+        // Completes the multipart upload, the object does not exist until then. The upload is
+        // aborted if any of its parts failed.
+        writer.shutdown().await?;
+        VortexResult::Ok(())
+    })?;
+    Ok(())
+}
 
-        writer = writer.write_array_columns(array.clone()).await?;
-        writer.finalize().await?;
+fn array_to_write(array: &Bound<'_, PyArray>, compress: bool) -> PyResult<Array> {
+    let array = array.borrow().unwrap().clone();
+    Ok(if compress {
+        SamplingCompressor::default()
+            .compress(&array, None)?
+            .into_array()
+    } else {
+        array
+    })
+}
+
+async fn write_array<W: VortexWrite>(array: Array, write: W) -> VortexResult<W> {
+    LayoutWriter::new(write)
+        .write_array_columns(array)
+        .await?
+        .finalize()
+        .await
+}
+
+fn path_to_string(path: PathBuf) -> PyResult<String> {
+    path.into_os_string()
+        .into_string()
+        .map_err(|path| PyValueError::new_err(format!("path is not valid unicode: {path:?}")))
+}
+
+/// Writes to a Python file-like object as the file is serialized.
+struct PyFileWrite {
+    file: PyObject,
+}
+
+impl PyFileWrite {
+    fn new(file: &Bound<'_, PyAny>) -> Self {
+        Self {
+            file: file.clone().unbind(),
+        }
+    }
+}
+
+impl VortexWrite for PyFileWrite {
+    async fn write_all<B: IoBuf>(&mut self, buffer: B) -> io::Result<B> {
+        Python::with_gil(|py| {
+            let file = self.file.bind(py);
+            let mut remaining = buffer.as_slice();
+            while !remaining.is_empty() {
+                // Raw files may write only part of the bytes, objects that don't report the
+                // number of bytes written are assumed to write all of them.
+                let written: Option<usize> = file
+                    .call_method1("write", (PyBytes::new_bound(py, remaining),))?
+                    .extract()?;
+                match written {
+                    None => break,
+                    Some(0) => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::WriteZero,
+                            "file-like object did not write any bytes",
+                        )
+                        .into())
+                    }
+                    Some(written) => remaining = &remaining[written.min(remaining.len())..],
+                }
+            }
+            PyResult::Ok(())
+        })?;
+        Ok(buffer)
+    }
+
+    async fn flush(&mut self) -> io::Result<()> {
+        Python::with_gil(|py| {
+            let file = self.file.bind(py);
+            if file.hasattr("flush")? {
+                file.call_method0("flush")?;
+            }
+            PyResult::Ok(())
+        })?;
         Ok(())
     }
 
-    let fname = f.to_str()?; // TODO(dk): support file objects
-    let mut array = array.borrow().unwrap().clone();
+    async fn shutdown(&mut self) -> io::Result<()> {
+        // The file belongs to the caller, it is flushed but not closed
+        self.flush().await
+    }
+}
+
+/// Reads a Python file-like object by seeking to each requested range.
+struct PyFileReadAt {
+    file: PyObject,
+    size: u64,
+}
+
+impl PyFileReadAt {
+    fn try_new(file: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if !file.hasattr("read")? || !file.hasattr("seek")? {
+            return Err(PyTypeError::new_err(format!(
+                "read: expected a path or an object with read and seek methods, but found: {}.",
+                file.get_type()
+            )));
+        }
+        // Seeking to the end returns the size of the file.
+        let size = file.call_method1("seek", (0, 2))?.extract()?;
+        Ok(Self {
+            file: file.clone().unbind(),
+            size,
+        })
+    }
+}
 
-    if compress {
-        let compressor = SamplingCompressor::default();
-        array = compressor.compress(&array, None)?.into_array();
+impl VortexReadAt for PyFileReadAt {
+    async fn read_at_into(&self, pos: u64, mut buffer: BytesMut) -> io::Result<BytesMut> {
+        Python::with_gil(|py| {
+            let file = self.file.bind(py);
+            file.call_method1("seek", (pos,))?;
+            // Reads may return fewer bytes than requested before the end of the file
+            let mut filled = 0;
+            while filled < buffer.len() {
+                let bytes = file.call_method1("read", (buffer.len() - filled,))?;
+                let bytes = bytes.downcast::<PyBytes>()?.as_bytes();
+                if bytes.is_empty() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        format!(
+                            "expected {} bytes at offset {pos}, but read {filled}",
+                            buffer.len(),
+                        ),
+                    )
+                    .into());
+                }
+                let len = bytes.len().min(buffer.len() - filled);
+                buffer[filled..filled + len].copy_from_slice(&bytes[..len]);
+                filled += len;
+            }
+            PyResult::Ok(())
+        })?;
+        Ok(buffer)
     }
 
-    TOKIO_RUNTIME.block_on(run(&array, fname))
+    async fn size(&self) -> u64 {
+        self.size
+    }
 }
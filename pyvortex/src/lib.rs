@@ -65,12 +65,16 @@ fn _lib(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     io.add_function(wrap_pyfunction!(io::read_url, m)?)?;
     io.add_function(wrap_pyfunction!(io::read_path, m)?)?;
     io.add_function(wrap_pyfunction!(io::write_path, m)?)?;
+    io.add_function(wrap_pyfunction!(io::read, m)?)?;
+    io.add_function(wrap_pyfunction!(io::write, m)?)?;
+    io.add_function(wrap_pyfunction!(io::write_url, m)?)?;
 
     let dataset = PyModule::new_bound(py, "dataset")?;
     m.add_submodule(&dataset)?;
 
     dataset.add_function(wrap_pyfunction!(dataset::dataset_from_url, m)?)?;
     dataset.add_function(wrap_pyfunction!(dataset::dataset_from_path, m)?)?;
+    dataset.add_function(wrap_pyfunction!(dataset::dataset_from_glob_url, m)?)?;
 
     let expr = PyModule::new_bound(py, "expr")?;
     m.add_submodule(&expr)?;
@@ -101,3 +101,13 @@ def test_to_record_batch_reader_with_duckdb(ds):
     tbl = duckdb.execute("select string as hi_mom, float as yolo from ds").arrow()
     assert len(tbl) == 1_000_000
     assert tbl.schema == pa.schema([("hi_mom", pa.utf8()), ("yolo", pa.float64())])
+
+
+def test_hive_partitions(tmp_path):
+    for region, numbers in [("eu", [1, 2]), ("us", [3])]:
+        os.makedirs(tmp_path / f"region={region}")
+        vortex.io.write_path(vortex.array([{"number": n} for n in numbers]), str(tmp_path / f"region={region}/a.vortex"))
+
+    ds = vortex.dataset.from_glob_url(f"file://{tmp_path}/**/*.vortex", hive_partitioning=True)
+    assert ds.schema.names == ["number", "region"]
+    assert ds.to_table(filter=pc.field("region") == "us").to_pylist() == [{"number": 3, "region": "us"}]
@@ -0,0 +1,49 @@
+import io
+
+import pyarrow as pa
+import vortex
+
+
+def test_file_like_round_trip():
+    records = [{"x": x, "y": str(x)} for x in range(1000)]
+    buf = io.BytesIO()
+    vortex.io.write(vortex.array(pa.array(records)), buf)
+
+    assert vortex.io.read(buf).to_arrow_array().to_pylist() == records
+    assert vortex.io.read(buf, projection=["y"]).to_arrow_array().to_pylist()[:2] == [{"y": "0"}, {"y": "1"}]
+
+
+def test_path_through_file_functions(tmp_path):
+    path = str(tmp_path / "a.vortex")
+    vortex.io.write(vortex.array([1, 2, None]), path)
+    assert vortex.io.read(path).to_arrow_array().to_pylist() == [1, 2, None]
+
+
+def test_write_url(tmp_path):
+    url = f"file://{tmp_path}/a.vortex"
+    vortex.io.write_url(vortex.array([1, 2, None]), url, compress=False)
+    assert vortex.io.read_url(url).to_arrow_array().to_pylist() == [1, 2, None]
+
+
+def test_path_like(tmp_path):
+    path = tmp_path / "a.vortex"
+    vortex.io.write(vortex.array([1, 2, None]), path)
+    assert vortex.io.read(path).to_arrow_array().to_pylist() == [1, 2, None]
+
+
+class ShortIO(io.BytesIO):
+    """Reads and writes at most a few bytes per call, like a raw file or socket."""
+
+    def read(self, size=-1):
+        return super().read(min(size, 7) if size >= 0 else 7)
+
+    def write(self, b):
+        return super().write(bytes(b)[:7])
+
+
+def test_short_reads_and_writes():
+    records = [{"x": x} for x in range(100)]
+    buf = ShortIO()
+    vortex.io.write(vortex.array(pa.array(records)), buf)
+    assert buf.getvalue()[-4:] == b"VRTX"
+    assert vortex.io.read(buf).to_arrow_array().to_pylist() == records
@@ -5,7 +5,7 @@ use vortex_dtype::{DType, NativePType, Nullability, PType};
 use vortex_error::VortexUnwrap;
 
 use super::{BoolArray, ChunkedArray, NullArray, PrimitiveArray, StructArray};
-use crate::array::{VarBinArray, VarBinViewArray};
+use crate::array::{ListArray, VarBinArray, VarBinViewArray};
 use crate::validity::Validity;
 use crate::{Array, ArrayDType, IntoArray as _, IntoArrayVariant};
 
@@ -80,9 +80,7 @@ fn random_array(u: &mut Unstructured, dtype: &DType, len: Option<usize>) -> Resu
                     .vortex_unwrap()
                     .into_array())
                 }
-                DType::List(..) => {
-                    todo!("List arrays are not implemented")
-                }
+                DType::List(edt, n) => random_list(u, edt, *n, chunk_len),
                 DType::Extension(..) => {
                     todo!("Extension arrays are not implemented")
                 }
@@ -109,6 +107,30 @@ fn split_number_into_parts(n: usize, parts: usize) -> Vec<usize> {
         .collect()
 }
 
+fn random_list(
+    u: &mut Unstructured,
+    element_dtype: &DType,
+    nullability: Nullability,
+    len: Option<usize>,
+) -> Result<Array> {
+    let len = len.map(Ok).unwrap_or_else(|| u.int_in_range(0..=20))?;
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0u64);
+    for _ in 0..len {
+        let list_len = u.int_in_range(0..=5)?;
+        offsets.push(offsets[offsets.len() - 1] + list_len);
+    }
+    let elements = random_array(u, element_dtype, Some(offsets[len] as usize))?;
+    let validity = random_validity(u, nullability, len)?;
+    Ok(ListArray::try_new(
+        elements,
+        PrimitiveArray::from(offsets).into_array(),
+        validity,
+    )
+    .vortex_unwrap()
+    .into_array())
+}
+
 fn random_string(
     u: &mut Unstructured,
     nullability: Nullability,
@@ -97,11 +97,19 @@ impl ArrayVariants for BoolArray {
 
 impl BoolArrayTrait for BoolArray {
     fn maybe_null_indices_iter<'a>(&'a self) -> Box<dyn Iterator<Item = usize> + 'a> {
-        Box::new(BitIndexIterator::new(self.buffer(), 0, self.len()))
+        Box::new(BitIndexIterator::new(
+            self.buffer(),
+            self.metadata().first_byte_bit_offset as usize,
+            self.len(),
+        ))
     }
 
     fn maybe_null_slices_iter<'a>(&'a self) -> Box<dyn Iterator<Item = (usize, usize)> + 'a> {
-        Box::new(BitSliceIterator::new(self.buffer(), 0, self.len()))
+        Box::new(BitSliceIterator::new(
+            self.buffer(),
+            self.metadata().first_byte_bit_offset as usize,
+            self.len(),
+        ))
     }
 }
 
@@ -164,6 +172,7 @@ mod tests {
     use itertools::Itertools;
 
     use crate::array::BoolArray;
+    use crate::compute::slice;
     use crate::compute::unary::scalar_at;
     use crate::validity::Validity;
     use crate::variants::BoolArrayTrait;
@@ -234,4 +243,12 @@ mod tests {
         assert_eq!(0, arr.maybe_null_indices_iter().collect_vec().len());
         assert_eq!(0, arr.maybe_null_slices_iter().collect_vec().len());
     }
+
+    #[test]
+    fn sliced_iter_test() {
+        let arr = BoolArray::from(vec![true, false, false, true, true, false]);
+        let sliced = BoolArray::try_from(slice(arr.as_ref(), 2, 6).unwrap()).unwrap();
+        assert_eq!(vec![1, 2], sliced.maybe_null_indices_iter().collect_vec());
+        assert_eq!(vec![(1, 3)], sliced.maybe_null_slices_iter().collect_vec());
+    }
 }
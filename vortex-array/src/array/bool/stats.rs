@@ -118,7 +118,10 @@ impl BoolStatsAccumulator {
 
     pub fn finish(self) -> StatsSet {
         StatsSet::from(HashMap::from([
-            (Stat::Min, (self.true_count == self.len).into()),
+            (
+                Stat::Min,
+                (self.true_count + self.null_count == self.len).into(),
+            ),
             (Stat::Max, (self.true_count > 0).into()),
             (
                 Stat::IsConstant,
@@ -200,6 +203,9 @@ mod test {
         assert!(bool_arr.statistics().compute_max::<bool>().unwrap());
         assert_eq!(bool_arr.statistics().compute_run_count().unwrap(), 3);
         assert_eq!(bool_arr.statistics().compute_true_count().unwrap(), 2);
+
+        let bool_arr = BoolArray::from_iter(vec![Some(true), None, Some(true)]);
+        assert!(bool_arr.statistics().compute_min::<bool>().unwrap());
     }
 
     #[test]
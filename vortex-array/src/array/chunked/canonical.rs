@@ -7,7 +7,8 @@ use crate::array::extension::ExtensionArray;
 use crate::array::null::NullArray;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::struct_::StructArray;
-use crate::array::{BinaryView, BoolArray, VarBinViewArray};
+use crate::array::{BinaryView, BoolArray, ListArray, VarBinViewArray};
+use crate::compute::unary::try_cast;
 use crate::validity::Validity;
 use crate::{
     Array, ArrayDType, ArrayValidity, Canonical, IntoArray, IntoArrayVariant, IntoCanonical,
@@ -92,9 +93,9 @@ pub(crate) fn try_canonicalize_chunks(
             )))
         }
 
-        // TODO(aduffy): better list support
-        DType::List(..) => {
-            todo!()
+        DType::List(element_dtype, _) => {
+            let list_array = pack_lists(chunks.as_slice(), element_dtype, validity)?;
+            Ok(Canonical::List(list_array))
         }
 
         DType::Bool(_) => {
@@ -147,6 +148,39 @@ fn swizzle_struct_chunks(
     StructArray::try_new(struct_dtype.names().clone(), field_arrays, len, validity)
 }
 
+/// Builds a new [ListArray] by concatenating the offsets of the chunks, rebased onto a single
+/// [ChunkedArray] of the chunks' elements.
+///
+/// The elements themselves are never copied or decompressed, only the offsets are rewritten.
+///
+/// It is expected this function is only called from [try_canonicalize_chunks], and thus all chunks have
+/// been checked to have the same DType already.
+fn pack_lists(
+    chunks: &[Array],
+    element_dtype: &DType,
+    validity: Validity,
+) -> VortexResult<ListArray> {
+    let len: usize = chunks.iter().map(|chunk| chunk.len()).sum();
+    let mut offsets: Vec<u64> = Vec::with_capacity(len + 1);
+    offsets.push(0);
+    let mut elements = Vec::with_capacity(chunks.len());
+    for chunk in chunks {
+        let chunk = chunk.clone().into_list()?;
+        let chunk_offsets = try_cast(chunk.offsets(), PType::U64.into())?.into_primitive()?;
+        let chunk_offsets = chunk_offsets.maybe_null_slice::<u64>();
+        let base = offsets.last().copied().unwrap_or_default();
+        let first = chunk_offsets.first().copied().unwrap_or_default();
+        offsets.extend(chunk_offsets.iter().skip(1).map(|o| o - first + base));
+        elements.push(chunk.sliced_elements()?);
+    }
+
+    ListArray::try_new(
+        ChunkedArray::try_new(elements, element_dtype.clone())?.into_array(),
+        PrimitiveArray::from(offsets).into_array(),
+        validity,
+    )
+}
+
 /// Builds a new [BoolArray] by repacking the values from the chunks in a single contiguous array.
 ///
 /// It is expected this function is only called from [try_canonicalize_chunks], and thus all chunks have
@@ -241,8 +275,9 @@ mod tests {
 
     use crate::accessor::ArrayAccessor;
     use crate::array::chunked::canonical::pack_views;
-    use crate::array::{ChunkedArray, StructArray, VarBinViewArray};
+    use crate::array::{ChunkedArray, ListArray, PrimitiveArray, StructArray, VarBinViewArray};
     use crate::compute::slice;
+    use crate::compute::unary::scalar_at;
     use crate::validity::Validity;
     use crate::variants::StructArrayTrait;
     use crate::{ArrayDType, IntoArray, IntoArrayVariant, ToArray};
@@ -272,6 +307,42 @@ mod tests {
         assert_eq!(values, &["bar", "baz", "baz", "quak"]);
     }
 
+    #[test]
+    pub fn pack_sliced_lists() {
+        let list = ListArray::try_new(
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5]).into_array(),
+            PrimitiveArray::from(vec![0u32, 2, 3, 5]).into_array(),
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+        let dtype = list.dtype().clone();
+        let chunked = ChunkedArray::try_new(
+            vec![slice(&list, 1, 3).unwrap(), list.clone()],
+            dtype.clone(),
+        )
+        .unwrap()
+        .into_array();
+
+        let packed = chunked.into_list().unwrap();
+        assert_eq!(packed.dtype(), &dtype);
+        assert_eq!(
+            packed
+                .offsets()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            [0, 1, 3, 5, 6, 8]
+        );
+        let values = (0..packed.len())
+            .map(|i| Vec::<i32>::try_from(&scalar_at(packed.as_ref(), i).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![vec![3], vec![4, 5], vec![1, 2], vec![3], vec![4, 5]]
+        );
+    }
+
     #[test]
     pub fn pack_nested_structs() {
         let struct_array = StructArray::try_new(
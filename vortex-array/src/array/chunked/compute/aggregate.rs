@@ -0,0 +1,66 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::chunked::ChunkedArray;
+use crate::compute::{
+    count_valid, min_max, sum, CountValidFn, MinMaxFn, MinMaxResult, SumAccumulator, SumFn,
+};
+use crate::ArrayDType;
+
+impl SumFn for ChunkedArray {
+    fn sum(&self) -> VortexResult<Scalar> {
+        let mut acc = SumAccumulator::try_new(self.dtype())?;
+        for chunk in self.chunks() {
+            acc.add_scalar(&sum(&chunk)?, 1)?;
+        }
+        Ok(acc.finish())
+    }
+}
+
+impl MinMaxFn for ChunkedArray {
+    fn min_max(&self) -> VortexResult<Option<MinMaxResult>> {
+        self.chunks().try_fold(None, |acc, chunk| {
+            Ok(MinMaxResult::merge(acc, min_max(&chunk)?))
+        })
+    }
+}
+
+impl CountValidFn for ChunkedArray {
+    fn count_valid(&self) -> VortexResult<usize> {
+        self.chunks()
+            .map(|chunk| count_valid(&chunk))
+            .sum::<VortexResult<usize>>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ChunkedArray, ConstantArray, PrimitiveArray};
+    use crate::compute::{min_max, sum};
+    use crate::IntoArray;
+
+    #[test]
+    fn aggregates() {
+        let dtype = DType::Primitive(PType::I32, Nullability::Nullable);
+        let chunked = ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![Some(-3), None, Some(8)]).into_array(),
+                ConstantArray::new(Scalar::null(dtype.clone()), 5).into_array(),
+                ConstantArray::new(Scalar::primitive(2, Nullability::Nullable), 4).into_array(),
+            ],
+            dtype,
+        )
+        .unwrap();
+
+        assert_eq!(
+            sum(chunked.as_ref()).unwrap(),
+            Scalar::primitive(13i64, Nullability::Nullable)
+        );
+        let min_max = min_max(chunked.as_ref()).unwrap().unwrap();
+        assert_eq!(min_max.min, Scalar::primitive(-3, Nullability::NonNullable));
+        assert_eq!(min_max.max, Scalar::primitive(8, Nullability::NonNullable));
+    }
+}
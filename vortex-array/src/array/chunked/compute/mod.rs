@@ -4,16 +4,27 @@ use vortex_error::VortexResult;
 use crate::array::chunked::ChunkedArray;
 use crate::compute::unary::{try_cast, CastFn, ScalarAtFn, SubtractScalarFn};
 use crate::compute::{
-    compare, slice, ArrayCompute, CompareFn, FilterFn, Operator, SliceFn, TakeFn,
+    arithmetic, compare, slice, ArithmeticOperator, ArithmeticOptions, ArrayCompute, CompareFn,
+    CountValidFn, FilterFn, MinMaxFn, Operator, SliceFn, SumFn, TakeFn,
 };
-use crate::{Array, IntoArray};
+use crate::{Array, ArrayDType, IntoArray};
 
+mod aggregate;
 mod filter;
 mod scalar_at;
 mod slice;
 mod take;
 
 impl ArrayCompute for ChunkedArray {
+    fn arithmetic(
+        &self,
+        other: &Array,
+        operator: ArithmeticOperator,
+        options: ArithmeticOptions,
+    ) -> Option<VortexResult<Array>> {
+        Some(chunked_arithmetic(self, other, operator, options))
+    }
+
     fn cast(&self) -> Option<&dyn CastFn> {
         Some(self)
     }
@@ -22,10 +33,22 @@ impl ArrayCompute for ChunkedArray {
         Some(CompareFn::compare(self, other, operator))
     }
 
+    fn count_valid(&self) -> Option<&dyn CountValidFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn subtract_scalar(&self) -> Option<&dyn SubtractScalarFn> {
         Some(self)
     }
@@ -71,13 +94,39 @@ impl CompareFn for ChunkedArray {
     }
 }
 
+fn chunked_arithmetic(
+    array: &ChunkedArray,
+    other: &Array,
+    operator: ArithmeticOperator,
+    options: ArithmeticOptions,
+) -> VortexResult<Array> {
+    let mut idx = 0;
+    let mut chunks = Vec::with_capacity(array.nchunks());
+
+    for chunk in array.chunks() {
+        let sliced = slice(other, idx, idx + chunk.len())?;
+        chunks.push(arithmetic(&chunk, &sliced, operator, options)?);
+
+        idx += chunk.len();
+    }
+
+    let nullability = if other.dtype().is_nullable() {
+        Nullability::Nullable
+    } else {
+        array.dtype().nullability()
+    };
+    Ok(ChunkedArray::try_new(chunks, array.dtype().with_nullability(nullability))?.into_array())
+}
+
 #[cfg(test)]
 mod test {
     use vortex_dtype::{DType, Nullability, PType};
 
     use crate::array::chunked::ChunkedArray;
     use crate::array::primitive::PrimitiveArray;
+    use crate::array::ConstantArray;
     use crate::compute::unary::try_cast;
+    use crate::compute::{arithmetic, ArithmeticOperator, ArithmeticOptions};
     use crate::validity::Validity;
     use crate::{IntoArray, IntoArrayVariant};
 
@@ -113,4 +162,31 @@ mod test {
             vec![0u64, 1, 2, 3],
         );
     }
+
+    #[test]
+    fn test_arithmetic_chunked() {
+        let chunked = ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![Some(1i64), None]).into_array(),
+                PrimitiveArray::from_nullable_vec(vec![Some(3i64)]).into_array(),
+            ],
+            DType::Primitive(PType::I64, Nullability::Nullable),
+        )
+        .unwrap()
+        .into_array();
+
+        let result = arithmetic(
+            &chunked,
+            ConstantArray::new(10i64, chunked.len()),
+            ArithmeticOperator::Sub,
+            ArithmeticOptions::default(),
+        )
+        .unwrap();
+        assert!(ChunkedArray::try_from(&result).is_ok());
+
+        let result = result.into_primitive().unwrap();
+        assert_eq!(result.maybe_null_slice::<i64>()[0], -9);
+        assert_eq!(result.maybe_null_slice::<i64>()[2], -7);
+        assert!(!result.validity().is_valid(1));
+    }
 }
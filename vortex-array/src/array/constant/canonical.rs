@@ -1,13 +1,15 @@
+use std::iter;
+
 use arrow_array::builder::make_view;
-use arrow_buffer::{BooleanBuffer, BufferBuilder};
+use arrow_buffer::{i256, BooleanBuffer, BufferBuilder};
 use vortex_buffer::Buffer;
 use vortex_dtype::{match_each_native_ptype, DType, Nullability, PType};
 use vortex_error::{vortex_bail, VortexResult};
-use vortex_scalar::{BinaryScalar, BoolScalar, Utf8Scalar};
+use vortex_scalar::{BinaryScalar, BoolScalar, DecimalScalar, Utf8Scalar};
 
 use crate::array::constant::ConstantArray;
 use crate::array::primitive::PrimitiveArray;
-use crate::array::{BinaryView, BoolArray, VarBinViewArray, VIEW_SIZE_BYTES};
+use crate::array::{BinaryView, BoolArray, DecimalArray, VarBinViewArray, VIEW_SIZE_BYTES};
 use crate::validity::Validity;
 use crate::{ArrayDType, Canonical, IntoArray, IntoCanonical};
 
@@ -59,6 +61,17 @@ impl IntoCanonical for ConstantArray {
             });
         }
 
+        if let Ok(d) = DecimalScalar::try_from(scalar) {
+            return Ok(Canonical::Extension(
+                DecimalArray::from_i256(
+                    iter::repeat(d.value().unwrap_or(i256::ZERO)).take(self.len()),
+                    validity,
+                    d.metadata(),
+                )?
+                .into(),
+            ));
+        }
+
         vortex_bail!("Unsupported scalar type {}", self.dtype())
     }
 }
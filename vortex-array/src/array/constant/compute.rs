@@ -7,8 +7,9 @@ use vortex_scalar::Scalar;
 use crate::array::constant::ConstantArray;
 use crate::compute::unary::{scalar_at, ScalarAtFn};
 use crate::compute::{
-    scalar_cmp, AndFn, ArrayCompute, FilterFn, MaybeCompareFn, Operator, OrFn, SearchResult,
-    SearchSortedFn, SearchSortedSide, SliceFn, TakeFn,
+    scalar_cmp, AndFn, ArrayCompute, CountValidFn, FilterFn, MaybeCompareFn, MinMaxFn,
+    MinMaxResult, Operator, OrFn, SearchResult, SearchSortedFn, SearchSortedSide, SliceFn,
+    SumAccumulator, SumFn, TakeFn,
 };
 use crate::stats::{ArrayStatistics, Stat};
 use crate::{Array, ArrayDType, IntoArray};
@@ -18,10 +19,18 @@ impl ArrayCompute for ConstantArray {
         MaybeCompareFn::maybe_compare(self, other, operator)
     }
 
+    fn count_valid(&self) -> Option<&dyn CountValidFn> {
+        Some(self)
+    }
+
     fn filter(&self) -> Option<&dyn FilterFn> {
         Some(self)
     }
 
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -34,6 +43,10 @@ impl ArrayCompute for ConstantArray {
         Some(self)
     }
 
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
@@ -119,6 +132,37 @@ impl MaybeCompareFn for ConstantArray {
     }
 }
 
+impl SumFn for ConstantArray {
+    fn sum(&self) -> VortexResult<Scalar> {
+        let mut acc = SumAccumulator::try_new(self.dtype())?;
+        acc.add_scalar(&self.owned_scalar(), self.len())?;
+        Ok(acc.finish())
+    }
+}
+
+impl MinMaxFn for ConstantArray {
+    fn min_max(&self) -> VortexResult<Option<MinMaxResult>> {
+        if self.is_empty() || self.scalar_value().is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(MinMaxResult {
+            min: self.owned_scalar(),
+            max: self.owned_scalar(),
+        }))
+    }
+}
+
+impl CountValidFn for ConstantArray {
+    fn count_valid(&self) -> VortexResult<usize> {
+        Ok(if self.scalar_value().is_null() {
+            0
+        } else {
+            self.len()
+        })
+    }
+}
+
 impl AndFn for ConstantArray {
     fn and(&self, array: &Array) -> VortexResult<Array> {
         constant_array_bool_impl(
@@ -170,11 +214,15 @@ fn constant_array_bool_impl(
 #[cfg(test)]
 mod test {
     use rstest::rstest;
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
 
     use crate::array::constant::ConstantArray;
     use crate::array::BoolArray;
     use crate::compute::unary::scalar_at;
-    use crate::compute::{and, or, search_sorted, SearchResult, SearchSortedSide};
+    use crate::compute::{
+        and, count_valid, mean, min_max, or, search_sorted, sum, SearchResult, SearchSortedSide,
+    };
     use crate::{Array, IntoArray, IntoArrayVariant};
 
     #[test]
@@ -203,6 +251,41 @@ mod test {
         );
     }
 
+    #[test]
+    fn aggregates() {
+        let cst = ConstantArray::new(Scalar::primitive(7u8, Nullability::Nullable), 1000);
+        assert_eq!(
+            sum(cst.as_ref()).unwrap(),
+            Scalar::primitive(7000u64, Nullability::Nullable)
+        );
+        assert_eq!(
+            mean(cst.as_ref()).unwrap(),
+            Scalar::primitive(7.0f64, Nullability::Nullable)
+        );
+        assert_eq!(count_valid(cst.as_ref()).unwrap(), 1000);
+
+        let min_max = min_max(cst.as_ref()).unwrap().unwrap();
+        assert_eq!(
+            min_max.min,
+            Scalar::primitive(7u8, Nullability::NonNullable)
+        );
+        assert_eq!(
+            min_max.max,
+            Scalar::primitive(7u8, Nullability::NonNullable)
+        );
+    }
+
+    #[test]
+    fn aggregates_null() {
+        let cst = ConstantArray::new(
+            Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable)),
+            10,
+        );
+        assert!(sum(cst.as_ref()).unwrap().is_null());
+        assert!(min_max(cst.as_ref()).unwrap().is_none());
+        assert_eq!(count_valid(cst.as_ref()).unwrap(), 0);
+    }
+
     #[rstest]
     #[case(ConstantArray::new(true, 4).into_array(), BoolArray::from_iter([Some(true), Some(false), Some(true), Some(false)].into_iter()).into_array())]
     #[case(BoolArray::from_iter([Some(true), Some(false), Some(true), Some(false)].into_iter()).into_array(), ConstantArray::new(true, 4).into_array())]
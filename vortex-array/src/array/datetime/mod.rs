@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod test;
 
-use vortex_datetime_dtype::{TemporalMetadata, TimeUnit, DATE_ID, TIMESTAMP_ID, TIME_ID};
+use vortex_datetime_dtype::{
+    IntervalUnit, TemporalMetadata, TimeUnit, DATE_ID, DURATION_ID, INTERVAL_ID, TIMESTAMP_ID,
+    TIME_ID,
+};
 use vortex_dtype::{DType, ExtDType};
 use vortex_error::{vortex_panic, VortexError};
 
@@ -12,7 +15,8 @@ use crate::{Array, ArrayDType, IntoArray};
 /// An array wrapper for primitive values that have an associated temporal meaning.
 ///
 /// This is a wrapper around ExtensionArrays containing numeric types, each of which corresponds to
-/// either a timestamp or julian date (both referenced to UNIX epoch), OR a time since midnight.
+/// either a timestamp or julian date (both referenced to UNIX epoch), a time since midnight, OR
+/// an elapsed duration or calendar interval.
 ///
 /// ## Arrow compatibility
 ///
@@ -22,6 +26,8 @@ use crate::{Array, ArrayDType, IntoArray};
 /// * `Timestamp`
 /// * `Date32`
 /// * `Date64`
+/// * `Duration`
+/// * `Interval`
 ///
 /// Anything that can be constructed and held in a `TemporalArray` can also be zero-copy converted
 /// back to the relevant Arrow datatype.
@@ -151,6 +157,59 @@ impl TemporalArray {
             temporal_metadata,
         }
     }
+
+    /// Create a new `TemporalArray` holding Arrow spec compliant Duration data, i.e. `i64` values
+    /// counting elapsed time in the given unit.
+    ///
+    /// # Panics
+    ///
+    /// If `array` does not hold Primitive i64 data, the function will panic.
+    ///
+    /// If the time_unit is days, the function will panic.
+    pub fn new_duration(array: Array, time_unit: TimeUnit) -> Self {
+        assert_width!(i64, array);
+        if time_unit == TimeUnit::D {
+            vortex_panic!("invalid unit D for vortex.duration data");
+        }
+
+        let temporal_metadata = TemporalMetadata::Duration(time_unit);
+        Self {
+            ext: ExtensionArray::new(
+                ExtDType::new(DURATION_ID.clone(), Some(temporal_metadata.clone().into())),
+                array,
+            ),
+            temporal_metadata,
+        }
+    }
+
+    /// Create a new `TemporalArray` holding Arrow spec compliant Interval data.
+    ///
+    /// Year-month intervals are stored as `i32` months, day-time and month-day-nano intervals as
+    /// a struct with one field per component, see [`IntervalUnit::storage_dtype`].
+    ///
+    /// # Panics
+    ///
+    /// If `array` does not have the storage dtype of the interval unit, the function will panic.
+    pub fn new_interval(array: Array, interval_unit: IntervalUnit) -> Self {
+        let expected = interval_unit.storage_dtype(array.dtype().nullability());
+        assert_eq!(
+            array.dtype(),
+            &expected,
+            "invalid dtype {} for {} interval, expected {}",
+            array.dtype(),
+            interval_unit,
+            expected
+        );
+
+        let temporal_metadata = TemporalMetadata::Interval(interval_unit);
+        Self {
+            ext: ExtensionArray::new(
+                ExtDType::new(INTERVAL_ID.clone(), Some(temporal_metadata.clone().into())),
+                array,
+            ),
+            temporal_metadata,
+        }
+    }
 }
 
 impl TemporalArray {
@@ -1,8 +1,17 @@
-use vortex_datetime_dtype::{TemporalMetadata, TimeUnit};
+use std::sync::Arc;
 
-use crate::array::{PrimitiveArray, TemporalArray};
+use arrow_array::{
+    ArrayRef, DurationMicrosecondArray, IntervalDayTimeArray, IntervalMonthDayNanoArray,
+    IntervalYearMonthArray,
+};
+use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano};
+use vortex_datetime_dtype::{IntervalUnit, TemporalMetadata, TimeUnit};
+use vortex_dtype::FieldNames;
+
+use crate::array::{PrimitiveArray, StructArray, TemporalArray};
+use crate::arrow::FromArrowArray;
 use crate::validity::Validity;
-use crate::{IntoArray, IntoArrayVariant};
+use crate::{Array, IntoArray, IntoArrayVariant, IntoCanonical};
 
 macro_rules! test_temporal_roundtrip {
     ($prim:ty, $constructor:expr, $unit:expr) => {{
@@ -15,7 +24,7 @@ macro_rules! test_temporal_roundtrip {
             prims.maybe_null_slice::<$prim>(),
             vec![100 as $prim].as_slice(),
         );
-        assert_eq!(temporal.temporal_metadata().time_unit(), $unit);
+        assert_eq!(temporal.temporal_metadata().time_unit(), Some($unit));
     }};
 }
 
@@ -142,3 +151,79 @@ fn test_timestamp_fails_i32() {
 
     let _ = TemporalArray::new_timestamp(ts_array.clone(), TimeUnit::S, None);
 }
+
+// Duration conformance tests
+test_success_case!(
+    test_roundtrip_duration,
+    i64,
+    TemporalArray::new_duration,
+    TimeUnit::Us
+);
+test_fail_case!(
+    test_fail_duration,
+    i32,
+    TemporalArray::new_duration,
+    TimeUnit::Us
+);
+
+#[test]
+fn test_interval() {
+    let months = PrimitiveArray::from_vec(vec![1i32, 14], Validity::NonNullable).into_array();
+    let interval = TemporalArray::new_interval(months, IntervalUnit::YearMonth);
+    assert_eq!(
+        interval.temporal_metadata(),
+        &TemporalMetadata::Interval(IntervalUnit::YearMonth)
+    );
+
+    let day_time = StructArray::try_new(
+        FieldNames::from(["days".into(), "milliseconds".into()]),
+        vec![
+            PrimitiveArray::from(vec![1i32, 2]).into_array(),
+            PrimitiveArray::from(vec![500i32, 0]).into_array(),
+        ],
+        2,
+        Validity::AllValid,
+    )
+    .unwrap();
+    let interval = TemporalArray::new_interval(day_time.into_array(), IntervalUnit::DayTime);
+    assert_eq!(
+        interval.temporal_metadata().interval_unit(),
+        Some(IntervalUnit::DayTime)
+    );
+    assert_eq!(interval.temporal_metadata().time_unit(), None);
+    assert_eq!(interval.temporal_values().len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_interval_fails_i64() {
+    let array = PrimitiveArray::from_vec(vec![100i64], Validity::NonNullable).into_array();
+    let _ = TemporalArray::new_interval(array, IntervalUnit::DayTime);
+}
+
+#[test]
+fn test_arrow_duration_interval_roundtrip() {
+    let arrays: Vec<ArrayRef> = vec![
+        Arc::new(DurationMicrosecondArray::from(vec![
+            Some(-1i64),
+            None,
+            Some(1_000_000),
+        ])),
+        Arc::new(IntervalYearMonthArray::from(vec![Some(13), None])),
+        Arc::new(IntervalDayTimeArray::from(vec![
+            Some(IntervalDayTime::new(1, 500)),
+            None,
+        ])),
+        Arc::new(IntervalMonthDayNanoArray::from(vec![
+            None,
+            Some(IntervalMonthDayNano::new(1, -2, 3)),
+        ])),
+    ];
+
+    for arrow in arrays {
+        let array = Array::from_arrow(arrow.clone(), true);
+        assert!(TemporalArray::try_from(&array).is_ok());
+        let round_tripped = array.into_canonical().unwrap().into_arrow().unwrap();
+        assert_eq!(&round_tripped, &arrow);
+    }
+}
@@ -0,0 +1,354 @@
+use arrow_buffer::i256;
+use vortex_dtype::{DecimalMetadata, ExtDType, FieldNames, PType, DECIMAL_WORD_NAMES};
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+use vortex_scalar::{decimal_from_words, decimal_to_words, precision_bound};
+
+use crate::array::{ExtensionArray, PrimitiveArray, StructArray};
+use crate::validity::Validity;
+use crate::variants::ExtensionArrayTrait;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+/// An array wrapper for the unscaled integers of the `vortex.decimal` extension type.
+///
+/// Decimals with a precision of up to 18 digits are stored as a single `i64` primitive array.
+/// Wider decimals are stored as a struct of 64-bit words, see [`DecimalMetadata`] for details.
+///
+/// ## Arrow compatibility
+///
+/// DecimalArray can be created from Arrow `Decimal128` and `Decimal256` arrays. It is converted
+/// back into `Decimal128` for precisions up to 38 digits, and into `Decimal256` otherwise.
+#[derive(Clone, Debug)]
+pub struct DecimalArray {
+    /// The underlying Vortex extension array holding the unscaled values.
+    ext: ExtensionArray,
+
+    /// In-memory representation of the ExtMetadata that is held by the underlying extension array.
+    decimal_metadata: DecimalMetadata,
+}
+
+impl DecimalArray {
+    /// Wrap an array of unscaled values, which must have the storage dtype of the decimal.
+    pub fn try_new(
+        unscaled_values: Array,
+        decimal_metadata: DecimalMetadata,
+    ) -> VortexResult<Self> {
+        let expected = decimal_metadata.storage_dtype(unscaled_values.dtype().nullability());
+        if unscaled_values.dtype() != &expected {
+            vortex_bail!(
+                "Invalid storage dtype {} for {}, expected {}",
+                unscaled_values.dtype(),
+                decimal_metadata,
+                expected
+            );
+        }
+
+        Ok(Self {
+            ext: ExtensionArray::new(decimal_metadata.ext_dtype(), unscaled_values),
+            decimal_metadata,
+        })
+    }
+
+    /// Create a new `DecimalArray` from unscaled 256-bit values.
+    ///
+    /// Fails if the absolute value of any element, including null slots, is not below
+    /// `10^precision`.
+    pub fn from_i256<I: IntoIterator<Item = i256>>(
+        values: I,
+        validity: Validity,
+        decimal_metadata: DecimalMetadata,
+    ) -> VortexResult<Self> {
+        let bound = precision_bound(decimal_metadata.precision())?;
+        let num_words = decimal_metadata.storage_words();
+        let mut words: Vec<Vec<u64>> = vec![Vec::new(); num_words];
+        for value in values {
+            if value >= bound || value <= bound.wrapping_neg() {
+                vortex_bail!("Decimal {} does not fit into {}", value, decimal_metadata);
+            }
+            for (column, word) in words.iter_mut().zip(decimal_to_words(value, num_words)?) {
+                column.push(word);
+            }
+        }
+        let len = words[0].len();
+
+        let mut columns = words.into_iter();
+        let most_significant = columns
+            .next()
+            .map(|w| w.into_iter().map(|w| w as i64).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let unscaled_values = if num_words == 1 {
+            PrimitiveArray::from_vec(most_significant, validity).into_array()
+        } else {
+            StructArray::try_new(
+                FieldNames::from(
+                    DECIMAL_WORD_NAMES[..num_words]
+                        .iter()
+                        .map(|name| (*name).into())
+                        .collect::<Vec<_>>(),
+                ),
+                [PrimitiveArray::from(most_significant).into_array()]
+                    .into_iter()
+                    .chain(columns.map(|w| PrimitiveArray::from(w).into_array()))
+                    .collect(),
+                len,
+                validity,
+            )?
+            .into_array()
+        };
+
+        Self::try_new(unscaled_values, decimal_metadata)
+    }
+
+    /// Create a new `DecimalArray` from unscaled 128-bit values.
+    pub fn from_i128<I: IntoIterator<Item = i128>>(
+        values: I,
+        validity: Validity,
+        decimal_metadata: DecimalMetadata,
+    ) -> VortexResult<Self> {
+        Self::from_i256(
+            values.into_iter().map(i256::from_i128),
+            validity,
+            decimal_metadata,
+        )
+    }
+
+    /// Access the unscaled values in the underlying ExtensionArray storage.
+    pub fn unscaled_values(&self) -> Array {
+        self.ext.storage()
+    }
+
+    /// Decode the unscaled value of every element. Null elements decode to an unspecified value.
+    pub fn unscaled_i256(&self) -> VortexResult<Vec<i256>> {
+        let storage = self.unscaled_values();
+        if self.decimal_metadata.storage_words() == 1 {
+            return Ok(storage
+                .into_primitive()?
+                .maybe_null_slice::<i64>()
+                .iter()
+                .map(|v| i256::from_i128(*v as i128))
+                .collect());
+        }
+
+        let storage = storage.into_struct()?;
+        let words = storage
+            .children()
+            .map(|w| Ok(w.into_primitive()?.reinterpret_cast(PType::U64)))
+            .collect::<VortexResult<Vec<_>>>()?;
+        let mut row = vec![0u64; words.len()];
+        Ok((0..storage.len())
+            .map(|i| {
+                for (word, column) in row.iter_mut().zip(words.iter()) {
+                    *word = column.maybe_null_slice::<u64>()[i];
+                }
+                decimal_from_words(&row)
+            })
+            .collect())
+    }
+
+    /// Retrieve the decimal metadata.
+    pub fn decimal_metadata(&self) -> DecimalMetadata {
+        self.decimal_metadata
+    }
+
+    /// Retrieve the extension DType associated with the underlying array.
+    pub fn ext_dtype(&self) -> &ExtDType {
+        self.ext.ext_dtype()
+    }
+}
+
+impl From<DecimalArray> for Array {
+    fn from(value: DecimalArray) -> Self {
+        value.ext.into_array()
+    }
+}
+
+impl TryFrom<&Array> for DecimalArray {
+    type Error = VortexError;
+
+    /// Try to specialize a generic Vortex array as a DecimalArray.
+    fn try_from(value: &Array) -> Result<Self, Self::Error> {
+        Self::try_from(ExtensionArray::try_from(value)?)
+    }
+}
+
+impl TryFrom<Array> for DecimalArray {
+    type Error = VortexError;
+
+    fn try_from(value: Array) -> Result<Self, Self::Error> {
+        DecimalArray::try_from(&value)
+    }
+}
+
+impl From<DecimalArray> for ExtensionArray {
+    fn from(value: DecimalArray) -> Self {
+        value.ext
+    }
+}
+
+impl TryFrom<ExtensionArray> for DecimalArray {
+    type Error = VortexError;
+
+    fn try_from(ext: ExtensionArray) -> Result<Self, Self::Error> {
+        let decimal_metadata = DecimalMetadata::try_from(ext.ext_dtype())?;
+        Ok(Self {
+            ext,
+            decimal_metadata,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Decimal128Type, Decimal256Type};
+    use arrow_array::{ArrayRef, Decimal128Array, Decimal256Array};
+    use arrow_buffer::i256;
+    use vortex_dtype::{DType, DecimalMetadata, Nullability, PType};
+    use vortex_scalar::{DecimalScalar, Scalar};
+
+    use crate::array::{ConstantArray, DecimalArray};
+    use crate::arrow::FromArrowArray;
+    use crate::compute::unary::{scalar_at, try_cast};
+    use crate::compute::{compare, Operator};
+    use crate::validity::Validity;
+    use crate::{Array, ArrayDType, IntoArrayVariant, IntoCanonical};
+
+    #[test]
+    fn narrow_decimal() {
+        let metadata = DecimalMetadata::try_new(10, 2).unwrap();
+        let array =
+            DecimalArray::from_i128([123i128, -456, 0], Validity::AllValid, metadata).unwrap();
+        assert!(matches!(
+            array.unscaled_values().dtype(),
+            DType::Primitive(PType::I64, _)
+        ));
+        assert_eq!(
+            array.unscaled_i256().unwrap(),
+            vec![
+                i256::from_i128(123),
+                i256::from_i128(-456),
+                i256::from_i128(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn wide_decimal() {
+        let metadata = DecimalMetadata::try_new(38, 4).unwrap();
+        let values = [i128::MAX / 7, -1, i128::MIN / 3];
+        let array = DecimalArray::from_i128(values, Validity::NonNullable, metadata).unwrap();
+        assert_eq!(
+            array.unscaled_i256().unwrap(),
+            values.map(i256::from_i128).to_vec()
+        );
+
+        let scalar = scalar_at(Array::from(array), 1).unwrap();
+        assert_eq!(
+            DecimalScalar::try_from(&scalar).unwrap().value(),
+            Some(i256::from_i128(-1))
+        );
+    }
+
+    #[test]
+    fn out_of_range_decimal() {
+        let metadata = DecimalMetadata::try_new(18, 0).unwrap();
+        assert!(DecimalArray::from_i128([i128::MAX], Validity::NonNullable, metadata).is_err());
+        // Fits into the i64 storage, but has 19 digits.
+        assert!(
+            DecimalArray::from_i128([-(10i128.pow(18))], Validity::NonNullable, metadata).is_err()
+        );
+        assert!(
+            DecimalArray::from_i128([10i128.pow(18) - 1], Validity::NonNullable, metadata).is_ok()
+        );
+    }
+
+    #[test]
+    fn arrow_roundtrip() {
+        for (precision, values) in [
+            (18, vec![Some(12345i128), None, Some(-99)]),
+            (
+                38,
+                vec![Some(10i128.pow(38) - 1), None, Some(1 - 10i128.pow(38))],
+            ),
+        ] {
+            let arrow = Decimal128Array::from(values)
+                .with_precision_and_scale(precision, 3)
+                .unwrap();
+            let array = Array::from_arrow(Arc::new(arrow.clone()) as ArrayRef, true);
+            let round_tripped = array.into_canonical().unwrap().into_arrow().unwrap();
+            assert_eq!(round_tripped.as_primitive::<Decimal128Type>(), &arrow);
+        }
+
+        let max_decimal256 = i256::from_i128(10).wrapping_pow(76) - i256::ONE;
+        let arrow = Decimal256Array::from(vec![Some(max_decimal256), None, Some(i256::MINUS_ONE)])
+            .with_precision_and_scale(76, -3)
+            .unwrap();
+        let array = Array::from_arrow(Arc::new(arrow.clone()) as ArrayRef, true);
+        let round_tripped = array.into_canonical().unwrap().into_arrow().unwrap();
+        assert_eq!(round_tripped.as_primitive::<Decimal256Type>(), &arrow);
+    }
+
+    #[test]
+    fn compare_decimals() {
+        for precision in [10, 30] {
+            let metadata = DecimalMetadata::try_new(precision, 2).unwrap();
+            let array = Array::from(
+                DecimalArray::from_i128([100i128, -250, 300], Validity::NonNullable, metadata)
+                    .unwrap(),
+            );
+            let constant = ConstantArray::new(
+                Scalar::decimal(i256::from_i128(100), metadata, Nullability::NonNullable).unwrap(),
+                array.len(),
+            );
+            let matches = compare(&array, constant, Operator::Gte)
+                .unwrap()
+                .into_bool()
+                .unwrap();
+            assert_eq!(
+                matches.boolean_buffer().iter().collect::<Vec<_>>(),
+                vec![true, false, true]
+            );
+        }
+    }
+
+    #[test]
+    fn cast_decimals() {
+        let array = DecimalArray::from_i128(
+            [125i128, -250],
+            Validity::NonNullable,
+            DecimalMetadata::try_new(10, 2).unwrap(),
+        )
+        .unwrap();
+        let wider = DecimalMetadata::try_new(30, 4).unwrap();
+        let cast = try_cast(
+            Array::from(array.clone()),
+            &wider.dtype(Nullability::NonNullable),
+        )
+        .unwrap();
+        assert_eq!(
+            DecimalArray::try_from(cast)
+                .unwrap()
+                .unscaled_i256()
+                .unwrap(),
+            vec![i256::from_i128(12500), i256::from_i128(-25000)]
+        );
+
+        // 1.25 needs five digits at scale 4.
+        let overflow = DecimalMetadata::try_new(4, 4).unwrap();
+        assert!(try_cast(
+            Array::from(array.clone()),
+            &overflow.dtype(Nullability::NonNullable)
+        )
+        .is_err());
+
+        let floats = try_cast(
+            Array::from(array),
+            &DType::Primitive(PType::F64, Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+        assert_eq!(floats.maybe_null_slice::<f64>(), &[1.25, -2.5]);
+    }
+}
@@ -1,21 +1,28 @@
-use vortex_error::{VortexExpect, VortexResult};
+use arrow_array::cast::AsArray as _;
+use arrow_array::types::{Decimal128Type, Decimal256Type};
+use arrow_array::Array as _;
+use arrow_cast::CastOptions;
+use arrow_schema::DataType;
+use vortex_dtype::{is_decimal_ext_type, DType};
+use vortex_error::{vortex_bail, VortexExpect, VortexResult};
 use vortex_scalar::{ExtScalar, Scalar};
 
 use crate::array::extension::ExtensionArray;
 use crate::array::ConstantArray;
+use crate::arrow::{infer_data_type, FromArrowArray};
 use crate::compute::unary::{scalar_at, scalar_at_unchecked, CastFn, ScalarAtFn};
 use crate::compute::{
     compare, slice, take, ArrayCompute, MaybeCompareFn, Operator, SliceFn, TakeFn,
 };
 use crate::variants::ExtensionArrayTrait;
-use crate::{Array, ArrayDType, IntoArray};
+use crate::{Array, ArrayDType, IntoArray, IntoCanonical};
 
 impl ArrayCompute for ExtensionArray {
     fn cast(&self) -> Option<&dyn CastFn> {
-        // It's not possible to cast an extension array to another type.
+        // Only decimals can be cast, other extension arrays can't be cast to another type.
         // TODO(ngates): we should allow some extension arrays to implement a callback
         //  to support this
-        None
+        is_decimal_ext_type(self.id()).then_some(self)
     }
 
     fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
@@ -37,6 +44,12 @@ impl ArrayCompute for ExtensionArray {
 
 impl MaybeCompareFn for ExtensionArray {
     fn maybe_compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        // Wide decimals and intervals are stored as a struct of components which can't be
+        // compared directly, they are compared by Arrow instead.
+        if !matches!(self.storage().dtype(), DType::Primitive(..)) {
+            return None;
+        }
+
         if let Ok(const_ext) = ConstantArray::try_from(other) {
             let scalar_ext = ExtScalar::try_new(const_ext.dtype(), const_ext.scalar_value())
                 .vortex_expect("Expected ExtScalar");
@@ -56,6 +69,35 @@ impl MaybeCompareFn for ExtensionArray {
     }
 }
 
+impl CastFn for ExtensionArray {
+    /// Decimals are cast by Arrow, rescaling values when casting to another decimal type.
+    fn cast(&self, dtype: &DType) -> VortexResult<Array> {
+        let arrow = self.clone().into_canonical()?.into_arrow()?;
+        let cast = arrow_cast::cast_with_options(
+            &arrow,
+            &infer_data_type(dtype)?,
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        )?;
+        // Arrow doesn't check that rescaled values still fit into the target precision.
+        match cast.data_type() {
+            DataType::Decimal128(precision, _) => cast
+                .as_primitive::<Decimal128Type>()
+                .validate_decimal_precision(*precision)?,
+            DataType::Decimal256(precision, _) => cast
+                .as_primitive::<Decimal256Type>()
+                .validate_decimal_precision(*precision)?,
+            _ => {}
+        }
+        if !dtype.is_nullable() && cast.null_count() > 0 {
+            vortex_bail!("Can't cast array with nulls to non-nullable {}", dtype);
+        }
+        Ok(Array::from_arrow(cast, dtype.is_nullable()))
+    }
+}
+
 impl ScalarAtFn for ExtensionArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         Ok(Scalar::extension(
@@ -0,0 +1,218 @@
+use num_traits::AsPrimitive;
+use vortex_dtype::{match_each_integer_ptype, NativePType};
+use vortex_error::{vortex_bail, VortexResult, VortexUnwrap as _};
+use vortex_scalar::Scalar;
+
+use crate::array::list::{list_scalar, ListArray};
+use crate::array::PrimitiveArray;
+use crate::compute::unary::ScalarAtFn;
+use crate::compute::{slice, take, ArrayCompute, FilterFn, SliceFn, TakeFn};
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, IntoArray, IntoArrayVariant};
+
+impl ArrayCompute for ListArray {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for ListArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        list_scalar(self, index)
+    }
+
+    fn scalar_at_unchecked(&self, index: usize) -> Scalar {
+        list_scalar(self, index).vortex_unwrap()
+    }
+}
+
+impl SliceFn for ListArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        Self::try_new_unchecked(
+            self.elements(),
+            slice(self.offsets(), start, stop + 1)?,
+            self.validity().slice(start, stop)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl TakeFn for ListArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let indices = indices.clone().into_primitive()?;
+        let validity = self.validity().take(indices.as_ref())?;
+        match_each_integer_ptype!(indices.ptype(), |$I| {
+            take_lists(self, indices.maybe_null_slice::<$I>(), validity)
+        })
+        .map(|a| a.into_array())
+    }
+}
+
+impl FilterFn for ListArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        let predicate = predicate.clone().into_bool()?;
+        let indices = predicate
+            .boolean_buffer()
+            .set_indices()
+            .map(|i| i as u64)
+            .collect::<Vec<_>>();
+        let validity = self.validity().filter(predicate.as_ref())?;
+        take_lists(self, &indices, validity).map(|a| a.into_array())
+    }
+}
+
+/// Gather the lists at `indices` into a new [ListArray].
+///
+/// The selected ranges of the elements array are gathered with a single [take], so the new array
+/// always has offsets starting at zero and no unreferenced elements.
+fn take_lists<I: NativePType + AsPrimitive<usize>>(
+    array: &ListArray,
+    indices: &[I],
+    validity: Validity,
+) -> VortexResult<ListArray> {
+    let offsets = array.offsets().into_primitive()?;
+    let nulls = array.logical_validity().to_null_buffer()?;
+    match_each_integer_ptype!(offsets.ptype(), |$O| {
+        let offsets = offsets.maybe_null_slice::<$O>();
+        let mut new_offsets: Vec<$O> = Vec::with_capacity(indices.len() + 1);
+        let mut element_indices: Vec<u64> = Vec::new();
+        new_offsets.push(0 as $O);
+        for idx in indices {
+            let idx: usize = idx.as_();
+            if idx >= array.len() {
+                vortex_bail!(OutOfBounds: idx, 0, array.len());
+            }
+            // Null lists are empty in the new array, their offsets may not mean anything
+            if nulls.as_ref().is_some_and(|nulls| nulls.is_null(idx)) {
+                new_offsets.push(element_indices.len() as $O);
+                continue;
+            }
+            let start: u64 = offsets[idx].as_();
+            let stop: u64 = offsets[idx + 1].as_();
+            element_indices.extend(start..stop);
+            new_offsets.push(element_indices.len() as $O);
+        }
+
+        ListArray::try_new_unchecked(
+            take(
+                array.elements(),
+                PrimitiveArray::from(element_indices).into_array(),
+            )?,
+            PrimitiveArray::from(new_offsets).into_array(),
+            validity,
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{BoolArray, ListArray, PrimitiveArray};
+    use crate::compute::unary::scalar_at;
+    use crate::compute::{filter, slice, take};
+    use crate::validity::Validity;
+    use crate::{Array, IntoArray};
+
+    fn list_array() -> Array {
+        // [[1, 2], null, [], [3, 4, 5]]
+        ListArray::try_new(
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5]).into_array(),
+            PrimitiveArray::from(vec![0u32, 2, 2, 2, 5]).into_array(),
+            Validity::from(vec![true, false, true, true]),
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    fn list_values(array: &Array) -> Vec<Option<Vec<i32>>> {
+        (0..array.len())
+            .map(|i| {
+                let scalar = scalar_at(array, i).unwrap();
+                scalar
+                    .is_valid()
+                    .then(|| Vec::<i32>::try_from(&scalar).unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn slice_list() {
+        let sliced = slice(list_array(), 1, 4).unwrap();
+        assert_eq!(
+            list_values(&sliced),
+            vec![None, Some(vec![]), Some(vec![3, 4, 5])]
+        );
+    }
+
+    #[test]
+    fn take_list() {
+        let taken = take(
+            list_array(),
+            PrimitiveArray::from(vec![3u32, 0, 1, 3]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(
+            list_values(&taken),
+            vec![
+                Some(vec![3, 4, 5]),
+                Some(vec![1, 2]),
+                None,
+                Some(vec![3, 4, 5])
+            ]
+        );
+    }
+
+    #[test]
+    fn take_null_lists_ignores_offsets() {
+        // The offsets of the null list point past its neighbours
+        let list = ListArray::try_new(
+            PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+            PrimitiveArray::from(vec![0u32, 1, 3, 3]).into_array(),
+            Validity::from(vec![true, false, true]),
+        )
+        .unwrap();
+        let taken = take(
+            list.as_ref(),
+            PrimitiveArray::from(vec![1u32, 0, 2]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(list_values(&taken), vec![None, Some(vec![1]), Some(vec![])]);
+        let taken = ListArray::try_from(taken).unwrap();
+        assert_eq!(taken.elements().len(), 1);
+    }
+
+    #[test]
+    fn take_list_out_of_bounds() {
+        let list = ListArray::try_new(
+            PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+            PrimitiveArray::from(vec![0u32, 1, 3]).into_array(),
+            Validity::NonNullable,
+        )
+        .unwrap();
+        assert!(take(list.as_ref(), PrimitiveArray::from(vec![2u32]).as_ref()).is_err());
+    }
+
+    #[test]
+    fn filter_list() {
+        let filtered = filter(
+            list_array(),
+            BoolArray::from(vec![true, true, false, true]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(
+            list_values(&filtered),
+            vec![Some(vec![1, 2]), None, Some(vec![3, 4, 5])]
+        );
+    }
+}
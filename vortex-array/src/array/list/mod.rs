@@ -0,0 +1,307 @@
+use std::fmt::{Debug, Display};
+use std::sync::Arc;
+
+use num_traits::{AsPrimitive, ToPrimitive as _};
+use serde::{Deserialize, Serialize};
+use vortex_dtype::{match_each_integer_ptype, match_each_native_ptype, DType, Nullability, PType};
+use vortex_error::{vortex_bail, vortex_panic, VortexExpect as _, VortexResult};
+use vortex_scalar::{Scalar, ScalarValue};
+
+use crate::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::array::PrimitiveArray;
+use crate::compute::slice;
+use crate::compute::unary::scalar_at;
+use crate::encoding::ids;
+use crate::stats::{ArrayStatisticsCompute, StatsSet};
+use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use crate::variants::{ArrayVariants, ListArrayTrait};
+use crate::{
+    impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArrayVariant, IntoCanonical,
+};
+
+mod compute;
+
+impl_encoding!("vortex.list", ids::LIST, List);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListMetadata {
+    validity: ValidityMetadata,
+    offsets_ptype: PType,
+    elements_len: usize,
+}
+
+impl Display for ListMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl ListArray {
+    /// Create a new list array from a flat array of elements and `len + 1` offsets into it.
+    ///
+    /// The list at index `i` contains the elements between `offsets[i]` and `offsets[i + 1]`.
+    pub fn try_new(elements: Array, offsets: Array, validity: Validity) -> VortexResult<Self> {
+        if !offsets.dtype().is_int() || offsets.dtype().is_nullable() {
+            vortex_bail!(MismatchedTypes: "non nullable int", offsets.dtype());
+        }
+        if offsets.is_empty() {
+            vortex_bail!("ListArray offsets must have at least one element");
+        }
+        validate_offsets(&offsets, elements.len())?;
+        Self::try_new_unchecked(elements, offsets, validity)
+    }
+
+    /// Create a new list array without checking that the offsets are non-decreasing and within
+    /// the elements, for offsets that come from an already valid list array.
+    pub(crate) fn try_new_unchecked(
+        elements: Array,
+        offsets: Array,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        let offsets_ptype = PType::try_from(offsets.dtype())?;
+        let length = offsets.len() - 1;
+
+        let metadata = ListMetadata {
+            validity: validity.to_metadata(length)?,
+            offsets_ptype,
+            elements_len: elements.len(),
+        };
+        let dtype = DType::List(Arc::new(elements.dtype().clone()), validity.nullability());
+
+        let mut children = Vec::with_capacity(3);
+        children.push(offsets);
+        children.push(elements);
+        if let Some(a) = validity.into_array() {
+            children.push(a)
+        }
+
+        Self::try_from_parts(dtype, length, metadata, children.into(), StatsSet::new())
+    }
+
+    /// The dtype of the list elements.
+    pub fn element_dtype(&self) -> &DType {
+        let DType::List(element_dtype, _) = self.dtype() else {
+            vortex_panic!("ListArray must have a list dtype, found {}", self.dtype())
+        };
+        element_dtype
+    }
+
+    #[inline]
+    pub fn offsets(&self) -> Array {
+        self.as_ref()
+            .child(
+                0,
+                &DType::Primitive(self.metadata().offsets_ptype, Nullability::NonNullable),
+                self.len() + 1,
+            )
+            .vortex_expect("Missing offsets in ListArray")
+    }
+
+    /// Access the elements child array
+    ///
+    /// # Note
+    ///
+    /// Like the bytes of a [VarBinArray](crate::array::VarBinArray), the elements are never sliced
+    /// when the array is sliced, so this can include values that are not logically present in
+    /// the array. Users should prefer [sliced_elements][Self::sliced_elements] unless they're
+    /// resolving values via offset child array.
+    #[inline]
+    pub fn elements(&self) -> Array {
+        self.as_ref()
+            .child(1, self.element_dtype(), self.metadata().elements_len)
+            .vortex_expect("Missing elements in ListArray")
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata().validity.to_validity(|| {
+            self.as_ref()
+                .child(2, &Validity::DTYPE, self.len())
+                .vortex_expect("ListArray: validity child")
+        })
+    }
+
+    pub fn offset_at(&self, index: usize) -> usize {
+        PrimitiveArray::try_from(self.offsets())
+            .ok()
+            .map(|p| {
+                match_each_native_ptype!(p.ptype(), |$P| {
+                    p.maybe_null_slice::<$P>()[index].as_()
+                })
+            })
+            .unwrap_or_else(|| {
+                scalar_at(self.offsets(), index)
+                    .unwrap_or_else(|err| {
+                        vortex_panic!(err, "Failed to get offset at index: {}", index)
+                    })
+                    .as_ref()
+                    .try_into()
+                    .vortex_expect("Failed to convert offset to usize")
+            })
+    }
+
+    /// The elements of the list at `index`.
+    pub fn elements_at(&self, index: usize) -> VortexResult<Array> {
+        slice(
+            self.elements(),
+            self.offset_at(index),
+            self.offset_at(index + 1),
+        )
+    }
+
+    /// Access the elements child array limited to values that are logically present in the
+    /// array unlike [elements][Self::elements].
+    pub fn sliced_elements(&self) -> VortexResult<Array> {
+        slice(
+            self.elements(),
+            self.offset_at(0),
+            self.offset_at(self.len()),
+        )
+    }
+}
+
+/// Check that the offsets never decrease and stay within the elements.
+fn validate_offsets(offsets: &Array, elements_len: usize) -> VortexResult<()> {
+    let offsets = offsets.clone().into_primitive()?;
+    match_each_integer_ptype!(offsets.ptype(), |$O| {
+        let mut previous = 0;
+        for offset in offsets.maybe_null_slice::<$O>() {
+            match offset.to_usize() {
+                Some(o) if o >= previous && o <= elements_len => previous = o,
+                _ => vortex_bail!(
+                    "ListArray offsets must be non-decreasing and at most {elements_len}, found {offset} after {previous}"
+                ),
+            }
+        }
+    });
+    Ok(())
+}
+
+impl ArrayTrait for ListArray {}
+
+impl ArrayVariants for ListArray {
+    fn as_list_array(&self) -> Option<&dyn ListArrayTrait> {
+        Some(self)
+    }
+}
+
+impl ListArrayTrait for ListArray {}
+
+impl IntoCanonical for ListArray {
+    /// ListEncoding is the canonical form for a [DType::List] array, so return self.
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        Ok(Canonical::List(self))
+    }
+}
+
+impl ArrayValidity for ListArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for ListArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("offsets", &self.offsets())?;
+        visitor.visit_child("elements", &self.elements())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for ListArray {}
+
+/// Build the [Scalar] for the list at `index` by materializing each of its elements.
+pub(crate) fn list_scalar(array: &ListArray, index: usize) -> VortexResult<Scalar> {
+    if !array.is_valid(index) {
+        return Ok(Scalar::null(array.dtype().clone()));
+    }
+
+    let elements = array.elements_at(index)?;
+    let values = (0..elements.len())
+        .map(|i| scalar_at(&elements, i).map(|s| s.into_value()))
+        .collect::<VortexResult<Vec<_>>>()?;
+    Ok(Scalar::new(
+        array.dtype().clone(),
+        ScalarValue::List(values.into()),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::{Scalar, ScalarValue};
+
+    use crate::array::{ListArray, PrimitiveArray};
+    use crate::compute::unary::scalar_at;
+    use crate::validity::Validity;
+    use crate::{ArrayDType, IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn test_list_array() {
+        let elements = PrimitiveArray::from(vec![1i32, 2, 3, 4, 5]);
+        let offsets = PrimitiveArray::from(vec![0u32, 2, 2, 5]);
+        let list = ListArray::try_new(
+            elements.into_array(),
+            offsets.into_array(),
+            Validity::from(vec![true, false, true]),
+        )
+        .unwrap();
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(
+            list.dtype(),
+            &DType::List(
+                Arc::new(DType::Primitive(PType::I32, Nullability::NonNullable)),
+                Nullability::Nullable
+            )
+        );
+        assert_eq!(
+            list.elements_at(2)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            [3, 4, 5]
+        );
+        assert_eq!(
+            scalar_at(list.as_ref(), 0).unwrap(),
+            Scalar::new(
+                list.dtype().clone(),
+                ScalarValue::List(vec![1i32.into(), 2i32.into()].into())
+            )
+        );
+        assert!(scalar_at(list.as_ref(), 1).unwrap().is_null());
+    }
+
+    #[test]
+    fn test_list_array_requires_offsets() {
+        let elements = PrimitiveArray::from(vec![1i32]);
+        let offsets = PrimitiveArray::from(Vec::<u32>::new());
+        assert!(ListArray::try_new(
+            elements.into_array(),
+            offsets.into_array(),
+            Validity::NonNullable
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_list_array_rejects_invalid_offsets() {
+        let list = |offsets: Vec<i32>| {
+            ListArray::try_new(
+                PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+                PrimitiveArray::from(offsets).into_array(),
+                Validity::NonNullable,
+            )
+        };
+        assert!(list(vec![1, 3]).is_ok());
+        assert!(list(vec![0, 2, 1]).is_err());
+        assert!(list(vec![0, 4]).is_err());
+        assert!(list(vec![-1, 2]).is_err());
+    }
+}
@@ -7,7 +7,9 @@ mod bool;
 mod chunked;
 mod constant;
 mod datetime;
+mod decimal;
 mod extension;
+mod list;
 mod null;
 mod primitive;
 mod sparse;
@@ -24,7 +26,9 @@ pub use self::bool::*;
 pub use self::chunked::*;
 pub use self::constant::*;
 pub use self::datetime::*;
+pub use self::decimal::*;
 pub use self::extension::*;
+pub use self::list::*;
 pub use self::null::*;
 pub use self::primitive::*;
 pub use self::sparse::*;
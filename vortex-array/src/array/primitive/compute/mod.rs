@@ -2,7 +2,9 @@ use vortex_error::VortexResult;
 
 use crate::array::primitive::PrimitiveArray;
 use crate::compute::unary::{CastFn, FillForwardFn, ScalarAtFn, SubtractScalarFn};
-use crate::compute::{ArrayCompute, MaybeCompareFn, Operator, SearchSortedFn, SliceFn, TakeFn};
+use crate::compute::{
+    ArrayCompute, MaybeCompareFn, Operator, SearchSortedFn, SliceFn, SumFn, TakeFn,
+};
 use crate::Array;
 
 mod cast;
@@ -13,6 +15,7 @@ mod scalar_at;
 mod search_sorted;
 mod slice;
 mod subtract_scalar;
+mod sum;
 mod take;
 
 impl ArrayCompute for PrimitiveArray {
@@ -44,6 +47,10 @@ impl ArrayCompute for PrimitiveArray {
         Some(self)
     }
 
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
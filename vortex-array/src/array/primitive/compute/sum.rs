@@ -0,0 +1,39 @@
+use vortex_dtype::{match_each_native_ptype, NativePType};
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::{SumAccumulator, SumFn};
+use crate::validity::{ArrayValidity, LogicalValidity};
+use crate::{ArrayDType, IntoArrayVariant};
+
+impl SumFn for PrimitiveArray {
+    fn sum(&self) -> VortexResult<Scalar> {
+        let mut acc = SumAccumulator::try_new(self.dtype())?;
+        match_each_native_ptype!(self.ptype(), |$T| {
+            sum_values(&mut acc, self.maybe_null_slice::<$T>(), self.logical_validity())?
+        });
+        Ok(acc.finish())
+    }
+}
+
+fn sum_values<T: NativePType>(
+    acc: &mut SumAccumulator,
+    values: &[T],
+    validity: LogicalValidity,
+) -> VortexResult<()> {
+    match validity {
+        LogicalValidity::AllValid(_) => {
+            for v in values {
+                acc.add(*v)?;
+            }
+        }
+        LogicalValidity::AllInvalid(_) => {}
+        LogicalValidity::Array(a) => {
+            for idx in a.into_bool()?.boolean_buffer().set_indices() {
+                acc.add(values[idx])?;
+            }
+        }
+    }
+    Ok(())
+}
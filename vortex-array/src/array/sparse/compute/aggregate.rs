@@ -0,0 +1,105 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::sparse::SparseArray;
+use crate::compute::{
+    count_valid, min_max, sum, CountValidFn, MinMaxFn, MinMaxResult, SumAccumulator, SumFn,
+};
+use crate::ArrayDType;
+
+impl SparseArray {
+    /// Number of positions that take the fill value.
+    fn fill_count(&self) -> usize {
+        self.len() - self.metadata().indices_len
+    }
+}
+
+impl SumFn for SparseArray {
+    fn sum(&self) -> VortexResult<Scalar> {
+        let mut acc = SumAccumulator::try_new(self.dtype())?;
+        acc.add_scalar(&self.fill_scalar(), self.fill_count())?;
+        acc.add_scalar(&sum(self.values())?, 1)?;
+        Ok(acc.finish())
+    }
+}
+
+impl MinMaxFn for SparseArray {
+    fn min_max(&self) -> VortexResult<Option<MinMaxResult>> {
+        let fill = self.fill_scalar();
+        let fill_min_max = (self.fill_count() > 0 && fill.is_valid()).then(|| MinMaxResult {
+            min: fill.clone(),
+            max: fill,
+        });
+        Ok(MinMaxResult::merge(fill_min_max, min_max(self.values())?))
+    }
+}
+
+impl CountValidFn for SparseArray {
+    fn count_valid(&self) -> VortexResult<usize> {
+        let fill_valid = if self.fill_value().is_null() {
+            0
+        } else {
+            self.fill_count()
+        };
+        Ok(count_valid(self.values())? + fill_valid)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::SparseArray;
+    use crate::compute::unary::try_cast;
+    use crate::compute::{count_valid, min_max, slice, sum};
+    use crate::IntoArray;
+
+    fn sparse(fill: Scalar) -> SparseArray {
+        SparseArray::try_new(
+            vec![2u64, 5, 8].into_array(),
+            try_cast(vec![-100i32, 200, 300].into_array(), fill.dtype()).unwrap(),
+            10,
+            fill.into_value(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn aggregates() {
+        let array = sparse(Scalar::from(400i32));
+        assert_eq!(
+            sum(array.as_ref()).unwrap(),
+            Scalar::primitive(3200i64, Nullability::Nullable)
+        );
+        assert_eq!(count_valid(array.as_ref()).unwrap(), 10);
+        let result = min_max(array.as_ref()).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(-100i32));
+        assert_eq!(result.max, Scalar::from(400i32));
+
+        let sliced = slice(array.as_ref(), 3, 7).unwrap();
+        assert_eq!(
+            sum(&sliced).unwrap(),
+            Scalar::primitive(1400i64, Nullability::Nullable)
+        );
+        let result = min_max(&sliced).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(200i32));
+        assert_eq!(result.max, Scalar::from(400i32));
+    }
+
+    #[test]
+    fn aggregates_null_fill() {
+        let array = sparse(Scalar::null(DType::Primitive(
+            PType::I32,
+            Nullability::Nullable,
+        )));
+        assert_eq!(
+            sum(array.as_ref()).unwrap(),
+            Scalar::primitive(400i64, Nullability::Nullable)
+        );
+        assert_eq!(count_valid(array.as_ref()).unwrap(), 3);
+        let result = min_max(array.as_ref()).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(-100i32));
+        assert_eq!(result.max, Scalar::from(300i32));
+    }
+}
@@ -6,15 +6,24 @@ use crate::array::sparse::SparseArray;
 use crate::array::PrimitiveArray;
 use crate::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
 use crate::compute::{
-    search_sorted, take, ArrayCompute, FilterFn, SearchResult, SearchSortedFn, SearchSortedSide,
-    SliceFn, TakeFn,
+    search_sorted, take, ArrayCompute, CountValidFn, FilterFn, MinMaxFn, SearchResult,
+    SearchSortedFn, SearchSortedSide, SliceFn, SumFn, TakeFn,
 };
 use crate::{Array, IntoArray, IntoArrayVariant};
 
+mod aggregate;
 mod slice;
 mod take;
 
 impl ArrayCompute for SparseArray {
+    fn count_valid(&self) -> Option<&dyn CountValidFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -27,6 +36,10 @@ impl ArrayCompute for SparseArray {
         Some(self)
     }
 
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
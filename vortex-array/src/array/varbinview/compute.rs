@@ -2,9 +2,14 @@ use std::sync::Arc;
 
 use arrow_array::cast::AsArray;
 use arrow_array::types::ByteViewType;
-use arrow_array::{Datum, GenericByteViewArray};
+use arrow_array::{
+    Array as ArrowArray, BooleanArray, Datum, GenericByteViewArray, Scalar as ArrowScalar,
+    StringViewArray,
+};
+use arrow_buffer::NullBuffer;
 use arrow_ord::cmp;
 use arrow_schema::DataType;
+use arrow_string::regexp::regexp_is_match_scalar;
 use vortex_buffer::Buffer;
 use vortex_error::{vortex_bail, VortexResult, VortexUnwrap};
 use vortex_scalar::Scalar;
@@ -14,7 +19,9 @@ use crate::array::varbinview::{VarBinViewArray, VIEW_SIZE_BYTES};
 use crate::array::{varbinview_as_arrow, ConstantArray};
 use crate::arrow::FromArrowArray;
 use crate::compute::unary::ScalarAtFn;
-use crate::compute::{slice, ArrayCompute, MaybeCompareFn, Operator, SliceFn, TakeFn};
+use crate::compute::{
+    slice, ArrayCompute, LikeFn, LikeOptions, MaybeCompareFn, Operator, SliceFn, TakeFn,
+};
 use crate::{Array, ArrayDType, IntoArray, IntoCanonical};
 
 impl ArrayCompute for VarBinViewArray {
@@ -22,6 +29,10 @@ impl ArrayCompute for VarBinViewArray {
         MaybeCompareFn::maybe_compare(self, other, operator)
     }
 
+    fn like(&self) -> Option<&dyn LikeFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -123,6 +134,33 @@ fn compare_constant_arrow<T: ByteViewType>(
     Ok(crate::Array::from_arrow(&array, true))
 }
 
+impl LikeFn for VarBinViewArray {
+    fn like(&self, pattern: &str, options: LikeOptions) -> VortexResult<Array> {
+        let lhs = varbinview_as_arrow(self);
+        let result = if options.regex {
+            let flag = options.case_insensitive.then_some("i");
+            let matched = regexp_is_match_scalar(lhs.as_string_view(), pattern, flag)?;
+            if options.negated {
+                BooleanArray::new(!matched.values(), matched.nulls().cloned())
+            } else {
+                matched
+            }
+        } else {
+            let rhs = ArrowScalar::new(StringViewArray::from_iter_values([pattern]));
+            match (options.negated, options.case_insensitive) {
+                (false, false) => arrow_string::like::like(&lhs, &rhs)?,
+                (true, false) => arrow_string::like::nlike(&lhs, &rhs)?,
+                (false, true) => arrow_string::like::ilike(&lhs, &rhs)?,
+                (true, true) => arrow_string::like::nilike(&lhs, &rhs)?,
+            }
+        };
+        // Arrow's fast paths for prefix and suffix patterns on string views drop the input nulls.
+        let nulls = NullBuffer::union(lhs.nulls(), result.nulls());
+        let result = BooleanArray::new(result.values().clone(), nulls);
+        Ok(Array::from_arrow(&result, self.dtype().is_nullable()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use vortex_dtype::Nullability;
@@ -1,28 +1,31 @@
 use arrow_array::array::{
     Array as ArrowArray, ArrayRef as ArrowArrayRef, ArrowPrimitiveType,
-    BooleanArray as ArrowBooleanArray, GenericByteArray, NullArray as ArrowNullArray,
-    OffsetSizeTrait, PrimitiveArray as ArrowPrimitiveArray, StructArray as ArrowStructArray,
+    BooleanArray as ArrowBooleanArray, GenericByteArray, GenericListArray,
+    NullArray as ArrowNullArray, OffsetSizeTrait, PrimitiveArray as ArrowPrimitiveArray,
+    StructArray as ArrowStructArray,
 };
 use arrow_array::cast::{as_null_array, AsArray};
 use arrow_array::types::{
-    ByteArrayType, ByteViewType, Date32Type, Date64Type, DurationMicrosecondType,
-    DurationMillisecondType, DurationNanosecondType, DurationSecondType, Float16Type, Float32Type,
-    Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, Time32MillisecondType,
-    Time32SecondType, Time64MicrosecondType, Time64NanosecondType, TimestampMicrosecondType,
-    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type,
-    UInt64Type, UInt8Type,
+    ByteArrayType, ByteViewType, Date32Type, Date64Type, Decimal128Type, Decimal256Type,
+    DecimalType, DurationMicrosecondType, DurationMillisecondType, DurationNanosecondType,
+    DurationSecondType, Float16Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type,
+    Int8Type, IntervalDayTimeType, IntervalMonthDayNanoType, IntervalYearMonthType,
+    Time32MillisecondType, Time32SecondType, Time64MicrosecondType, Time64NanosecondType,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
 };
 use arrow_array::{BinaryViewArray, GenericByteViewArray, StringViewArray};
 use arrow_buffer::buffer::{NullBuffer, OffsetBuffer};
-use arrow_buffer::{ArrowNativeType, Buffer, ScalarBuffer};
-use arrow_schema::{DataType, TimeUnit as ArrowTimeUnit};
+use arrow_buffer::{i256, ArrowNativeType, Buffer, ScalarBuffer};
+use arrow_schema::{DataType, IntervalUnit as ArrowIntervalUnit, TimeUnit as ArrowTimeUnit};
 use itertools::Itertools;
-use vortex_datetime_dtype::TimeUnit;
-use vortex_dtype::{DType, NativePType, PType};
-use vortex_error::{vortex_panic, VortexExpect as _};
+use vortex_datetime_dtype::{IntervalUnit, TimeUnit};
+use vortex_dtype::{DType, DecimalMetadata, FieldNames, NativePType, PType};
+use vortex_error::{vortex_panic, VortexError, VortexExpect as _};
 
 use crate::array::{
-    BoolArray, NullArray, PrimitiveArray, StructArray, TemporalArray, VarBinArray, VarBinViewArray,
+    BoolArray, DecimalArray, ListArray, NullArray, PrimitiveArray, StructArray, TemporalArray,
+    VarBinArray, VarBinViewArray,
 };
 use crate::arrow::FromArrowArray;
 use crate::stats::{ArrayStatistics, Stat};
@@ -98,8 +101,12 @@ where
             }
             DataType::Date32 => TemporalArray::new_date(arr.into(), TimeUnit::D).into(),
             DataType::Date64 => TemporalArray::new_date(arr.into(), TimeUnit::Ms).into(),
-            DataType::Duration(_) => unimplemented!(),
-            DataType::Interval(_) => unimplemented!(),
+            DataType::Duration(time_unit) => {
+                TemporalArray::new_duration(arr.into(), time_unit.into()).into()
+            }
+            DataType::Interval(ArrowIntervalUnit::YearMonth) => {
+                TemporalArray::new_interval(arr.into(), IntervalUnit::YearMonth).into()
+            }
             _ => vortex_panic!("Invalid data type for PrimitiveArray: {}", T::DATA_TYPE),
         }
     }
@@ -179,6 +186,106 @@ impl FromArrowArray<&ArrowStructArray> for Array {
     }
 }
 
+impl<O> FromArrowArray<&GenericListArray<O>> for Array
+where
+    O: NativePType + OffsetSizeTrait,
+{
+    fn from_arrow(value: &GenericListArray<O>, nullable: bool) -> Self {
+        let elements_nullable = match value.data_type() {
+            DataType::List(field) | DataType::LargeList(field) => field.is_nullable(),
+            _ => vortex_panic!("Invalid data type for ListArray: {}", value.data_type()),
+        };
+        // Arrow already checked the offsets
+        ListArray::try_new_unchecked(
+            Self::from_arrow(value.values().clone(), elements_nullable),
+            value.offsets().clone().into(),
+            nulls(value.nulls(), nullable),
+        )
+        .vortex_expect("Failed to convert Arrow ListArray to Vortex ListArray")
+        .into()
+    }
+}
+
+/// Convert an Arrow decimal array into a [DecimalArray], zeroing the values of null slots as they
+/// are not guaranteed to be within the decimal's precision.
+fn decimal_from_arrow<T: DecimalType>(
+    value: &ArrowPrimitiveArray<T>,
+    to_i256: impl Fn(T::Native) -> i256,
+    nullable: bool,
+) -> Array {
+    let (DataType::Decimal128(precision, scale) | DataType::Decimal256(precision, scale)) =
+        value.data_type()
+    else {
+        vortex_panic!("Invalid data type for DecimalArray: {}", value.data_type());
+    };
+    let metadata = DecimalMetadata::try_new(*precision, *scale)
+        .vortex_expect("Arrow decimal must be a valid Vortex decimal");
+    DecimalArray::from_i256(
+        value.iter().map(|v| v.map(&to_i256).unwrap_or(i256::ZERO)),
+        nulls(value.nulls(), nullable),
+        metadata,
+    )
+    .vortex_expect("Failed to convert Arrow decimal array to Vortex DecimalArray")
+    .into()
+}
+
+/// Convert an Arrow day-time interval array into a [TemporalArray] storing the days and
+/// milliseconds as separate columns.
+fn interval_day_time_from_arrow(
+    value: &ArrowPrimitiveArray<IntervalDayTimeType>,
+    nullable: bool,
+) -> Array {
+    let values = value.values();
+    interval_from_components(
+        IntervalUnit::DayTime,
+        vec![
+            PrimitiveArray::from(values.iter().map(|v| v.days).collect_vec()).into_array(),
+            PrimitiveArray::from(values.iter().map(|v| v.milliseconds).collect_vec()).into_array(),
+        ],
+        nulls(value.nulls(), nullable),
+    )
+}
+
+/// Convert an Arrow month-day-nano interval array into a [TemporalArray] storing the months, days
+/// and nanoseconds as separate columns.
+fn interval_month_day_nano_from_arrow(
+    value: &ArrowPrimitiveArray<IntervalMonthDayNanoType>,
+    nullable: bool,
+) -> Array {
+    let values = value.values();
+    interval_from_components(
+        IntervalUnit::MonthDayNano,
+        vec![
+            PrimitiveArray::from(values.iter().map(|v| v.months).collect_vec()).into_array(),
+            PrimitiveArray::from(values.iter().map(|v| v.days).collect_vec()).into_array(),
+            PrimitiveArray::from(values.iter().map(|v| v.nanoseconds).collect_vec()).into_array(),
+        ],
+        nulls(value.nulls(), nullable),
+    )
+}
+
+fn interval_from_components(
+    interval_unit: IntervalUnit,
+    components: Vec<Array>,
+    validity: Validity,
+) -> Array {
+    let len = components.first().map_or(0, |c| c.len());
+    let storage = StructArray::try_new(
+        FieldNames::from(
+            interval_unit
+                .components()
+                .iter()
+                .map(|(name, _)| (*name).into())
+                .collect_vec(),
+        ),
+        components,
+        len,
+        validity,
+    )
+    .vortex_expect("Failed to convert Arrow interval array to Vortex StructArray");
+    TemporalArray::new_interval(storage.into_array(), interval_unit).into()
+}
+
 impl FromArrowArray<&ArrowNullArray> for Array {
     fn from_arrow(value: &ArrowNullArray, nullable: bool) -> Self {
         assert!(nullable);
@@ -237,6 +344,16 @@ impl FromArrowArray<ArrowArrayRef> for Array {
                 nullable,
             ),
             DataType::Struct(_) => Self::from_arrow(array.as_struct(), nullable),
+            DataType::Decimal128(..) => decimal_from_arrow(
+                array.as_primitive::<Decimal128Type>(),
+                i256::from_i128,
+                nullable,
+            ),
+            DataType::Decimal256(..) => {
+                decimal_from_arrow(array.as_primitive::<Decimal256Type>(), |v| v, nullable)
+            }
+            DataType::List(_) => Self::from_arrow(array.as_list::<i32>(), nullable),
+            DataType::LargeList(_) => Self::from_arrow(array.as_list::<i64>(), nullable),
             DataType::Null => Self::from_arrow(as_null_array(&array), nullable),
             DataType::Timestamp(u, _) => match u {
                 ArrowTimeUnit::Second => {
@@ -286,6 +403,24 @@ impl FromArrowArray<ArrowArrayRef> for Array {
                     Self::from_arrow(array.as_primitive::<DurationNanosecondType>(), nullable)
                 }
             },
+            DataType::Interval(u) => match u {
+                ArrowIntervalUnit::YearMonth => {
+                    Self::from_arrow(array.as_primitive::<IntervalYearMonthType>(), nullable)
+                }
+                ArrowIntervalUnit::DayTime => {
+                    interval_day_time_from_arrow(array.as_primitive(), nullable)
+                }
+                ArrowIntervalUnit::MonthDayNano => {
+                    interval_month_day_nano_from_arrow(array.as_primitive(), nullable)
+                }
+            },
+            // Dictionaries are decoded, vortex-dict can import them without decoding.
+            DataType::Dictionary(_, value_type) => Self::from_arrow(
+                arrow_cast::cast(&array, value_type)
+                    .map_err(VortexError::from)
+                    .vortex_expect("Failed to decode Arrow DictionaryArray"),
+                nullable,
+            ),
             _ => vortex_panic!(
                 "Array encoding not implementedfor Arrow data type {}",
                 array.data_type().clone()
@@ -14,10 +14,13 @@ use std::sync::Arc;
 
 use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaBuilder, SchemaRef};
 use itertools::Itertools;
-use vortex_datetime_dtype::arrow::{make_arrow_temporal_dtype, make_temporal_ext_dtype};
+use vortex_datetime_dtype::arrow::{make_arrow_temporal_dtype, try_make_temporal_ext_dtype};
 use vortex_datetime_dtype::is_temporal_ext_type;
-use vortex_dtype::{DType, Nullability, PType, StructDType};
-use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_dtype::{
+    is_decimal_ext_type, DType, DecimalMetadata, Nullability, PType, StructDType,
+    MAX_DECIMAL128_PRECISION,
+};
+use vortex_error::{vortex_bail, vortex_err, VortexExpect as _, VortexResult};
 
 use crate::arrow::{FromArrowType, TryFromArrowType};
 
@@ -66,15 +69,21 @@ impl FromArrowType<SchemaRef> for DType {
 
 impl FromArrowType<&Field> for DType {
     fn from_arrow(field: &Field) -> Self {
+        Self::try_from_arrow(field).vortex_expect("Failed to convert Arrow field to Vortex DType")
+    }
+}
+
+impl TryFromArrowType<&Field> for DType {
+    fn try_from_arrow(field: &Field) -> VortexResult<Self> {
         use vortex_dtype::DType::*;
 
         let nullability: Nullability = field.is_nullable().into();
 
         if let Ok(ptype) = PType::try_from_arrow(field.data_type()) {
-            return Primitive(ptype, nullability);
+            return Ok(Primitive(ptype, nullability));
         }
 
-        match field.data_type() {
+        Ok(match field.data_type() {
             DataType::Null => Null,
             DataType::Boolean => Bool(nullability),
             DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => Utf8(nullability),
@@ -83,25 +92,40 @@ impl FromArrowType<&Field> for DType {
             | DataType::Date64
             | DataType::Time32(_)
             | DataType::Time64(_)
-            | DataType::Timestamp(..) => Extension(
-                make_temporal_ext_dtype(field.data_type()),
+            | DataType::Timestamp(..)
+            | DataType::Duration(_)
+            | DataType::Interval(_) => Extension(
+                try_make_temporal_ext_dtype(field.data_type())?,
                 field.is_nullable().into(),
             ),
+            DataType::Decimal128(precision, scale) | DataType::Decimal256(precision, scale) => {
+                Extension(
+                    DecimalMetadata::try_new(*precision, *scale)?.ext_dtype(),
+                    nullability,
+                )
+            }
             DataType::List(e) | DataType::LargeList(e) => {
-                List(Arc::new(Self::from_arrow(e.as_ref())), nullability)
+                List(Arc::new(Self::try_from_arrow(e.as_ref())?), nullability)
             }
+            DataType::Dictionary(_, value_type) => Self::try_from_arrow(&Field::new(
+                field.name(),
+                value_type.as_ref().clone(),
+                field.is_nullable(),
+            ))?,
             DataType::Struct(f) => Struct(
                 StructDType::new(
                     f.iter()
                         .map(|f| f.name().as_str().into())
                         .collect_vec()
                         .into(),
-                    f.iter().map(|f| Self::from_arrow(f.as_ref())).collect_vec(),
+                    f.iter()
+                        .map(|f| Self::try_from_arrow(f.as_ref()))
+                        .collect::<VortexResult<Vec<_>>>()?,
                 ),
                 nullability,
             ),
-            _ => unimplemented!("Arrow data type not yet supported: {:?}", field.data_type()),
-        }
+            _ => vortex_bail!("Arrow data type not yet supported: {:?}", field.data_type()),
+        })
     }
 }
 
@@ -169,12 +193,23 @@ pub fn infer_data_type(dtype: &DType) -> VortexResult<DataType> {
         }
         // There are four kinds of lists: List (32-bit offsets), Large List (64-bit), List View
         // (32-bit), Large List View (64-bit). We cannot both guarantee zero-copy and commit to an
-        // Arrow dtype because we do not how large our offsets are.
-        DType::List(..) => vortex_bail!("Unsupported dtype: {}", dtype),
+        // Arrow dtype because we do not how large our offsets are, so we pick List here. Arrays
+        // with i64 offsets still convert to LargeList, all others cast their offsets to i32.
+        DType::List(element_dtype, _) => DataType::List(FieldRef::new(Field::new_list_field(
+            infer_data_type(element_dtype)?,
+            element_dtype.is_nullable(),
+        ))),
         DType::Extension(ext_dtype, _) => {
             // Try and match against the known extension DTypes.
             if is_temporal_ext_type(ext_dtype.id()) {
                 make_arrow_temporal_dtype(ext_dtype)
+            } else if is_decimal_ext_type(ext_dtype.id()) {
+                let metadata = DecimalMetadata::try_from(ext_dtype)?;
+                if metadata.precision() <= MAX_DECIMAL128_PRECISION {
+                    DataType::Decimal128(metadata.precision(), metadata.scale())
+                } else {
+                    DataType::Decimal256(metadata.precision(), metadata.scale())
+                }
             } else {
                 vortex_bail!("Unsupported extension type \"{}\"", ext_dtype.id())
             }
@@ -229,6 +264,26 @@ mod test {
                 FieldRef::from(Field::new("field_b", DataType::Utf8View, true)),
             ]))
         );
+
+        assert_eq!(
+            infer_data_type(
+                &DecimalMetadata::try_new(18, 2)
+                    .unwrap()
+                    .dtype(Nullability::Nullable)
+            )
+            .unwrap(),
+            DataType::Decimal128(18, 2)
+        );
+
+        assert_eq!(
+            infer_data_type(
+                &DecimalMetadata::try_new(50, -3)
+                    .unwrap()
+                    .dtype(Nullability::Nullable)
+            )
+            .unwrap(),
+            DataType::Decimal256(50, -3)
+        );
     }
 
     #[test]
@@ -264,6 +319,25 @@ mod test {
         let _ = infer_schema(&schema_null).unwrap();
     }
 
+    #[test]
+    fn test_temporal_roundtrip() {
+        for data_type in [
+            DataType::Duration(arrow_schema::TimeUnit::Millisecond),
+            DataType::Interval(arrow_schema::IntervalUnit::YearMonth),
+            DataType::Interval(arrow_schema::IntervalUnit::MonthDayNano),
+        ] {
+            let dtype = DType::from_arrow(&Field::new("t", data_type.clone(), true));
+            assert!(matches!(dtype, DType::Extension(..)));
+            assert_eq!(infer_data_type(&dtype).unwrap(), data_type);
+        }
+    }
+
+    #[test]
+    fn test_unsupported_field() {
+        let field = Field::new("f", DataType::FixedSizeBinary(4), false);
+        assert!(DType::try_from_arrow(&field).is_err());
+    }
+
     fn the_struct() -> StructDType {
         StructDType::new(
             FieldNames::from([
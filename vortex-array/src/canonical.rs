@@ -8,21 +8,25 @@ use arrow_array::types::{
 };
 use arrow_array::{
     ArrayRef, ArrowPrimitiveType, BooleanArray as ArrowBoolArray, Date32Array, Date64Array,
+    Decimal128Array, Decimal256Array, DurationMicrosecondArray, DurationMillisecondArray,
+    DurationNanosecondArray, DurationSecondArray, IntervalDayTimeArray, IntervalMonthDayNanoArray,
+    IntervalYearMonthArray, LargeListArray, ListArray as ArrowListArray,
     NullArray as ArrowNullArray, PrimitiveArray as ArrowPrimitiveArray,
     StructArray as ArrowStructArray, Time32MillisecondArray, Time32SecondArray,
     Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
     TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray,
 };
-use arrow_buffer::ScalarBuffer;
+use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano, NullBuffer, ScalarBuffer};
 use arrow_schema::{Field, Fields};
-use vortex_datetime_dtype::{is_temporal_ext_type, TemporalMetadata, TimeUnit};
-use vortex_dtype::{DType, NativePType, PType};
+use vortex_datetime_dtype::{is_temporal_ext_type, IntervalUnit, TemporalMetadata, TimeUnit};
+use vortex_dtype::{is_decimal_ext_type, DType, NativePType, PType, MAX_DECIMAL128_PRECISION};
 use vortex_error::{vortex_bail, VortexResult};
 
 use crate::array::{
-    varbinview_as_arrow, BoolArray, ExtensionArray, NullArray, PrimitiveArray, StructArray,
-    TemporalArray, VarBinViewArray,
+    varbinview_as_arrow, BoolArray, DecimalArray, ExtensionArray, ListArray, NullArray,
+    PrimitiveArray, StructArray, TemporalArray, VarBinViewArray,
 };
+use crate::arrow::wrappers::as_offset_buffer;
 use crate::compute::unary::try_cast;
 use crate::encoding::ArrayEncoding;
 use crate::validity::ArrayValidity;
@@ -58,6 +62,7 @@ pub enum Canonical {
     Bool(BoolArray),
     Primitive(PrimitiveArray),
     Struct(StructArray),
+    List(ListArray),
     VarBinView(VarBinViewArray),
     Extension(ExtensionArray),
 }
@@ -74,12 +79,16 @@ impl Canonical {
             Canonical::Bool(a) => bool_to_arrow(a)?,
             Canonical::Primitive(a) => primitive_to_arrow(a)?,
             Canonical::Struct(a) => struct_to_arrow(a)?,
+            Canonical::List(a) => list_to_arrow(a)?,
             Canonical::VarBinView(a) => varbinview_as_arrow(&a),
             Canonical::Extension(a) => {
-                if !is_temporal_ext_type(a.id()) {
+                if is_decimal_ext_type(a.id()) {
+                    decimal_to_arrow(DecimalArray::try_from(a)?)?
+                } else if is_temporal_ext_type(a.id()) {
+                    temporal_to_arrow(TemporalArray::try_from(&a.into_array())?)?
+                } else {
                     vortex_bail!("unsupported extension dtype with ID {}", a.id().as_ref())
                 }
-                temporal_to_arrow(TemporalArray::try_from(&a.into_array())?)?
             }
         })
     }
@@ -115,6 +124,13 @@ impl Canonical {
         }
     }
 
+    pub fn into_list(self) -> VortexResult<ListArray> {
+        match self {
+            Canonical::List(a) => Ok(a),
+            _ => vortex_bail!("Cannot unwrap ListArray from {:?}", &self),
+        }
+    }
+
     pub fn into_varbinview(self) -> VortexResult<VarBinViewArray> {
         match self {
             Canonical::VarBinView(a) => Ok(a),
@@ -210,6 +226,76 @@ fn struct_to_arrow(struct_array: StructArray) -> VortexResult<ArrayRef> {
     )?))
 }
 
+fn list_to_arrow(list_array: ListArray) -> VortexResult<ArrayRef> {
+    // Lists with 64-bit offsets, such as those imported from an Arrow `LargeList`, map to
+    // `LargeList`, all others to `List` with 32-bit offsets as picked by `infer_data_type`.
+    let offsets = list_array
+        .offsets()
+        .into_primitive()
+        .map_err(|err| err.with_context("Failed to canonicalize offsets"))?;
+    let large = offsets.ptype() == PType::I64;
+    let offsets_ptype = if large { PType::I64 } else { PType::I32 };
+    let offsets = if offsets.ptype() == offsets_ptype {
+        offsets
+    } else {
+        try_cast(offsets, offsets_ptype.into())
+            .and_then(|a| a.into_primitive())
+            .map_err(|err| {
+                err.with_context(format!(
+                    "Failed to cast offsets to PrimitiveArray of {offsets_ptype}"
+                ))
+            })?
+    };
+
+    let values = list_array
+        .elements()
+        .into_canonical()
+        .and_then(|c| c.into_arrow())
+        .map_err(|err| err.with_context("Failed to convert list elements to arrow"))?;
+    let field = Arc::new(Field::new_list_field(
+        values.data_type().clone(),
+        list_array.element_dtype().is_nullable(),
+    ));
+    let nulls = list_array.logical_validity().to_null_buffer()?;
+
+    Ok(if large {
+        Arc::new(LargeListArray::try_new(
+            field,
+            as_offset_buffer::<i64>(offsets),
+            values,
+            nulls,
+        )?)
+    } else {
+        Arc::new(ArrowListArray::try_new(
+            field,
+            as_offset_buffer::<i32>(offsets),
+            values,
+            nulls,
+        )?)
+    })
+}
+
+fn decimal_to_arrow(decimal_array: DecimalArray) -> VortexResult<ArrayRef> {
+    let metadata = decimal_array.decimal_metadata();
+    let nulls = decimal_array
+        .unscaled_values()
+        .with_dyn(|a| a.logical_validity())
+        .to_null_buffer()?;
+    let values = decimal_array.unscaled_i256()?;
+
+    Ok(if metadata.precision() <= MAX_DECIMAL128_PRECISION {
+        Arc::new(
+            Decimal128Array::new(values.iter().map(|v| v.as_i128()).collect(), nulls)
+                .with_precision_and_scale(metadata.precision(), metadata.scale())?,
+        )
+    } else {
+        Arc::new(
+            Decimal256Array::new(values.into(), nulls)
+                .with_precision_and_scale(metadata.precision(), metadata.scale())?,
+        )
+    })
+}
+
 fn temporal_to_arrow(temporal_array: TemporalArray) -> VortexResult<ArrayRef> {
     macro_rules! extract_temporal_values {
         ($values:expr, $prim:ty) => {{
@@ -283,9 +369,71 @@ fn temporal_to_arrow(temporal_array: TemporalArray) -> VortexResult<ArrayRef> {
                 ),
             }
         }
+        TemporalMetadata::Duration(time_unit) => {
+            let (scalars, nulls) = extract_temporal_values!(&temporal_array.temporal_values(), i64);
+            match time_unit {
+                TimeUnit::Ns => Arc::new(DurationNanosecondArray::new(scalars, nulls)),
+                TimeUnit::Us => Arc::new(DurationMicrosecondArray::new(scalars, nulls)),
+                TimeUnit::Ms => Arc::new(DurationMillisecondArray::new(scalars, nulls)),
+                TimeUnit::S => Arc::new(DurationSecondArray::new(scalars, nulls)),
+                _ => vortex_bail!(
+                    "Invalid TimeUnit {time_unit} for {}",
+                    temporal_array.ext_dtype().id()
+                ),
+            }
+        }
+        TemporalMetadata::Interval(interval_unit) => match interval_unit {
+            IntervalUnit::YearMonth => {
+                let (scalars, nulls) =
+                    extract_temporal_values!(&temporal_array.temporal_values(), i32);
+                Arc::new(IntervalYearMonthArray::new(scalars, nulls))
+            }
+            IntervalUnit::DayTime => {
+                let (components, nulls) = interval_components(temporal_array.temporal_values())?;
+                let [days, millis] = components.as_slice() else {
+                    vortex_bail!("Expected 2 components for {interval_unit} interval");
+                };
+                Arc::new(IntervalDayTimeArray::new(
+                    days.maybe_null_slice::<i32>()
+                        .iter()
+                        .zip(millis.maybe_null_slice::<i32>())
+                        .map(|(d, ms)| IntervalDayTime::new(*d, *ms))
+                        .collect(),
+                    nulls,
+                ))
+            }
+            IntervalUnit::MonthDayNano => {
+                let (components, nulls) = interval_components(temporal_array.temporal_values())?;
+                let [months, days, nanos] = components.as_slice() else {
+                    vortex_bail!("Expected 3 components for {interval_unit} interval");
+                };
+                Arc::new(IntervalMonthDayNanoArray::new(
+                    months
+                        .maybe_null_slice::<i32>()
+                        .iter()
+                        .zip(days.maybe_null_slice::<i32>())
+                        .zip(nanos.maybe_null_slice::<i64>())
+                        .map(|((m, d), ns)| IntervalMonthDayNano::new(*m, *d, *ns))
+                        .collect(),
+                    nulls,
+                ))
+            }
+        },
     })
 }
 
+/// Canonicalize the components of an interval that is stored as a struct, along with the nulls of
+/// the intervals themselves.
+fn interval_components(values: Array) -> VortexResult<(Vec<PrimitiveArray>, Option<NullBuffer>)> {
+    let storage = values.into_struct()?;
+    let nulls = storage.logical_validity().to_null_buffer()?;
+    let components = storage
+        .children()
+        .map(|c| c.into_primitive())
+        .collect::<VortexResult<Vec<_>>>()?;
+    Ok((components, nulls))
+}
+
 /// Support trait for transmuting an array into its [vortex_dtype::DType]'s canonical encoding.
 ///
 /// This conversion ensures that the array's encoding matches one of the builtin canonical
@@ -312,6 +460,8 @@ pub trait IntoArrayVariant {
 
     fn into_struct(self) -> VortexResult<StructArray>;
 
+    fn into_list(self) -> VortexResult<ListArray>;
+
     fn into_varbinview(self) -> VortexResult<VarBinViewArray>;
 
     fn into_extension(self) -> VortexResult<ExtensionArray>;
@@ -337,6 +487,10 @@ where
         self.into_canonical()?.into_struct()
     }
 
+    fn into_list(self) -> VortexResult<ListArray> {
+        self.into_canonical()?.into_list()
+    }
+
     fn into_varbinview(self) -> VortexResult<VarBinViewArray> {
         self.into_canonical()?.into_varbinview()
     }
@@ -368,6 +522,7 @@ impl From<Canonical> for Array {
             Canonical::Bool(a) => a.into(),
             Canonical::Primitive(a) => a.into(),
             Canonical::Struct(a) => a.into(),
+            Canonical::List(a) => a.into(),
             Canonical::VarBinView(a) => a.into(),
             Canonical::Extension(a) => a.into(),
         }
@@ -381,8 +536,8 @@ mod test {
     use arrow_array::cast::AsArray;
     use arrow_array::types::{Int32Type, Int64Type, UInt64Type};
     use arrow_array::{
-        Array, PrimitiveArray as ArrowPrimitiveArray, StringViewArray,
-        StructArray as ArrowStructArray,
+        Array, LargeListArray, ListArray as ArrowListArray, PrimitiveArray as ArrowPrimitiveArray,
+        StringViewArray, StructArray as ArrowStructArray,
     };
     use arrow_buffer::NullBufferBuilder;
     use arrow_schema::{DataType, Field};
@@ -505,4 +660,47 @@ mod test {
                 .as_struct()
         );
     }
+
+    #[test]
+    fn roundtrip_list() {
+        let arrow_list = ArrowListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(3), None, Some(5)]),
+        ]);
+
+        let vortex_list = crate::Array::from_arrow(&arrow_list, true);
+        assert_eq!(vortex_list.len(), 4);
+
+        assert_eq!(
+            &arrow_list,
+            vortex_list
+                .into_canonical()
+                .unwrap()
+                .into_arrow()
+                .unwrap()
+                .as_list::<i32>()
+        );
+    }
+
+    #[test]
+    fn roundtrip_large_list() {
+        let arrow_list = LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3)]),
+        ]);
+
+        let vortex_list = crate::Array::from_arrow(&arrow_list, true);
+        assert_eq!(
+            &arrow_list,
+            vortex_list
+                .into_canonical()
+                .unwrap()
+                .into_arrow()
+                .unwrap()
+                .as_list::<i64>()
+        );
+    }
 }
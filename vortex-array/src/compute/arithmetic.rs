@@ -0,0 +1,350 @@
+use core::fmt;
+use std::fmt::{Display, Formatter};
+
+use arrow_arith::numeric;
+use arrow_array::{Datum, Scalar as ArrowScalar};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::{Constant, ConstantArray};
+use crate::arrow::FromArrowArray;
+use crate::compute::unary::scalar_at;
+use crate::{Array, ArrayDType, ArrayDef, IntoArray, IntoCanonical};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ArithmeticOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl Display for ArithmeticOperator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let display = match &self {
+            ArithmeticOperator::Add => "+",
+            ArithmeticOperator::Sub => "-",
+            ArithmeticOperator::Mul => "*",
+            ArithmeticOperator::Div => "/",
+            ArithmeticOperator::Rem => "%",
+        };
+        Display::fmt(display, f)
+    }
+}
+
+impl ArithmeticOperator {
+    /// Whether the operands can be swapped without changing the result of the operation
+    pub fn is_commutative(self) -> bool {
+        matches!(self, ArithmeticOperator::Add | ArithmeticOperator::Mul)
+    }
+}
+
+/// How integer overflow is handled by [`arithmetic`].
+///
+/// The default is checked, where overflow is an error.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ArithmeticOptions {
+    /// Integer overflow wraps around instead of failing, like arithmetic in DataFusion.
+    pub wrapping: bool,
+}
+
+/// Element-wise arithmetic between two primitive arrays of the same type.
+///
+/// Integer overflow is an error unless [`ArithmeticOptions::wrapping`] is set, integer division
+/// by zero is always an error, and a null on either side produces a null. The result is nullable
+/// if either operand is nullable, where a non-null constant operand takes on the nullability of
+/// the other side.
+pub fn arithmetic(
+    lhs: impl AsRef<Array>,
+    rhs: impl AsRef<Array>,
+    operator: ArithmeticOperator,
+    options: ArithmeticOptions,
+) -> VortexResult<Array> {
+    let lhs = lhs.as_ref();
+    let rhs = rhs.as_ref();
+
+    if lhs.len() != rhs.len() {
+        vortex_bail!("Arithmetic operations only support arrays of the same length");
+    }
+
+    if !matches!(lhs.dtype(), DType::Primitive(..)) {
+        vortex_bail!(
+            "Arithmetic operations are only supported on primitive arrays, found {}",
+            lhs.dtype()
+        );
+    }
+
+    // Both sides must have the same type until arithmetic supports type coercion
+    if !lhs.dtype().eq_ignore_nullability(rhs.dtype()) {
+        vortex_bail!("Arithmetic operations only support arrays of the same type");
+    }
+
+    let aligned_lhs = align_constant_nullability(lhs, rhs)?;
+    let aligned_rhs = align_constant_nullability(rhs, lhs)?;
+    let (lhs, rhs) = (&aligned_lhs, &aligned_rhs);
+
+    if operator.is_commutative() && lhs.is_encoding(Constant::ID) && !rhs.is_encoding(Constant::ID)
+    {
+        return arithmetic(rhs, lhs, operator, options);
+    }
+
+    if let Some(result) = lhs.with_dyn(|a| a.arithmetic(rhs, operator, options)) {
+        return result;
+    }
+
+    if operator.is_commutative() {
+        if let Some(result) = rhs.with_dyn(|a| a.arithmetic(lhs, operator, options)) {
+            return result;
+        }
+    }
+
+    // Fallback to the arrow kernels on canonical types
+    let nullable = lhs.dtype().is_nullable() || rhs.dtype().is_nullable();
+    let left = to_datum(lhs)?;
+    let right = to_datum(rhs)?;
+    let (left, right) = (left.as_ref(), right.as_ref());
+    let result = match (operator, options.wrapping) {
+        (ArithmeticOperator::Add, false) => numeric::add(left, right)?,
+        (ArithmeticOperator::Add, true) => numeric::add_wrapping(left, right)?,
+        (ArithmeticOperator::Sub, false) => numeric::sub(left, right)?,
+        (ArithmeticOperator::Sub, true) => numeric::sub_wrapping(left, right)?,
+        (ArithmeticOperator::Mul, false) => numeric::mul(left, right)?,
+        (ArithmeticOperator::Mul, true) => numeric::mul_wrapping(left, right)?,
+        (ArithmeticOperator::Div, _) => numeric::div(left, right)?,
+        (ArithmeticOperator::Rem, _) => numeric::rem(left, right)?,
+    };
+    let result = Array::from_arrow(result, nullable);
+
+    // Two constants produce a single value
+    if lhs.is_encoding(Constant::ID) && rhs.is_encoding(Constant::ID) {
+        return Ok(ConstantArray::new(scalar_at(&result, 0)?, lhs.len()).into_array());
+    }
+
+    Ok(result)
+}
+
+/// Element-wise arithmetic between an array and a scalar, see [`arithmetic`].
+pub fn arithmetic_scalar(
+    array: impl AsRef<Array>,
+    scalar: &Scalar,
+    operator: ArithmeticOperator,
+    options: ArithmeticOptions,
+) -> VortexResult<Array> {
+    let array = array.as_ref();
+    arithmetic(
+        array,
+        ConstantArray::new(scalar.clone(), array.len()),
+        operator,
+        options,
+    )
+}
+
+/// A non-null constant can't introduce nulls, so it takes on the nullability of the other operand.
+fn align_constant_nullability(array: &Array, other: &Array) -> VortexResult<Array> {
+    let nullability = other.dtype().nullability();
+    match ConstantArray::try_from(array) {
+        Ok(constant)
+            if !constant.scalar_value().is_null() && array.dtype().nullability() != nullability =>
+        {
+            let scalar = constant
+                .owned_scalar()
+                .cast(&array.dtype().with_nullability(nullability))?;
+            Ok(ConstantArray::new(scalar, array.len()).into_array())
+        }
+        _ => Ok(array.clone()),
+    }
+}
+
+/// Constants are passed to arrow as scalars instead of being expanded
+fn to_datum(array: &Array) -> VortexResult<Box<dyn Datum>> {
+    Ok(match ConstantArray::try_from(array) {
+        Ok(constant) => Box::new(ArrowScalar::new(
+            ConstantArray::new(constant.owned_scalar(), 1)
+                .into_canonical()?
+                .into_arrow()?,
+        )),
+        Err(_) => Box::new(array.clone().into_canonical()?.into_arrow()?),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_dtype::Nullability;
+    use vortex_scalar::Scalar;
+
+    use crate::array::{Constant, ConstantArray, PrimitiveArray};
+    use crate::compute::unary::scalar_at;
+    use crate::compute::{arithmetic, arithmetic_scalar, ArithmeticOperator, ArithmeticOptions};
+    use crate::{Array, ArrayDType, ArrayDef, IntoArray, IntoArrayVariant};
+
+    fn values(array: &Array) -> Vec<Option<i32>> {
+        (0..array.len())
+            .map(|i| {
+                let scalar = scalar_at(array, i).unwrap();
+                (!scalar.is_null()).then(|| i32::try_from(&scalar).unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn arrays() {
+        let lhs = PrimitiveArray::from_nullable_vec(vec![Some(7), None, Some(-9), Some(20)]);
+        let rhs = PrimitiveArray::from(vec![2, 3, 4, 5]);
+
+        let expected = [
+            (
+                ArithmeticOperator::Add,
+                vec![Some(9), None, Some(-5), Some(25)],
+            ),
+            (
+                ArithmeticOperator::Sub,
+                vec![Some(5), None, Some(-13), Some(15)],
+            ),
+            (
+                ArithmeticOperator::Mul,
+                vec![Some(14), None, Some(-36), Some(100)],
+            ),
+            (
+                ArithmeticOperator::Div,
+                vec![Some(3), None, Some(-2), Some(4)],
+            ),
+            (
+                ArithmeticOperator::Rem,
+                vec![Some(1), None, Some(-1), Some(0)],
+            ),
+        ];
+        for (operator, expected) in expected {
+            let result = arithmetic(
+                lhs.as_ref(),
+                rhs.as_ref(),
+                operator,
+                ArithmeticOptions::default(),
+            )
+            .unwrap();
+            assert!(result.dtype().is_nullable());
+            assert_eq!(values(&result), expected, "{operator}");
+        }
+    }
+
+    #[test]
+    fn scalars() {
+        let array = PrimitiveArray::from(vec![10, 20, 30]).into_array();
+
+        let result = arithmetic_scalar(
+            &array,
+            &Scalar::primitive(5, Nullability::Nullable),
+            ArithmeticOperator::Sub,
+            ArithmeticOptions::default(),
+        )
+        .unwrap();
+        // A non-null scalar doesn't make the result nullable
+        assert!(!result.dtype().is_nullable());
+        assert_eq!(values(&result), vec![Some(5), Some(15), Some(25)]);
+
+        let result = arithmetic(
+            ConstantArray::new(2, array.len()),
+            &array,
+            ArithmeticOperator::Mul,
+            ArithmeticOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(values(&result), vec![Some(20), Some(40), Some(60)]);
+
+        let result = arithmetic(
+            ConstantArray::new(100, array.len()),
+            &array,
+            ArithmeticOperator::Div,
+            ArithmeticOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(values(&result), vec![Some(10), Some(5), Some(3)]);
+
+        let result = arithmetic_scalar(
+            &array,
+            &Scalar::null(array.dtype().as_nullable()),
+            ArithmeticOperator::Add,
+            ArithmeticOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(values(&result), vec![None, None, None]);
+    }
+
+    #[test]
+    fn constants() {
+        let result = arithmetic(
+            ConstantArray::new(6, 4),
+            ConstantArray::new(7, 4),
+            ArithmeticOperator::Mul,
+            ArithmeticOptions::default(),
+        )
+        .unwrap();
+        assert!(result.is_encoding(Constant::ID));
+        assert_eq!(values(&result), vec![Some(42); 4]);
+    }
+
+    #[test]
+    fn checked() {
+        let array = PrimitiveArray::from(vec![1u8, 200]).into_array();
+        arithmetic_scalar(
+            &array,
+            &100u8.into(),
+            ArithmeticOperator::Add,
+            ArithmeticOptions::default(),
+        )
+        .expect_err("Expected overflow");
+        arithmetic_scalar(
+            &array,
+            &0u8.into(),
+            ArithmeticOperator::Div,
+            ArithmeticOptions::default(),
+        )
+        .expect_err("Expected division by zero");
+
+        // Overflow in null positions is ignored
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1u8), None]);
+        let result = arithmetic(
+            array.as_ref(),
+            PrimitiveArray::from(vec![1u8, 255]).as_ref(),
+            ArithmeticOperator::Add,
+            ArithmeticOptions::default(),
+        )
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+        assert_eq!(result.maybe_null_slice::<u8>()[0], 2);
+    }
+
+    #[test]
+    fn wrapping() {
+        let array = PrimitiveArray::from(vec![1u8, 200]).into_array();
+        let options = ArithmeticOptions { wrapping: true };
+
+        let result = arithmetic_scalar(&array, &100u8.into(), ArithmeticOperator::Add, options)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(result.maybe_null_slice::<u8>(), [101, 44]);
+
+        let result = arithmetic_scalar(&array, &2u8.into(), ArithmeticOperator::Sub, options)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(result.maybe_null_slice::<u8>(), [255, 198]);
+
+        arithmetic_scalar(&array, &0u8.into(), ArithmeticOperator::Div, options)
+            .expect_err("Expected division by zero");
+    }
+
+    #[test]
+    fn mismatched_types() {
+        arithmetic(
+            PrimitiveArray::from(vec![1i32]).as_ref(),
+            PrimitiveArray::from(vec![1i64]).as_ref(),
+            ArithmeticOperator::Add,
+            ArithmeticOptions::default(),
+        )
+        .expect_err("Expected type mismatch error");
+    }
+}
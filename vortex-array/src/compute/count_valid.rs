@@ -0,0 +1,55 @@
+use vortex_error::{vortex_err, VortexResult};
+
+use crate::stats::ArrayStatistics;
+use crate::validity::LogicalValidity;
+use crate::Array;
+
+pub trait CountValidFn {
+    /// Number of elements of the array that aren't null.
+    fn count_valid(&self) -> VortexResult<usize>;
+}
+
+/// Count the elements of an array that aren't null.
+pub fn count_valid(array: impl AsRef<Array>) -> VortexResult<usize> {
+    let array = array.as_ref();
+    if let Some(count) = array.with_dyn(|a| a.count_valid().map(|f| f.count_valid())) {
+        return count;
+    }
+
+    match array.with_dyn(|a| a.logical_validity()) {
+        LogicalValidity::AllValid(len) => Ok(len),
+        LogicalValidity::AllInvalid(_) => Ok(0),
+        LogicalValidity::Array(validity) => validity
+            .statistics()
+            .compute_true_count()
+            .ok_or_else(|| vortex_err!("Failed to compute true count of validity")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ChunkedArray, ConstantArray, PrimitiveArray};
+    use crate::compute::count_valid;
+    use crate::IntoArray;
+
+    #[test]
+    fn count() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1), None, Some(3)]);
+        assert_eq!(count_valid(array.as_ref()).unwrap(), 2);
+
+        let dtype = DType::Primitive(PType::I32, Nullability::Nullable);
+        let chunked = ChunkedArray::try_new(
+            vec![
+                array.into_array(),
+                ConstantArray::new(Scalar::null(dtype.clone()), 4).into_array(),
+                ConstantArray::new(Scalar::primitive(5, Nullability::Nullable), 2).into_array(),
+            ],
+            dtype,
+        )
+        .unwrap();
+        assert_eq!(count_valid(chunked.as_ref()).unwrap(), 4);
+    }
+}
@@ -0,0 +1,172 @@
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::{Array, ArrayDType, IntoCanonical};
+
+/// Variants of the SQL `LIKE` operator and of regular expression matches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LikeOptions {
+    /// `NOT LIKE`, strings that don't match the pattern evaluate to true.
+    pub negated: bool,
+    /// `ILIKE`, the pattern is matched ignoring case.
+    pub case_insensitive: bool,
+    /// The pattern is a regular expression that may match anywhere in the string, like the
+    /// Postgres `~` operator, instead of a `LIKE` pattern.
+    pub regex: bool,
+}
+
+pub trait LikeFn {
+    /// Match every string of the array against a SQL `LIKE` pattern, where `%` matches any
+    /// sequence of characters, `_` matches a single character and `\` escapes the next character.
+    /// With [`LikeOptions::regex`] the pattern is a regular expression instead.
+    ///
+    /// Null strings produce null results.
+    fn like(&self, pattern: &str, options: LikeOptions) -> VortexResult<Array>;
+}
+
+/// Match a utf8 array against a SQL `LIKE` pattern or a regular expression, returning a boolean
+/// array.
+///
+/// Encodings without a [`LikeFn`] are canonicalized and matched with the Arrow kernels.
+pub fn like(array: impl AsRef<Array>, pattern: &str, options: LikeOptions) -> VortexResult<Array> {
+    let array = array.as_ref();
+    if !matches!(array.dtype(), DType::Utf8(_)) {
+        vortex_bail!(
+            "LIKE is only supported on utf8 arrays, found {}",
+            array.dtype()
+        );
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.like().map(|f| f.like(pattern, options))) {
+        return result;
+    }
+
+    let canonical = array.clone().into_canonical()?.into_varbinview()?;
+    LikeFn::like(&canonical, pattern, options)
+}
+
+/// The literal prefix of a pattern of the form `prefix%` without any other wildcards or escapes,
+/// which matches exactly the strings that start with it.
+pub fn like_literal_prefix(pattern: &str) -> Option<&str> {
+    let prefix = pattern.strip_suffix('%')?;
+    (!prefix.contains(['%', '_', '\\'])).then_some(prefix)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{BoolArray, ChunkedArray, VarBinArray};
+    use crate::compute::{like, like_literal_prefix, LikeOptions};
+    use crate::validity::ArrayValidity;
+    use crate::{ArrayDType, IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn like_canonical_fallback() {
+        let array = ChunkedArray::from_iter([
+            VarBinArray::from_iter(
+                [Some("error: disk full"), None],
+                vortex_dtype::DType::Utf8(vortex_dtype::Nullability::Nullable),
+            )
+            .into_array(),
+            VarBinArray::from_iter(
+                [Some("ERROR: timeout"), Some("info: ok")],
+                vortex_dtype::DType::Utf8(vortex_dtype::Nullability::Nullable),
+            )
+            .into_array(),
+        ])
+        .into_array();
+
+        let matched = like(&array, "error%", LikeOptions::default())
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert!(matched.dtype().is_nullable());
+        assert_eq!(
+            nullable_values(&matched),
+            vec![Some(true), None, Some(false), Some(false)]
+        );
+
+        let matched = like(
+            &array,
+            "%_rror:%",
+            LikeOptions {
+                negated: true,
+                case_insensitive: true,
+                regex: false,
+            },
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            nullable_values(&matched),
+            vec![Some(false), None, Some(false), Some(true)]
+        );
+    }
+
+    #[test]
+    fn regex_canonical_fallback() {
+        let array = VarBinArray::from_iter(
+            [
+                Some("error: disk full"),
+                None,
+                Some("ERROR 42"),
+                Some("info: ok"),
+            ],
+            vortex_dtype::DType::Utf8(vortex_dtype::Nullability::Nullable),
+        )
+        .into_array();
+
+        let regex = |pattern: &str, negated: bool, case_insensitive: bool| {
+            nullable_values(
+                &like(
+                    &array,
+                    pattern,
+                    LikeOptions {
+                        negated,
+                        case_insensitive,
+                        regex: true,
+                    },
+                )
+                .unwrap()
+                .into_bool()
+                .unwrap(),
+            )
+        };
+        assert_eq!(
+            regex("^error", false, false),
+            vec![Some(true), None, Some(false), Some(false)]
+        );
+        assert_eq!(
+            regex("^error", false, true),
+            vec![Some(true), None, Some(true), Some(false)]
+        );
+        assert_eq!(
+            regex("[0-9]+$", true, false),
+            vec![Some(true), None, Some(false), Some(true)]
+        );
+        assert!(like(
+            &array,
+            "(",
+            LikeOptions {
+                regex: true,
+                ..LikeOptions::default()
+            }
+        )
+        .is_err());
+    }
+
+    fn nullable_values(array: &BoolArray) -> Vec<Option<bool>> {
+        (0..array.len())
+            .map(|i| array.is_valid(i).then(|| array.boolean_buffer().value(i)))
+            .collect()
+    }
+
+    #[test]
+    fn literal_prefix() {
+        assert_eq!(like_literal_prefix("error%"), Some("error"));
+        assert_eq!(like_literal_prefix("%"), Some(""));
+        assert_eq!(like_literal_prefix("err_r%"), None);
+        assert_eq!(like_literal_prefix("error"), None);
+        assert_eq!(like_literal_prefix("100\\%%"), None);
+    }
+}
@@ -0,0 +1,117 @@
+use std::cmp::Ordering;
+
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::stats::{ArrayStatistics, Stat};
+use crate::{Array, ArrayDType, IntoCanonical};
+
+/// The smallest and largest valid values of an array.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinMaxResult {
+    pub min: Scalar,
+    pub max: Scalar,
+}
+
+impl MinMaxResult {
+    /// Combine the extremes of two parts of an array, either of which may not have any valid values.
+    pub fn merge(lhs: Option<Self>, rhs: Option<Self>) -> Option<Self> {
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => Some(Self {
+                min: if rhs.min.partial_cmp(&lhs.min) == Some(Ordering::Less) {
+                    rhs.min
+                } else {
+                    lhs.min
+                },
+                max: if rhs.max.partial_cmp(&lhs.max) == Some(Ordering::Greater) {
+                    rhs.max
+                } else {
+                    lhs.max
+                },
+            }),
+            (lhs, rhs) => lhs.or(rhs),
+        }
+    }
+}
+
+pub trait MinMaxFn {
+    /// The extremes of the valid values of the array, or `None` if there are none.
+    fn min_max(&self) -> VortexResult<Option<MinMaxResult>>;
+}
+
+/// Compute the smallest and largest non-null values of an array, or `None` if it doesn't have any.
+///
+/// The returned scalars have the non-nullable dtype of the array. Encodings without a [`MinMaxFn`]
+/// fall back to the [`Stat::Min`] and [`Stat::Max`] statistics of the array or its canonical form.
+pub fn min_max(array: impl AsRef<Array>) -> VortexResult<Option<MinMaxResult>> {
+    let array = array.as_ref();
+    if array.is_empty() {
+        return Ok(None);
+    }
+
+    let result = match array.with_dyn(|a| a.min_max().map(|f| f.min_max())) {
+        Some(result) => result?,
+        None => match stats_min_max(array) {
+            Some(result) => result,
+            None => stats_min_max(&Array::from(array.clone().into_canonical()?))
+                .ok_or_else(|| vortex_err!("Min and max aren't supported for {}", array.dtype()))?,
+        },
+    };
+
+    let dtype = array.dtype().as_nonnullable();
+    result
+        .map(|MinMaxResult { min, max }| {
+            Ok(MinMaxResult {
+                min: min.cast(&dtype)?,
+                max: max.cast(&dtype)?,
+            })
+        })
+        .transpose()
+}
+
+/// Min and max from the statistics, `None` if they can't be computed and `Some(None)` if the
+/// array doesn't have any valid values.
+fn stats_min_max(array: &Array) -> Option<Option<MinMaxResult>> {
+    let stats = array.statistics();
+    let min = stats.compute(Stat::Min)?;
+    let max = stats.compute(Stat::Max)?;
+    Some((!min.is_null() && !max.is_null()).then_some(MinMaxResult { min, max }))
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::array::{BoolArray, PrimitiveArray, VarBinArray};
+    use crate::compute::min_max;
+
+    #[test]
+    fn primitive() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(5u16), None, Some(1), Some(9)]);
+        let result = min_max(array.as_ref()).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(1u16));
+        assert_eq!(result.max, Scalar::from(9u16));
+    }
+
+    #[test]
+    fn no_valid_values() {
+        let array = PrimitiveArray::from_nullable_vec(vec![None::<i64>, None]);
+        assert!(min_max(array.as_ref()).unwrap().is_none());
+
+        let array = PrimitiveArray::from(Vec::<i64>::new());
+        assert!(min_max(array.as_ref()).unwrap().is_none());
+    }
+
+    #[test]
+    fn bool_and_utf8() {
+        let array = BoolArray::from_iter([Some(true), None, Some(true)]);
+        let result = min_max(array.as_ref()).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(true));
+        assert_eq!(result.max, Scalar::from(true));
+
+        let array = VarBinArray::from(vec!["hello", "abc", "world"]);
+        let result = min_max(array.as_ref()).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from("abc"));
+        assert_eq!(result.max, Scalar::from("world"));
+    }
+}
@@ -7,28 +7,60 @@
 //! implementations of these operators, else we will decode, and perform the equivalent operator
 //! from Arrow.
 
+pub use arithmetic::{arithmetic, arithmetic_scalar, ArithmeticOperator, ArithmeticOptions};
 pub use boolean::{and, or, AndFn, OrFn};
 pub use compare::{compare, scalar_cmp, CompareFn, MaybeCompareFn, Operator};
+pub use count_valid::{count_valid, CountValidFn};
 pub use filter::{filter, FilterFn};
+pub use like::{like, like_literal_prefix, LikeFn, LikeOptions};
+pub use min_max::{min_max, MinMaxFn, MinMaxResult};
 pub use search_sorted::*;
 pub use slice::{slice, SliceFn};
+pub use sort::{argsort, sort, ArgsortFn, SortFn, SortOptions};
+pub use sum::{mean, sum, SumAccumulator, SumFn};
 pub use take::{take, TakeFn};
 use unary::{CastFn, FillForwardFn, ScalarAtFn, SubtractScalarFn};
 use vortex_error::VortexResult;
 
 use crate::Array;
 
+mod arithmetic;
 mod boolean;
 mod compare;
+mod count_valid;
 mod filter;
+mod like;
+mod min_max;
 mod search_sorted;
 mod slice;
+mod sort;
+mod sum;
 mod take;
 
 pub mod unary;
 
 /// Trait providing compute functions on top of Vortex arrays.
 pub trait ArrayCompute {
+    /// Element-wise arithmetic of arrays against other arrays, returning `None` if the
+    /// encoding has no specialized implementation for the operands.
+    ///
+    /// See: [arithmetic].
+    fn arithmetic(
+        &self,
+        _other: &Array,
+        _operator: ArithmeticOperator,
+        _options: ArithmeticOptions,
+    ) -> Option<VortexResult<Array>> {
+        None
+    }
+
+    /// Indices that sort the array.
+    ///
+    /// See: [ArgsortFn].
+    fn argsort(&self) -> Option<&dyn ArgsortFn> {
+        None
+    }
+
     /// Implemented for arrays that can be casted to different types.
     ///
     /// See: [CastFn].
@@ -36,6 +68,13 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Number of non-null elements of the array.
+    ///
+    /// See: [CountValidFn].
+    fn count_valid(&self) -> Option<&dyn CountValidFn> {
+        None
+    }
+
     /// Binary operator implementation for arrays against other arrays.
     ///
     ///See: [CompareFn].
@@ -57,6 +96,20 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Matching of utf8 arrays against SQL `LIKE` patterns.
+    ///
+    /// See: [LikeFn].
+    fn like(&self) -> Option<&dyn LikeFn> {
+        None
+    }
+
+    /// Smallest and largest non-null values of the array.
+    ///
+    /// See: [MinMaxFn].
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        None
+    }
+
     /// Single item indexing on Vortex arrays.
     ///
     /// See: [ScalarAtFn].
@@ -85,6 +138,20 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Sort the elements of an array.
+    ///
+    /// See: [SortFn].
+    fn sort(&self) -> Option<&dyn SortFn> {
+        None
+    }
+
+    /// Sum of the non-null values of a primitive array.
+    ///
+    /// See: [SumFn].
+    fn sum(&self) -> Option<&dyn SumFn> {
+        None
+    }
+
     /// Take a set of indices from an array. This often forces allocations and decoding of
     /// the receiver.
     ///
@@ -0,0 +1,159 @@
+use arrow_ord::sort::{lexsort_to_indices, SortColumn};
+use vortex_error::VortexResult;
+
+use crate::array::PrimitiveArray;
+use crate::compute::take;
+use crate::{Array, IntoArray, IntoCanonical};
+
+/// Order in which [`sort`] and [`argsort`] arrange the elements of an array.
+///
+/// The default is ascending with nulls last.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SortOptions {
+    pub descending: bool,
+    pub nulls_first: bool,
+}
+
+impl From<SortOptions> for arrow_schema::SortOptions {
+    fn from(value: SortOptions) -> Self {
+        Self {
+            descending: value.descending,
+            nulls_first: value.nulls_first,
+        }
+    }
+}
+
+pub trait ArgsortFn {
+    /// Indices that arrange the elements of the array in the given order, see [`argsort`].
+    fn argsort(&self, options: SortOptions) -> VortexResult<Array>;
+}
+
+pub trait SortFn {
+    /// The elements of the array arranged in the given order, see [`sort`].
+    fn sort(&self, options: SortOptions) -> VortexResult<Array>;
+}
+
+/// Compute the indices that would sort an array, as a non-nullable `u64` array.
+///
+/// Every [`ArgsortFn`] must produce `u64` indices as well, so callers can rely on the index type.
+/// Struct arrays are ordered lexicographically by their fields. The relative order of equal
+/// elements is not guaranteed to be preserved.
+pub fn argsort(array: impl AsRef<Array>, options: SortOptions) -> VortexResult<Array> {
+    let array = array.as_ref();
+    if let Some(indices) = array.with_dyn(|a| a.argsort().map(|f| f.argsort(options))) {
+        return indices;
+    }
+
+    // Fallback: sort the canonical array with Arrow, which supports all the canonical types
+    let values = array.clone().into_canonical()?.into_arrow()?;
+    let indices = lexsort_to_indices(
+        &[SortColumn {
+            values,
+            options: Some(options.into()),
+        }],
+        None,
+    )?;
+    let indices = indices
+        .values()
+        .iter()
+        .map(|&idx| idx as u64)
+        .collect::<Vec<_>>();
+    Ok(PrimitiveArray::from(indices).into_array())
+}
+
+/// Sort the elements of an array.
+///
+/// Encodings without a [`SortFn`] are sorted by taking the result of [`argsort`].
+pub fn sort(array: impl AsRef<Array>, options: SortOptions) -> VortexResult<Array> {
+    let array = array.as_ref();
+    if let Some(sorted) = array.with_dyn(|a| a.sort().map(|f| f.sort(options))) {
+        return sorted;
+    }
+
+    take(array, argsort(array, options)?)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::field::Field;
+
+    use crate::array::{BoolArray, PrimitiveArray, StructArray, VarBinViewArray};
+    use crate::compute::unary::scalar_at;
+    use crate::compute::{argsort, sort, take, SortOptions};
+    use crate::variants::StructArrayTrait;
+    use crate::{Array, IntoArray, IntoArrayVariant};
+
+    fn to_i32_vec(array: &Array) -> Vec<Option<i32>> {
+        (0..array.len())
+            .map(|i| {
+                let scalar = scalar_at(array, i).unwrap();
+                scalar.is_valid().then(|| i32::try_from(&scalar).unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sort_primitive() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(3), None, Some(-1), Some(7)]);
+
+        let sorted = sort(array.as_ref(), SortOptions::default()).unwrap();
+        assert_eq!(to_i32_vec(&sorted), vec![Some(-1), Some(3), Some(7), None]);
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let sorted = sort(array.as_ref(), options).unwrap();
+        assert_eq!(to_i32_vec(&sorted), vec![None, Some(7), Some(3), Some(-1)]);
+
+        let indices = argsort(array.as_ref(), options).unwrap();
+        assert_eq!(
+            indices.into_primitive().unwrap().maybe_null_slice::<u64>(),
+            &[1, 3, 0, 2]
+        );
+    }
+
+    #[test]
+    fn sort_bool_and_utf8() {
+        let array = BoolArray::from(vec![true, false, true]);
+        let sorted = sort(array.as_ref(), SortOptions::default())
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            sorted.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, true]
+        );
+
+        let array = VarBinViewArray::from_iter_str(["pear", "apple", "fig"]);
+        let indices = argsort(array.as_ref(), SortOptions::default()).unwrap();
+        assert_eq!(
+            indices.into_primitive().unwrap().maybe_null_slice::<u64>(),
+            &[1, 2, 0]
+        );
+    }
+
+    #[test]
+    fn sort_struct_by_key() {
+        let array = StructArray::from_fields(&[
+            ("a", PrimitiveArray::from(vec![2, 1, 2, 1]).into_array()),
+            ("b", PrimitiveArray::from(vec![5, 6, 4, 3]).into_array()),
+            ("c", PrimitiveArray::from(vec![0, 1, 2, 3]).into_array()),
+        ])
+        .unwrap();
+
+        // Order the rows by the (a, b) cluster key
+        let key = array
+            .project(&[Field::from("a"), Field::from("b")])
+            .unwrap();
+        let indices = argsort(key.as_ref(), SortOptions::default()).unwrap();
+        let sorted = take(array.as_ref(), &indices)
+            .unwrap()
+            .into_struct()
+            .unwrap();
+        assert_eq!(
+            to_i32_vec(&sorted.field(2).unwrap()),
+            vec![Some(3), Some(1), Some(2), Some(0)]
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use vortex_dtype::{DType, NativePType, Nullability, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::compute::count_valid;
+use crate::{Array, ArrayDType, IntoCanonical};
+
+pub trait SumFn {
+    /// Sum of the valid values of the array, see [`sum`] for the result type.
+    fn sum(&self) -> VortexResult<Scalar>;
+}
+
+/// Sum the non-null values of a primitive array.
+///
+/// Signed and unsigned integers are summed into a nullable `i64` and `u64` respectively, floats
+/// into a nullable `f64`. The result is null if the array doesn't have any valid values, and an
+/// error is returned if an integer sum overflows.
+pub fn sum(array: impl AsRef<Array>) -> VortexResult<Scalar> {
+    let array = array.as_ref();
+    if !matches!(array.dtype(), DType::Primitive(..)) {
+        vortex_bail!(
+            "Sum is only supported for primitive arrays, got {}",
+            array.dtype()
+        );
+    }
+
+    if let Some(sum) = array.with_dyn(|a| a.sum().map(|f| f.sum())) {
+        return sum;
+    }
+
+    // Fallback: canonicalize and sum the primitive array
+    array.clone().into_canonical()?.into_primitive()?.sum()
+}
+
+/// Arithmetic mean of the non-null values of a primitive array as a nullable `f64`, null if there
+/// aren't any.
+pub fn mean(array: impl AsRef<Array>) -> VortexResult<Scalar> {
+    let array = array.as_ref();
+    let dtype = DType::Primitive(PType::F64, Nullability::Nullable);
+    let count = count_valid(array)?;
+    if count == 0 {
+        return Ok(Scalar::null(dtype));
+    }
+
+    let total = f64::try_from(&sum(array)?.cast(&dtype)?)?;
+    Ok(Scalar::primitive(
+        total / count as f64,
+        Nullability::Nullable,
+    ))
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Total {
+    Signed(i64),
+    Unsigned(u64),
+    Float(f64),
+}
+
+/// Running sum of primitive values, used to implement [`SumFn`] for encodings that combine
+/// values from several parts of the array.
+#[derive(Debug, Clone)]
+pub struct SumAccumulator {
+    total: Total,
+    empty: bool,
+}
+
+impl SumAccumulator {
+    /// Create an accumulator for the values of an array with the given primitive dtype.
+    pub fn try_new(dtype: &DType) -> VortexResult<Self> {
+        let ptype = PType::try_from(dtype)?;
+        let total = if ptype.is_signed_int() {
+            Total::Signed(0)
+        } else if ptype.is_unsigned_int() {
+            Total::Unsigned(0)
+        } else {
+            Total::Float(0.0)
+        };
+        Ok(Self { total, empty: true })
+    }
+
+    /// The dtype of the sums produced by this accumulator.
+    pub fn dtype(&self) -> DType {
+        let ptype = match self.total {
+            Total::Signed(_) => PType::I64,
+            Total::Unsigned(_) => PType::U64,
+            Total::Float(_) => PType::F64,
+        };
+        DType::Primitive(ptype, Nullability::Nullable)
+    }
+
+    pub fn add<T: NativePType>(&mut self, value: T) -> VortexResult<()> {
+        self.add_repeated(value, 1)
+    }
+
+    /// Add `value` to the sum `count` times.
+    pub fn add_repeated<T: NativePType>(&mut self, value: T, count: usize) -> VortexResult<()> {
+        if count == 0 {
+            return Ok(());
+        }
+        self.empty = false;
+
+        let overflow = || vortex_err!("Sum of {} values overflowed", T::PTYPE);
+        self.total = match self.total {
+            Total::Signed(total) => Total::Signed(
+                value
+                    .to_i64()
+                    .and_then(|v| v.checked_mul(i64::try_from(count).ok()?))
+                    .and_then(|v| total.checked_add(v))
+                    .ok_or_else(overflow)?,
+            ),
+            Total::Unsigned(total) => Total::Unsigned(
+                value
+                    .to_u64()
+                    .and_then(|v| v.checked_mul(count as u64))
+                    .and_then(|v| total.checked_add(v))
+                    .ok_or_else(overflow)?,
+            ),
+            Total::Float(total) => {
+                Total::Float(total + value.to_f64().ok_or_else(overflow)? * count as f64)
+            }
+        };
+        Ok(())
+    }
+
+    /// Add a scalar to the sum `count` times, ignoring it if it's null.
+    ///
+    /// This can also be used to combine partial sums returned by [`sum`].
+    pub fn add_scalar(&mut self, value: &Scalar, count: usize) -> VortexResult<()> {
+        if value.is_null() || count == 0 {
+            return Ok(());
+        }
+
+        let value = value.cast(&self.dtype())?;
+        match self.total {
+            Total::Signed(_) => self.add_repeated(i64::try_from(&value)?, count),
+            Total::Unsigned(_) => self.add_repeated(u64::try_from(&value)?, count),
+            Total::Float(_) => self.add_repeated(f64::try_from(&value)?, count),
+        }
+    }
+
+    pub fn finish(self) -> Scalar {
+        if self.empty {
+            return Scalar::null(self.dtype());
+        }
+
+        match self.total {
+            Total::Signed(total) => Scalar::primitive(total, Nullability::Nullable),
+            Total::Unsigned(total) => Scalar::primitive(total, Nullability::Nullable),
+            Total::Float(total) => Scalar::primitive(total, Nullability::Nullable),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::PrimitiveArray;
+    use crate::compute::{mean, sum};
+
+    #[test]
+    fn sum_primitive() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(-4), Some(10)]);
+        assert_eq!(
+            sum(array.as_ref()).unwrap(),
+            Scalar::primitive(7i64, Nullability::Nullable)
+        );
+
+        let array = PrimitiveArray::from(vec![1.5f32, 2.5]);
+        assert_eq!(
+            sum(array.as_ref()).unwrap(),
+            Scalar::primitive(4.0f64, Nullability::Nullable)
+        );
+        assert_eq!(
+            mean(array.as_ref()).unwrap(),
+            Scalar::primitive(2.0f64, Nullability::Nullable)
+        );
+    }
+
+    #[test]
+    fn sum_all_null() {
+        let array = PrimitiveArray::from_nullable_vec(vec![None::<u8>, None]);
+        assert_eq!(
+            sum(array.as_ref()).unwrap(),
+            Scalar::null(DType::Primitive(PType::U64, Nullability::Nullable))
+        );
+        assert!(mean(array.as_ref()).unwrap().is_null());
+    }
+
+    #[test]
+    fn sum_overflow() {
+        let array = PrimitiveArray::from(vec![u64::MAX, 1]);
+        assert!(sum(array.as_ref()).is_err());
+    }
+}
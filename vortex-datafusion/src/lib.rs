@@ -5,14 +5,17 @@
 use std::any::Any;
 use std::fmt::{Debug, Formatter};
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use std::task::{Context, Poll};
 
 use arrow_array::RecordBatch;
 use arrow_schema::{DataType, Schema, SchemaRef};
 use datafusion::execution::{RecordBatchStream, SendableRecordBatchStream, TaskContext};
 use datafusion::prelude::{DataFrame, SessionContext};
-use datafusion_common::{exec_datafusion_err, DataFusionError, Result as DFResult, Statistics};
+use datafusion_common::stats::Precision;
+use datafusion_common::{
+    exec_datafusion_err, DataFusionError, Result as DFResult, ScalarValue, Statistics,
+};
 use datafusion_execution::object_store::ObjectStoreUrl;
 use datafusion_expr::{Expr, Operator};
 use datafusion_physical_plan::{DisplayAs, DisplayFormatType, ExecutionPlan, PlanProperties};
@@ -23,7 +26,7 @@ use persistent::provider::VortexFileTableProvider;
 use vortex::array::ChunkedArray;
 use vortex::{Array, ArrayDType, IntoArrayVariant};
 use vortex_dtype::field::Field;
-use vortex_error::{vortex_err, VortexResult};
+use vortex_error::vortex_err;
 
 use crate::statistics::chunked_array_df_stats;
 
@@ -40,6 +43,11 @@ const SUPPORTED_BINARY_OPS: &[Operator] = &[
     Operator::GtEq,
     Operator::Lt,
     Operator::LtEq,
+    Operator::Plus,
+    Operator::Minus,
+    Operator::Multiply,
+    Operator::Divide,
+    Operator::Modulo,
 ];
 
 fn supported_data_types(dt: DataType) -> bool {
@@ -164,37 +172,98 @@ fn can_be_pushed_down(expr: &Expr, schema: &Schema) -> bool {
             can_be_pushed_down(expr.left.as_ref(), schema)
                 & can_be_pushed_down(expr.right.as_ref(), schema)
         }
-        Expr::Column(col) => match schema.column_with_name(col.name()) {
-            Some((_, field)) => supported_data_types(field.data_type().clone()),
-            _ => false,
-        },
+        Expr::BinaryExpr(expr)
+            if matches!(
+                expr.op,
+                Operator::RegexMatch
+                    | Operator::RegexIMatch
+                    | Operator::RegexNotMatch
+                    | Operator::RegexNotIMatch
+            ) =>
+        {
+            is_string_literal(&expr.right) && can_be_pushed_down(&expr.left, schema)
+        }
+        Expr::Column(_) | Expr::ScalarFunction(_) => {
+            column_data_type(expr, schema).is_some_and(supported_data_types)
+        }
         Expr::Literal(lit) => supported_data_types(lit.data_type()),
+        Expr::IsNull(expr) | Expr::IsNotNull(expr) | Expr::Not(expr) => {
+            can_be_pushed_down(expr, schema)
+        }
+        Expr::InList(in_list) => {
+            can_be_pushed_down(&in_list.expr, schema)
+                && in_list.list.iter().all(|value| {
+                    matches!(value, Expr::Literal(_)) && can_be_pushed_down(value, schema)
+                })
+        }
+        Expr::Like(like) => {
+            matches!(like.escape_char, None | Some('\\'))
+                && is_string_literal(&like.pattern)
+                && can_be_pushed_down(&like.expr, schema)
+        }
         _ => false,
     }
 }
 
+fn is_string_literal(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Literal(
+            ScalarValue::Utf8(Some(_))
+                | ScalarValue::LargeUtf8(Some(_))
+                | ScalarValue::Utf8View(Some(_))
+        )
+    )
+}
+
+/// Data type of a column, or of a struct field nested in a column referenced through `get_field`
+fn column_data_type(expr: &Expr, schema: &Schema) -> Option<DataType> {
+    match expr {
+        Expr::Column(col) => schema
+            .column_with_name(col.name())
+            .map(|(_, field)| field.data_type().clone()),
+        Expr::ScalarFunction(func) if func.name() == "get_field" => {
+            let [source, Expr::Literal(ScalarValue::Utf8(Some(name)))] = func.args.as_slice()
+            else {
+                return None;
+            };
+            match column_data_type(source, schema)? {
+                DataType::Struct(fields) => fields
+                    .find(name)
+                    .map(|(_, field)| field.data_type().clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 /// Physical plan node for scans against an in-memory, possibly chunked Vortex Array.
 #[derive(Clone)]
 struct VortexScanExec {
     array: ChunkedArray,
     scan_projection: Vec<usize>,
     plan_properties: PlanProperties,
-    statistics: Statistics,
+    /// Whether to compute the min, max and null count of the projected columns, following the
+    /// `collect_statistics` option of the session.
+    collect_statistics: bool,
+    statistics: OnceLock<Statistics>,
 }
 
 impl VortexScanExec {
-    pub fn try_new(
+    pub fn new(
         array: ChunkedArray,
         scan_projection: Vec<usize>,
         plan_properties: PlanProperties,
-    ) -> VortexResult<Self> {
-        let statistics = chunked_array_df_stats(&array, &scan_projection)?;
-        Ok(Self {
+        collect_statistics: bool,
+    ) -> Self {
+        Self {
             array,
             scan_projection,
             plan_properties,
-            statistics,
-        })
+            collect_statistics,
+            statistics: OnceLock::new(),
+        }
     }
 }
 
@@ -307,6 +376,18 @@ impl ExecutionPlan for VortexScanExec {
     }
 
     fn statistics(&self) -> DFResult<Statistics> {
-        Ok(self.statistics.clone())
+        // DataFusion asks every plan for statistics while planning, column statistics are only
+        // computed if the session collects them and then only once
+        if !self.collect_statistics {
+            let mut statistics = Statistics::new_unknown(&self.schema());
+            statistics.num_rows = Precision::Exact(self.array.len());
+            return Ok(statistics);
+        }
+        if let Some(statistics) = self.statistics.get() {
+            return Ok(statistics.clone());
+        }
+
+        let statistics = chunked_array_df_stats(&self.array, &self.scan_projection)?;
+        Ok(self.statistics.get_or_init(|| statistics).clone())
     }
 }
@@ -26,6 +26,9 @@ use crate::{can_be_pushed_down, VortexScanExec};
 ///
 /// Only arrays that have a top-level [struct type](vortex_dtype::StructDType) can be exposed as
 /// a table to DataFusion.
+///
+/// Column statistics, which let DataFusion answer `MIN`, `MAX` and `COUNT` aggregates without a
+/// scan, are only computed if the session's `collect_statistics` option is enabled.
 #[derive(Debug, Clone)]
 pub struct VortexMemTable {
     array: ChunkedArray,
@@ -125,11 +128,12 @@ impl TableProvider for VortexMemTable {
                     ExecutionMode::Bounded,
                 );
 
-                Ok(Arc::new(VortexScanExec::try_new(
+                Ok(Arc::new(VortexScanExec::new(
                     self.array.clone(),
                     output_projection.clone(),
                     plan_properties,
-                )?))
+                    state.config_options().execution.collect_statistics,
+                )))
             }
         }
     }
@@ -208,13 +212,17 @@ fn make_filter_then_take_plan(
 #[cfg(test)]
 mod test {
     use arrow_array::cast::AsArray as _;
-    use arrow_array::types::Int64Type;
+    use arrow_array::types::{Int64Type, UInt16Type};
     use arrow_schema::{DataType, Field, Schema};
-    use datafusion::functions_aggregate::count::count_distinct;
-    use datafusion::prelude::SessionContext;
+    use datafusion::functions::core::expr_fn::get_field;
+    use datafusion::functions_aggregate::count::{count, count_distinct};
+    use datafusion::functions_aggregate::min_max::{max, min};
+    use datafusion::prelude::{SessionConfig, SessionContext};
     use datafusion_common::{Column, TableReference};
     use datafusion_expr::{and, col, lit, BinaryExpr, Expr, Operator};
+    use datafusion_physical_plan::displayable;
     use vortex::array::{PrimitiveArray, StructArray, VarBinViewArray};
+    use vortex::stats::{ArrayStatistics as _, Stat};
     use vortex::validity::Validity;
     use vortex::{Array, IntoArray};
 
@@ -351,7 +359,7 @@ mod test {
     fn test_can_be_pushed_down3() {
         let e = BinaryExpr {
             left: Box::new(col("nums")),
-            op: Operator::Modulo,
+            op: Operator::BitwiseAnd,
             right: Box::new(lit(5)),
         };
         let e = Expr::BinaryExpr(e);
@@ -362,6 +370,16 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_can_be_pushed_down_arithmetic() {
+        let e = (col("nums") % lit(5)).eq(lit(0));
+
+        assert!(can_be_pushed_down(
+            &e,
+            &Schema::new(vec![Field::new("nums", DataType::Int32, true)])
+        ));
+    }
+
     #[test]
     fn test_can_be_pushed_down4() {
         let e = and((col("a")).eq(lit(2u64)), col("b").eq(lit(true)));
@@ -373,4 +391,281 @@ mod test {
             ])
         ));
     }
+
+    #[test]
+    fn test_can_be_pushed_down_nested() {
+        let schema = Schema::new(vec![Field::new(
+            "s",
+            DataType::Struct(vec![Field::new("x", DataType::Int32, true)].into()),
+            true,
+        )]);
+
+        let e = get_field(col("s"), "x").eq(lit(2));
+        assert!(can_be_pushed_down(&e, &schema));
+
+        let e = get_field(col("s"), "y").eq(lit(2));
+        assert!(!can_be_pushed_down(&e, &schema));
+    }
+
+    #[test]
+    fn test_can_be_pushed_down_null_not_in_list() {
+        let schema = Schema::new(vec![Field::new("status", DataType::Utf8, true)]);
+
+        assert!(can_be_pushed_down(&col("status").is_not_null(), &schema));
+        assert!(can_be_pushed_down(&!col("status").is_null(), &schema));
+        assert!(can_be_pushed_down(
+            &col("status").in_list(vec![lit("a"), lit("b")], true),
+            &schema
+        ));
+        assert!(!can_be_pushed_down(
+            &col("status").in_list(vec![lit("a"), col("status")], false),
+            &schema
+        ));
+    }
+
+    #[test]
+    fn test_can_be_pushed_down_like() {
+        let schema = Schema::new(vec![Field::new("status", DataType::Utf8, true)]);
+
+        assert!(can_be_pushed_down(&col("status").like(lit("a%")), &schema));
+        assert!(can_be_pushed_down(
+            &col("status").not_ilike(lit("%b")),
+            &schema
+        ));
+        assert!(!can_be_pushed_down(
+            &col("status").like(col("status")),
+            &schema
+        ));
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_datafusion_pushdown_like() {
+        let ctx = SessionContext::new();
+
+        let df = ctx.read_mem_vortex(presidents_array()).unwrap();
+
+        let terms = df
+            .filter(
+                col("president")
+                    .like(lit("%a%"))
+                    .and(col("president").not_ilike(lit("j%"))),
+            )
+            .unwrap()
+            .select(vec![col("term_start")])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        let terms = terms
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<UInt16Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(terms, vec![1789, 1797, 1809, 1825]);
+    }
+
+    fn regex(expr: Expr, op: Operator, pattern: &str) -> Expr {
+        Expr::BinaryExpr(BinaryExpr::new(Box::new(expr), op, Box::new(lit(pattern))))
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_datafusion_pushdown_regex() {
+        let schema = Schema::new(vec![Field::new("president", DataType::Utf8, false)]);
+        let filter = regex(col("president"), Operator::RegexIMatch, "^m").and(regex(
+            col("president"),
+            Operator::RegexNotMatch,
+            "son$",
+        ));
+        assert!(can_be_pushed_down(&filter, &schema));
+        assert!(!can_be_pushed_down(
+            &Expr::BinaryExpr(BinaryExpr::new(
+                Box::new(col("president")),
+                Operator::RegexMatch,
+                Box::new(col("president")),
+            )),
+            &schema
+        ));
+
+        let ctx = SessionContext::new();
+        let terms = ctx
+            .read_mem_vortex(presidents_array())
+            .unwrap()
+            .filter(filter)
+            .unwrap()
+            .select(vec![col("term_start")])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        let terms = terms
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<UInt16Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(terms, vec![1817]);
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_datafusion_pushdown_arithmetic() {
+        let ctx = SessionContext::new();
+
+        let df = ctx.read_mem_vortex(presidents_array()).unwrap();
+
+        let terms = df
+            .filter(
+                (col("term_start") - lit(1789u16))
+                    .gt(lit(10u16))
+                    .and((col("term_start") % lit(2u16)).eq(lit(1u16))),
+            )
+            .unwrap()
+            .select(vec![col("term_start")])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        let terms = terms
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<UInt16Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(terms, vec![1801, 1809, 1817, 1825]);
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_datafusion_pushdown_arithmetic_overflow() {
+        let ctx = SessionContext::new();
+
+        let df = ctx.read_mem_vortex(presidents_array()).unwrap();
+
+        // Earlier terms wrap around like they do in DataFusion instead of failing the scan
+        let terms = df
+            .filter((col("term_start") - lit(1800u16)).lt(lit(10u16)))
+            .unwrap()
+            .select(vec![col("term_start")])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        let terms = terms
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<UInt16Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(terms, vec![1801, 1809]);
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_datafusion_collect_statistics() {
+        let array = presidents_array();
+        let president = array
+            .with_dyn(|a| a.as_struct_array().and_then(|s| s.field(0)))
+            .unwrap();
+
+        // Column statistics are only computed when the session collects statistics
+        SessionContext::new()
+            .read_mem_vortex(array.clone())
+            .unwrap()
+            .aggregate(vec![], vec![max(col("president"))])
+            .unwrap()
+            .create_physical_plan()
+            .await
+            .unwrap();
+        assert!(president.statistics().get(Stat::Max).is_none());
+
+        SessionContext::new_with_config(SessionConfig::new().with_collect_statistics(true))
+            .read_mem_vortex(array)
+            .unwrap()
+            .aggregate(vec![], vec![max(col("president"))])
+            .unwrap()
+            .create_physical_plan()
+            .await
+            .unwrap();
+        assert!(president.statistics().get(Stat::Max).is_some());
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_datafusion_aggregate_statistics() {
+        let ctx =
+            SessionContext::new_with_config(SessionConfig::new().with_collect_statistics(true));
+
+        let df = ctx
+            .read_mem_vortex(presidents_array())
+            .unwrap()
+            .aggregate(
+                vec![],
+                vec![
+                    min(col("term_start")),
+                    max(col("term_start")),
+                    max(col("president")),
+                    count(col("term_start")),
+                ],
+            )
+            .unwrap();
+
+        // The aggregates are answered from the exact statistics of the scan, without reading it
+        let plan = df.clone().create_physical_plan().await.unwrap();
+        let plan = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(!plan.contains("VortexScanExec"), "{plan}");
+
+        let batch = &df.collect().await.unwrap()[0];
+        assert_eq!(
+            batch
+                .column(0)
+                .as_primitive::<UInt16Type>()
+                .values()
+                .to_vec(),
+            vec![1789]
+        );
+        assert_eq!(
+            batch
+                .column(1)
+                .as_primitive::<UInt16Type>()
+                .values()
+                .to_vec(),
+            vec![1825]
+        );
+        assert_eq!(batch.column(2).as_string_view().value(0), "Washington");
+        assert_eq!(
+            batch
+                .column(3)
+                .as_primitive::<Int64Type>()
+                .values()
+                .to_vec(),
+            vec![6]
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_datafusion_pushdown_in_list() {
+        let ctx = SessionContext::new();
+
+        let df = ctx.read_mem_vortex(presidents_array()).unwrap();
+
+        let terms = df
+            .filter(
+                col("president")
+                    .in_list(vec![lit("Adams"), lit("Monroe")], false)
+                    .and(col("term_start").is_not_null())
+                    .and(col("term_start").in_list(vec![lit(1825u16)], true)),
+            )
+            .unwrap()
+            .select(vec![col("term_start")])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        let terms = terms
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<UInt16Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(terms, vec![1797, 1817]);
+    }
 }
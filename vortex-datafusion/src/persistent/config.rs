@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
-use arrow_schema::SchemaRef;
+use arrow_schema::{DataType, SchemaRef};
 use chrono::TimeZone as _;
 use datafusion::datasource::listing::PartitionedFile;
+use datafusion_common::{DataFusionError, Result as DFResult, ScalarValue};
 use object_store::path::Path;
 use object_store::ObjectMeta;
 use vortex::Context;
@@ -19,6 +20,34 @@ impl From<VortexFile> for PartitionedFile {
 }
 
 impl VortexFile {
+    /// Parse the values of the partition columns from the `key=value` directories of the path.
+    pub(crate) fn partition_values(
+        &self,
+        table_partition_cols: &[(String, DataType)],
+    ) -> DFResult<Vec<ScalarValue>> {
+        let parts = self.object_meta.location.parts().collect::<Vec<_>>();
+        let directories = &parts[..parts.len().saturating_sub(1)];
+        table_partition_cols
+            .iter()
+            .map(|(name, data_type)| {
+                let value = directories
+                    .iter()
+                    .find_map(|part| {
+                        part.as_ref()
+                            .split_once('=')
+                            .and_then(|(key, value)| (key == name).then_some(value))
+                    })
+                    .ok_or_else(|| {
+                        DataFusionError::Execution(format!(
+                            "Missing partition {name} in path {}",
+                            self.object_meta.location
+                        ))
+                    })?;
+                ScalarValue::try_from_string(value.to_string(), data_type)
+            })
+            .collect()
+    }
+
     pub fn new(path: impl Into<String>, size: u64) -> Self {
         Self {
             object_meta: ObjectMeta {
@@ -37,6 +66,7 @@ pub struct VortexTableOptions {
     pub(crate) data_files: Vec<VortexFile>,
     pub(crate) schema: Option<SchemaRef>,
     pub(crate) ctx: Arc<Context>,
+    pub(crate) table_partition_cols: Vec<(String, DataType)>,
 }
 
 impl VortexTableOptions {
@@ -45,6 +75,18 @@ impl VortexTableOptions {
             data_files,
             schema: Some(schema),
             ctx,
+            table_partition_cols: Vec::new(),
         }
     }
+
+    /// Columns whose values are parsed from hive style `key=value` directories of the file paths.
+    ///
+    /// They follow the columns of the files in the table schema.
+    pub fn with_table_partition_cols(
+        mut self,
+        table_partition_cols: Vec<(String, DataType)>,
+    ) -> Self {
+        self.table_partition_cols = table_partition_cols;
+        self
+    }
 }
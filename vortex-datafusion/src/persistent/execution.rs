@@ -2,7 +2,7 @@ use std::fmt;
 use std::sync::Arc;
 
 use datafusion::datasource::physical_plan::{FileScanConfig, FileStream};
-use datafusion_common::{project_schema, Result as DFResult};
+use datafusion_common::{Result as DFResult, Statistics};
 use datafusion_execution::{SendableRecordBatchStream, TaskContext};
 use datafusion_physical_expr::{EquivalenceProperties, Partitioning, PhysicalExpr};
 use datafusion_physical_plan::metrics::ExecutionPlanMetricsSet;
@@ -26,14 +26,13 @@ impl VortexExec {
     pub fn try_new(
         file_scan_config: FileScanConfig,
         metrics: ExecutionPlanMetricsSet,
-        projection: Option<&Vec<usize>>,
         predicate: Option<Arc<dyn PhysicalExpr>>,
         ctx: Arc<Context>,
     ) -> DFResult<Self> {
-        let projected_schema = project_schema(&file_scan_config.file_schema, projection)?;
+        let (projected_schema, ..) = file_scan_config.project();
         let plan_properties = PlanProperties::new(
             EquivalenceProperties::new(projected_schema),
-            Partitioning::UnknownPartitioning(1),
+            Partitioning::UnknownPartitioning(file_scan_config.file_groups.len()),
             ExecutionMode::Bounded,
         );
 
@@ -83,6 +82,11 @@ impl ExecutionPlan for VortexExec {
         Ok(self)
     }
 
+    fn statistics(&self) -> DFResult<Statistics> {
+        let (_, statistics, _) = self.file_scan_config.project();
+        Ok(statistics)
+    }
+
     fn execute(
         &self,
         partition: usize,
@@ -94,13 +98,23 @@ impl ExecutionPlan for VortexExec {
 
         let arrow_schema = self.file_scan_config.file_schema.clone();
 
+        // Partition columns are appended by the FileStream, only file columns are read.
+        let projection = self.file_scan_config.projection.as_ref().map(|projection| {
+            projection
+                .iter()
+                .copied()
+                .filter(|&i| i < arrow_schema.fields().len())
+                .collect()
+        });
+
         let opener = VortexFileOpener {
             ctx: self.ctx.clone(),
             object_store,
-            projection: self.file_scan_config.projection.clone(),
+            projection,
             batch_size: None,
             predicate: self.predicate.clone(),
             arrow_schema,
+            limit: self.file_scan_config.limit,
         };
         let stream = FileStream::new(&self.file_scan_config, partition, opener, &self.metrics)?;
 
@@ -0,0 +1,483 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow_schema::{Schema, SchemaRef};
+use async_trait::async_trait;
+use datafusion::datasource::file_format::file_compression_type::FileCompressionType;
+use datafusion::datasource::file_format::{FileFormat, FileFormatFactory};
+use datafusion::datasource::physical_plan::{FileScanConfig, FileSinkConfig};
+use datafusion::execution::SessionState;
+use datafusion_common::parsers::CompressionTypeVariant;
+use datafusion_common::stats::Precision;
+use datafusion_common::{not_impl_err, DataFusionError, GetExt, Result as DFResult, Statistics};
+use datafusion_expr::Operator;
+use datafusion_physical_expr::expressions::BinaryExpr;
+use datafusion_physical_expr::utils::{collect_columns, split_conjunction};
+use datafusion_physical_expr::{LexRequirement, PhysicalExpr};
+use datafusion_physical_plan::insert::DataSinkExec;
+use datafusion_physical_plan::metrics::ExecutionPlanMetricsSet;
+use datafusion_physical_plan::ExecutionPlan;
+use object_store::{ObjectMeta, ObjectStore};
+use vortex::arrow::infer_schema;
+use vortex::Context;
+use vortex_expr::datafusion::convert_expr_to_vortex;
+use vortex_sampling_compressor::{SamplingCompressor, ALL_COMPRESSORS_CONTEXT};
+use vortex_serde::io::ObjectStoreReadAt;
+use vortex_serde::layouts::{
+    read_column_statistics, LayoutContext, LayoutDescriptor, LayoutDescriptorReader,
+    LayoutDeserializer,
+};
+
+use crate::persistent::execution::VortexExec;
+use crate::persistent::sink::VortexSink;
+use crate::statistics::file_df_stats;
+
+/// The extension of Vortex files, without the leading dot.
+pub const VORTEX_EXTENSION: &str = "vortex";
+
+/// Creates [`VortexFormat`]s, registering it with a session enables `STORED AS VORTEX`.
+#[derive(Debug)]
+pub struct VortexFormatFactory {
+    context: Arc<Context>,
+    compressor: SamplingCompressor<'static>,
+}
+
+impl Default for VortexFormatFactory {
+    fn default() -> Self {
+        Self::new(ALL_COMPRESSORS_CONTEXT.clone())
+    }
+}
+
+impl VortexFormatFactory {
+    pub fn new(context: Arc<Context>) -> Self {
+        Self {
+            context,
+            compressor: SamplingCompressor::default(),
+        }
+    }
+
+    /// Set the compressor applied to record batches written by `COPY TO` and `INSERT INTO`.
+    pub fn with_compressor(mut self, compressor: SamplingCompressor<'static>) -> Self {
+        self.compressor = compressor;
+        self
+    }
+}
+
+impl GetExt for VortexFormatFactory {
+    fn get_ext(&self) -> String {
+        VORTEX_EXTENSION.to_string()
+    }
+}
+
+impl FileFormatFactory for VortexFormatFactory {
+    #[allow(clippy::disallowed_types)]
+    fn create(
+        &self,
+        _state: &SessionState,
+        _format_options: &std::collections::HashMap<String, String>,
+    ) -> DFResult<Arc<dyn FileFormat>> {
+        Ok(self.default())
+    }
+
+    fn default(&self) -> Arc<dyn FileFormat> {
+        Arc::new(VortexFormat::new(self.context.clone()).with_compressor(self.compressor.clone()))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// DataFusion [`FileFormat`] for Vortex files, used by `ListingTable` to scan directories of
+/// Vortex files with a schema inferred from their footers, and to write query results into new
+/// Vortex files.
+#[derive(Debug)]
+pub struct VortexFormat {
+    context: Arc<Context>,
+    compressor: SamplingCompressor<'static>,
+}
+
+impl Default for VortexFormat {
+    fn default() -> Self {
+        Self::new(ALL_COMPRESSORS_CONTEXT.clone())
+    }
+}
+
+impl VortexFormat {
+    pub fn new(context: Arc<Context>) -> Self {
+        Self {
+            context,
+            compressor: SamplingCompressor::default(),
+        }
+    }
+
+    /// Set the compressor applied to record batches before they are written.
+    ///
+    /// The context used to read the files back must contain the encodings it produces.
+    pub fn with_compressor(mut self, compressor: SamplingCompressor<'static>) -> Self {
+        self.compressor = compressor;
+        self
+    }
+}
+
+async fn read_footer(
+    context: &Arc<Context>,
+    store: &Arc<dyn ObjectStore>,
+    object: &ObjectMeta,
+) -> DFResult<LayoutDescriptor> {
+    let read_at = ObjectStoreReadAt::new(store.clone(), object.location.clone());
+    Ok(LayoutDescriptorReader::new(LayoutDeserializer::new(
+        context.clone(),
+        Arc::new(LayoutContext::default()),
+    ))
+    .read_footer(&read_at, object.size as u64)
+    .await?)
+}
+
+/// Read the row count and column statistics of a Vortex file from its footer and chunk metadata.
+pub(crate) async fn file_statistics(
+    context: &Arc<Context>,
+    store: &Arc<dyn ObjectStore>,
+    object: &ObjectMeta,
+    table_schema: &Schema,
+) -> DFResult<Statistics> {
+    let footer = read_footer(context, store, object).await?;
+    let read_at = ObjectStoreReadAt::new(store.clone(), object.location.clone());
+    let column_stats = read_column_statistics(read_at, &footer).await?;
+
+    let mut statistics = file_df_stats(
+        footer.row_count()?,
+        &footer.dtype()?,
+        &column_stats,
+        table_schema,
+    );
+    statistics.total_byte_size = Precision::Inexact(object.size);
+    Ok(statistics)
+}
+
+#[async_trait]
+impl FileFormat for VortexFormat {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_ext(&self) -> String {
+        VORTEX_EXTENSION.to_string()
+    }
+
+    fn get_ext_with_compression(
+        &self,
+        file_compression_type: &FileCompressionType,
+    ) -> DFResult<String> {
+        match file_compression_type.get_variant() {
+            CompressionTypeVariant::UNCOMPRESSED => Ok(self.get_ext()),
+            _ => Err(DataFusionError::Internal(
+                "Vortex files cannot be externally compressed".to_string(),
+            )),
+        }
+    }
+
+    async fn infer_schema(
+        &self,
+        _state: &SessionState,
+        store: &Arc<dyn ObjectStore>,
+        objects: &[ObjectMeta],
+    ) -> DFResult<SchemaRef> {
+        let mut schemas = Vec::with_capacity(objects.len());
+        for object in objects {
+            let footer = read_footer(&self.context, store, object).await?;
+            schemas.push(infer_schema(&footer.dtype()?)?);
+        }
+
+        Ok(Arc::new(Schema::try_merge(schemas)?))
+    }
+
+    async fn infer_stats(
+        &self,
+        _state: &SessionState,
+        store: &Arc<dyn ObjectStore>,
+        table_schema: SchemaRef,
+        object: &ObjectMeta,
+    ) -> DFResult<Statistics> {
+        file_statistics(&self.context, store, object, &table_schema).await
+    }
+
+    async fn create_physical_plan(
+        &self,
+        _state: &SessionState,
+        file_scan_config: FileScanConfig,
+        filters: Option<&Arc<dyn PhysicalExpr>>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        if file_scan_config
+            .file_groups
+            .iter()
+            .flatten()
+            .any(|f| f.range.is_some())
+        {
+            return not_impl_err!("Vortex files cannot be scanned in byte ranges");
+        }
+
+        let predicate = filters.and_then(|filters| {
+            file_predicate(filters, file_scan_config.file_schema.fields().len())
+        });
+
+        Ok(VortexExec::try_new(
+            file_scan_config,
+            ExecutionPlanMetricsSet::new(),
+            predicate,
+            self.context.clone(),
+        )?
+        .into_arc())
+    }
+
+    async fn create_writer_physical_plan(
+        &self,
+        input: Arc<dyn ExecutionPlan>,
+        _state: &SessionState,
+        conf: FileSinkConfig,
+        order_requirements: Option<LexRequirement>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        let sink_schema = conf.output_schema().clone();
+        let sink = Arc::new(VortexSink::new(conf, self.compressor.clone()));
+        Ok(Arc::new(DataSinkExec::new(
+            input,
+            sink,
+            sink_schema,
+            order_requirements,
+        )))
+    }
+}
+
+/// The conjunction of all filters that can be evaluated against the columns of a file.
+///
+/// Filters pushed down by `ListingTable` are inexact, anything left out here is still applied by
+/// DataFusion after the scan.
+fn file_predicate(
+    filters: &Arc<dyn PhysicalExpr>,
+    file_columns: usize,
+) -> Option<Arc<dyn PhysicalExpr>> {
+    split_conjunction(filters)
+        .into_iter()
+        .filter(|expr| {
+            collect_columns(expr)
+                .iter()
+                .all(|column| column.index() < file_columns)
+                && convert_expr_to_vortex(Arc::clone(expr)).is_ok()
+        })
+        .cloned()
+        .reduce(|left, right| Arc::new(BinaryExpr::new(left, Operator::And, right)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt32Type;
+    use datafusion::datasource::listing::{
+        ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl,
+    };
+    use datafusion::datasource::provider::DefaultTableFactory;
+    use datafusion::execution::SessionStateBuilder;
+    use datafusion::prelude::SessionContext;
+    use datafusion_common::GetExt;
+    use tempfile::tempdir;
+    use tokio::fs::OpenOptions;
+    use vortex::array::{ChunkedArray, PrimitiveArray, StructArray, VarBinArray};
+    use vortex::IntoArray;
+    use vortex_serde::layouts::LayoutWriter;
+
+    use crate::persistent::format::{VortexFormat, VortexFormatFactory};
+
+    async fn write_file(path: std::path::PathBuf, offset: u32) {
+        let numbers = ChunkedArray::from_iter([
+            PrimitiveArray::from(vec![offset, offset + 1]).into_array(),
+            PrimitiveArray::from(vec![offset + 2, offset + 3]).into_array(),
+        ])
+        .into_array();
+        let strings = VarBinArray::from(vec!["a", "b", "c", "d"]).into_array();
+        let st = StructArray::from_fields(&[("numbers", numbers), ("strings", strings)]).unwrap();
+
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .await
+            .unwrap();
+        LayoutWriter::new(file)
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    fn vortex_session() -> SessionContext {
+        let mut state_builder = SessionStateBuilder::new().with_default_features();
+        if let Some(table_factories) = state_builder.table_factories() {
+            table_factories.insert("VORTEX".to_string(), Arc::new(DefaultTableFactory::new()));
+        }
+        if let Some(file_formats) = state_builder.file_formats() {
+            file_formats.push(Arc::new(VortexFormatFactory::default()));
+        }
+        SessionContext::new_with_state(state_builder.build())
+    }
+
+    async fn query_numbers(ctx: &SessionContext, sql: &str) -> Vec<u32> {
+        ctx.sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<UInt32Type>().values().to_vec())
+            .collect()
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn listing_table() {
+        let dir = tempdir().unwrap();
+        write_file(dir.path().join("a.vortex"), 0).await;
+        write_file(dir.path().join("b.vortex"), 10).await;
+
+        let ctx = SessionContext::new();
+        let table_url = ListingTableUrl::parse(dir.path().to_str().unwrap()).unwrap();
+        let options = ListingOptions::new(Arc::new(VortexFormat::default()))
+            .with_file_extension(VortexFormatFactory::default().get_ext());
+        let config = ListingTableConfig::new(table_url)
+            .with_listing_options(options)
+            .infer_schema(&ctx.state())
+            .await
+            .unwrap();
+        ctx.register_table("tbl", Arc::new(ListingTable::try_new(config).unwrap()))
+            .unwrap();
+
+        let batches = ctx
+            .sql("SELECT numbers FROM tbl WHERE numbers > 1 AND strings != 'd' ORDER BY numbers")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let numbers = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<UInt32Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, vec![2, 10, 11, 12]);
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn create_external_table() {
+        let dir = tempdir().unwrap();
+        write_file(dir.path().join("a.vortex"), 0).await;
+
+        let ctx = vortex_session();
+
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE tbl STORED AS VORTEX LOCATION '{}/'",
+            dir.path().to_str().unwrap()
+        ))
+        .await
+        .unwrap();
+
+        let batches = ctx
+            .sql("SELECT COUNT(*) FROM tbl")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            batches[0]
+                .column(0)
+                .as_primitive::<arrow_array::types::Int64Type>()
+                .value(0),
+            4
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn copy_to_file() {
+        let dir = tempdir().unwrap();
+        write_file(dir.path().join("a.vortex"), 0).await;
+        let ctx = vortex_session();
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE src STORED AS VORTEX LOCATION '{}/'",
+            dir.path().to_str().unwrap()
+        ))
+        .await
+        .unwrap();
+
+        let out = tempdir().unwrap();
+        let out_path = out.path().join("out.vortex");
+        let written = ctx
+            .sql(&format!(
+                "COPY (SELECT numbers + numbers AS doubled, strings FROM src WHERE numbers > 0) TO '{}' STORED AS VORTEX",
+                out_path.to_str().unwrap()
+            ))
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            written[0]
+                .column(0)
+                .as_primitive::<arrow_array::types::UInt64Type>()
+                .value(0),
+            3
+        );
+
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE copied STORED AS VORTEX LOCATION '{}'",
+            out_path.to_str().unwrap()
+        ))
+        .await
+        .unwrap();
+        assert_eq!(
+            query_numbers(
+                &ctx,
+                "SELECT doubled FROM copied WHERE strings != 'c' ORDER BY doubled"
+            )
+            .await,
+            vec![2, 6]
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn insert_into_listing_table() {
+        let dir = tempdir().unwrap();
+        write_file(dir.path().join("a.vortex"), 0).await;
+        let ctx = vortex_session();
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE tbl STORED AS VORTEX LOCATION '{}/'",
+            dir.path().to_str().unwrap()
+        ))
+        .await
+        .unwrap();
+
+        ctx.sql("INSERT INTO tbl VALUES (20, 'e'), (21, 'f')")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+        assert_eq!(
+            query_numbers(
+                &ctx,
+                "SELECT numbers FROM tbl WHERE strings > 'b' ORDER BY numbers"
+            )
+            .await,
+            vec![2, 3, 20, 21]
+        );
+    }
+}
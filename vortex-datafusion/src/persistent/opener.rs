@@ -1,13 +1,14 @@
 use std::sync::Arc;
 
-use arrow_array::RecordBatch;
-use arrow_schema::SchemaRef;
+use arrow_array::{RecordBatch, RecordBatchOptions};
+use arrow_schema::{Schema, SchemaRef};
 use datafusion::datasource::physical_plan::{FileMeta, FileOpenFuture, FileOpener};
 use datafusion_common::Result as DFResult;
 use datafusion_physical_expr::PhysicalExpr;
 use futures::{FutureExt as _, StreamExt, TryStreamExt};
 use object_store::ObjectStore;
 use vortex::Context;
+use vortex_error::VortexError;
 use vortex_expr::datafusion::convert_expr_to_vortex;
 use vortex_serde::io::ObjectStoreReadAt;
 use vortex_serde::layouts::{
@@ -21,6 +22,7 @@ pub struct VortexFileOpener {
     pub projection: Option<Vec<usize>>,
     pub predicate: Option<Arc<dyn PhysicalExpr>>,
     pub arrow_schema: SchemaRef,
+    pub limit: Option<usize>,
 }
 
 impl FileOpener for VortexFileOpener {
@@ -31,7 +33,8 @@ impl FileOpener for VortexFileOpener {
         let mut builder = LayoutReaderBuilder::new(
             read_at,
             LayoutDeserializer::new(self.ctx.clone(), Arc::new(LayoutContext::default())),
-        );
+        )
+        .with_size(file_meta.object_meta.size as u64);
 
         if let Some(batch_size) = self.batch_size {
             builder = builder.with_batch_size(batch_size);
@@ -48,17 +51,43 @@ impl FileOpener for VortexFileOpener {
             builder = builder.with_row_filter(row_filter);
         }
 
-        if let Some(projection) = self.projection.as_ref() {
+        // Layouts can't produce batches without any columns, so read the first column to learn
+        // the number of rows and drop it afterwards.
+        let count_only = self
+            .projection
+            .as_ref()
+            .is_some_and(|projection| projection.is_empty());
+
+        if count_only {
+            builder = builder.with_projection(Projection::new([0]));
+        } else if let Some(projection) = self.projection.as_ref() {
             builder = builder.with_projection(Projection::new(projection));
         }
 
-        Ok(async {
+        if let Some(limit) = self.limit {
+            builder = builder.with_limit(limit);
+        }
+
+        Ok(async move {
             Ok(Box::pin(
                 builder
                     .build()
                     .await?
                     .map_ok(RecordBatch::try_from)
                     .map(|r| r.and_then(|inner| inner))
+                    .map_ok(move |batch| {
+                        if count_only {
+                            RecordBatch::try_new_with_options(
+                                Arc::new(Schema::empty()),
+                                vec![],
+                                &RecordBatchOptions::new().with_row_count(Some(batch.num_rows())),
+                            )
+                            .map_err(VortexError::from)
+                        } else {
+                            Ok(batch)
+                        }
+                    })
+                    .map(|r| r.and_then(|inner| inner))
                     .map_err(|e| e.into()),
             ) as _)
         }
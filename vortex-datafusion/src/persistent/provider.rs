@@ -1,13 +1,16 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use arrow_schema::SchemaRef;
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, RecordBatch, RecordBatchOptions};
+use arrow_schema::{Field, Schema, SchemaRef};
 use async_trait::async_trait;
 use datafusion::catalog::Session;
+use datafusion::datasource::listing::PartitionedFile;
 use datafusion::datasource::physical_plan::FileScanConfig;
 use datafusion::datasource::TableProvider;
 use datafusion_common::{
-    project_schema, DataFusionError, Result as DFResult, Statistics, ToDFSchema,
+    project_schema, DataFusionError, Result as DFResult, ScalarValue, Statistics, ToDFSchema,
 };
 use datafusion_execution::object_store::ObjectStoreUrl;
 use datafusion_expr::utils::conjunction;
@@ -16,28 +19,127 @@ use datafusion_physical_plan::empty::EmptyExec;
 use datafusion_physical_plan::metrics::ExecutionPlanMetricsSet;
 use datafusion_physical_plan::ExecutionPlan;
 use itertools::Itertools;
+use object_store::ObjectStore;
 
 use super::config::VortexTableOptions;
 use crate::can_be_pushed_down;
 use crate::persistent::execution::VortexExec;
+use crate::persistent::format::file_statistics;
+use crate::statistics::merge_df_stats;
 
 pub struct VortexFileTableProvider {
     schema_ref: SchemaRef,
+    file_schema: SchemaRef,
     object_store_url: ObjectStoreUrl,
     config: VortexTableOptions,
+    statistics: Option<Statistics>,
 }
 
 impl VortexFileTableProvider {
     pub fn try_new(object_store_url: ObjectStoreUrl, config: VortexTableOptions) -> DFResult<Self> {
+        let file_schema = config
+            .schema
+            .clone()
+            .ok_or_else(|| DataFusionError::Configuration("Missing schema".to_string()))?;
+        let schema_ref = Arc::new(Schema::new(
+            file_schema
+                .fields()
+                .iter()
+                .cloned()
+                .chain(partition_fields(&config).into_iter().map(Arc::new))
+                .collect::<Vec<_>>(),
+        ));
         Ok(Self {
-            schema_ref: config
-                .schema
-                .clone()
-                .ok_or_else(|| DataFusionError::Configuration("Missing schema".to_string()))?,
+            schema_ref,
+            file_schema,
             object_store_url,
             config,
+            statistics: None,
         })
     }
+
+    /// Read the row count and column statistics of all data files from their footers, so that
+    /// DataFusion can use them for planning.
+    pub async fn load_statistics(mut self, object_store: &Arc<dyn ObjectStore>) -> DFResult<Self> {
+        let mut statistics: Option<Statistics> = None;
+        for file in &self.config.data_files {
+            let file_statistics = file_statistics(
+                &self.config.ctx,
+                object_store,
+                &file.object_meta,
+                &self.schema_ref,
+            )
+            .await?;
+            statistics = Some(match statistics {
+                None => file_statistics,
+                Some(s) => merge_df_stats(s, &file_statistics),
+            });
+        }
+        self.statistics = statistics;
+        Ok(self)
+    }
+}
+
+impl VortexFileTableProvider {
+    fn references_partition(&self, expr: &Expr) -> bool {
+        expr.column_refs().iter().any(|column| {
+            self.config
+                .table_partition_cols
+                .iter()
+                .any(|(name, _)| *name == column.name)
+        })
+    }
+
+    /// Whether the filter only references partition columns
+    fn is_partition_filter(&self, expr: &Expr) -> bool {
+        let columns = expr.column_refs();
+        !columns.is_empty()
+            && columns.iter().all(|column| {
+                self.config
+                    .table_partition_cols
+                    .iter()
+                    .any(|(name, _)| *name == column.name)
+            })
+    }
+
+    /// Keep the files whose partition values pass the predicate
+    fn prune_partitions(
+        &self,
+        state: &dyn Session,
+        files: Vec<PartitionedFile>,
+        predicate: Expr,
+    ) -> DFResult<Vec<PartitionedFile>> {
+        let partition_schema = Arc::new(Schema::new(partition_fields(&self.config)));
+        let columns = (0..partition_schema.fields().len())
+            .map(|idx| {
+                ScalarValue::iter_to_array(files.iter().map(|f| f.partition_values[idx].clone()))
+            })
+            .collect::<DFResult<Vec<_>>>()?;
+        let partitions = RecordBatch::try_new_with_options(
+            partition_schema.clone(),
+            columns,
+            &RecordBatchOptions::new().with_row_count(Some(files.len())),
+        )?;
+
+        let predicate = state.create_physical_expr(predicate, &partition_schema.to_dfschema()?)?;
+        let selected = predicate.evaluate(&partitions)?.into_array(files.len())?;
+        let selected = selected.as_boolean();
+        Ok(files
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| selected.is_valid(*idx) && selected.value(*idx))
+            .map(|(_, file)| file)
+            .collect())
+    }
+}
+
+/// Schema fields of the partition columns, partition values are never null
+fn partition_fields(config: &VortexTableOptions) -> Vec<Field> {
+    config
+        .table_partition_cols
+        .iter()
+        .map(|(name, data_type)| Field::new(name, data_type.clone(), false))
+        .collect()
 }
 
 #[async_trait]
@@ -59,37 +161,62 @@ impl TableProvider for VortexFileTableProvider {
         state: &dyn Session,
         projection: Option<&Vec<usize>>,
         filters: &[Expr],
-        _limit: Option<usize>,
+        limit: Option<usize>,
     ) -> DFResult<Arc<dyn ExecutionPlan>> {
         if self.config.data_files.is_empty() {
             let projected_schema = project_schema(&self.schema(), projection)?;
             return Ok(Arc::new(EmptyExec::new(projected_schema)));
         }
 
+        // Filters on partition columns are answered by skipping files, before their footers are read
+        let (partition_filters, file_filters): (Vec<_>, Vec<_>) = filters
+            .iter()
+            .cloned()
+            .partition(|expr| self.is_partition_filter(expr));
+        let mut files = self
+            .config
+            .data_files
+            .iter()
+            .map(|file| {
+                let mut partitioned_file = PartitionedFile::from(file.clone());
+                partitioned_file.partition_values =
+                    file.partition_values(&self.config.table_partition_cols)?;
+                Ok(partitioned_file)
+            })
+            .collect::<DFResult<Vec<_>>>()?;
+        let file_count = files.len();
+        if let Some(partition_predicate) = conjunction(partition_filters) {
+            files = self.prune_partitions(state, files, partition_predicate)?;
+        }
+        if files.is_empty() {
+            let projected_schema = project_schema(&self.schema(), projection)?;
+            return Ok(Arc::new(EmptyExec::new(projected_schema)));
+        }
+
         let df_schema = self.schema().to_dfschema()?;
-        let predicate = conjunction(filters.to_vec());
+        let predicate = conjunction(file_filters);
         let predicate = predicate
             .map(|predicate| state.create_physical_expr(predicate, &df_schema))
             .transpose()?;
 
         let metrics = ExecutionPlanMetricsSet::new();
 
-        // TODO: Point at some files and/or ranges
-        let file_scan_config = FileScanConfig::new(self.object_store_url.clone(), self.schema())
-            .with_file_group(
-                self.config
-                    .data_files
-                    .iter()
-                    .cloned()
-                    .map(Into::into)
-                    .collect(),
-            )
-            .with_projection(projection.cloned());
+        let mut file_scan_config =
+            FileScanConfig::new(self.object_store_url.clone(), self.file_schema.clone())
+                .with_file_group(files.clone())
+                .with_table_partition_cols(partition_fields(&self.config))
+                .with_projection(projection.cloned())
+                .with_limit(limit);
+        // The statistics describe all files, they don't apply once some are skipped
+        if files.len() == file_count {
+            if let Some(statistics) = self.statistics.clone() {
+                file_scan_config = file_scan_config.with_statistics(statistics);
+            }
+        }
 
         let exec = VortexExec::try_new(
             file_scan_config,
             metrics,
-            projection,
             predicate,
             self.config.ctx.clone(),
         )?
@@ -105,7 +232,11 @@ impl TableProvider for VortexFileTableProvider {
         filters
             .iter()
             .map(|expr| {
-                if can_be_pushed_down(expr, self.schema().as_ref()) {
+                if self.is_partition_filter(expr) {
+                    Ok(TableProviderFilterPushDown::Exact)
+                } else if self.references_partition(expr) {
+                    Ok(TableProviderFilterPushDown::Unsupported)
+                } else if can_be_pushed_down(expr, self.schema().as_ref()) {
                     Ok(TableProviderFilterPushDown::Exact)
                 } else {
                     Ok(TableProviderFilterPushDown::Unsupported)
@@ -115,6 +246,210 @@ impl TableProvider for VortexFileTableProvider {
     }
 
     fn statistics(&self) -> Option<Statistics> {
-        None
+        self.statistics.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_schema::{DataType, Field, Schema};
+    use datafusion::assert_batches_eq;
+    use datafusion::prelude::SessionContext;
+    use datafusion_common::stats::Precision;
+    use datafusion_common::ScalarValue;
+    use datafusion_execution::object_store::ObjectStoreUrl;
+    use datafusion_physical_plan::displayable;
+    use object_store::local::LocalFileSystem;
+    use object_store::path::Path;
+    use object_store::ObjectStore;
+    use tempfile::tempdir;
+    use vortex::array::{ChunkedArray, PrimitiveArray, StructArray};
+    use vortex::validity::Validity;
+    use vortex::{Context, IntoArray};
+    use vortex_serde::layouts::LayoutWriter;
+
+    use crate::persistent::config::{VortexFile, VortexTableOptions};
+    use crate::persistent::provider::VortexFileTableProvider;
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn statistics_from_footer() {
+        let numbers = ChunkedArray::from_iter([
+            PrimitiveArray::from_nullable_vec(vec![Some(5i64), None, Some(-3)]).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(42i64), None]).into_array(),
+        ])
+        .into_array();
+        let st = StructArray::try_new(
+            ["numbers".into()].into(),
+            vec![numbers],
+            5,
+            Validity::NonNullable,
+        )
+        .unwrap();
+
+        let dir = tempdir().unwrap();
+        let filepath = dir.path().join("a.vortex");
+        let file = tokio::fs::File::create(&filepath).await.unwrap();
+        LayoutWriter::new(file)
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+        let file_size = tokio::fs::metadata(&filepath).await.unwrap().len();
+
+        let object_store: Arc<dyn ObjectStore> = Arc::new(LocalFileSystem::new());
+        let provider = VortexFileTableProvider::try_new(
+            ObjectStoreUrl::local_filesystem(),
+            VortexTableOptions::new(
+                Arc::new(Schema::new(vec![Field::new(
+                    "numbers",
+                    DataType::Int64,
+                    true,
+                )])),
+                vec![VortexFile::new(
+                    Path::from_filesystem_path(&filepath).unwrap(),
+                    file_size,
+                )],
+                Arc::new(Context::default()),
+            ),
+        )
+        .unwrap()
+        .load_statistics(&object_store)
+        .await
+        .unwrap();
+
+        let statistics = datafusion::datasource::TableProvider::statistics(&provider).unwrap();
+        assert_eq!(statistics.num_rows, Precision::Exact(5));
+        let numbers = &statistics.column_statistics[0];
+        assert_eq!(numbers.null_count, Precision::Exact(2));
+        assert_eq!(
+            numbers.min_value,
+            Precision::Exact(ScalarValue::Int64(Some(-3)))
+        );
+        assert_eq!(
+            numbers.max_value,
+            Precision::Exact(ScalarValue::Int64(Some(42)))
+        );
+
+        // COUNT(*) is answered from the statistics without scanning the file
+        let ctx = SessionContext::new();
+        ctx.register_table("tbl", Arc::new(provider)).unwrap();
+        let plan = ctx
+            .sql("SELECT COUNT(*) FROM tbl")
+            .await
+            .unwrap()
+            .create_physical_plan()
+            .await
+            .unwrap();
+        let plan = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(!plan.contains("VortexExec"), "{plan}");
+    }
+
+    async fn write_numbers(path: std::path::PathBuf, numbers: Vec<i64>) -> VortexFile {
+        tokio::fs::create_dir_all(path.parent().unwrap())
+            .await
+            .unwrap();
+        let st =
+            StructArray::from_fields(&[("numbers", PrimitiveArray::from(numbers).into_array())])
+                .unwrap();
+        let file = tokio::fs::File::create(&path).await.unwrap();
+        LayoutWriter::new(file)
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+        let size = tokio::fs::metadata(&path).await.unwrap().len();
+        VortexFile::new(Path::from_filesystem_path(&path).unwrap(), size)
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn hive_partition_columns() {
+        let dir = tempdir().unwrap();
+        let mut files = vec![
+            write_numbers(
+                dir.path().join("date=2024-10-01/region=eu/a.vortex"),
+                vec![1, 2],
+            )
+            .await,
+            write_numbers(
+                dir.path().join("date=2024-10-01/region=us/a.vortex"),
+                vec![3],
+            )
+            .await,
+            write_numbers(
+                dir.path().join("date=2024-10-02/region=eu/a.vortex"),
+                vec![4, 5],
+            )
+            .await,
+        ];
+        // Scanning fails if this file isn't skipped by its partition
+        let corrupt = dir.path().join("date=2024-10-02/region=us/a.vortex");
+        tokio::fs::create_dir_all(corrupt.parent().unwrap())
+            .await
+            .unwrap();
+        tokio::fs::write(&corrupt, [0u8; 16]).await.unwrap();
+        files.push(VortexFile::new(
+            Path::from_filesystem_path(&corrupt).unwrap(),
+            16,
+        ));
+
+        let provider = VortexFileTableProvider::try_new(
+            ObjectStoreUrl::local_filesystem(),
+            VortexTableOptions::new(
+                Arc::new(Schema::new(vec![Field::new(
+                    "numbers",
+                    DataType::Int64,
+                    false,
+                )])),
+                files,
+                Arc::new(Context::default()),
+            )
+            .with_table_partition_cols(vec![
+                ("date".to_string(), DataType::Date32),
+                ("region".to_string(), DataType::Utf8),
+            ]),
+        )
+        .unwrap();
+        let ctx = SessionContext::new();
+        ctx.register_table("tbl", Arc::new(provider)).unwrap();
+
+        let batches = ctx
+            .sql(
+                "SELECT numbers, CAST(date AS VARCHAR) AS date, region FROM tbl \
+                 WHERE region = 'eu' OR date = DATE '2024-10-01' ORDER BY numbers",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let expected = [
+            "+---------+------------+--------+",
+            "| numbers | date       | region |",
+            "+---------+------------+--------+",
+            "| 1       | 2024-10-01 | eu     |",
+            "| 2       | 2024-10-01 | eu     |",
+            "| 3       | 2024-10-01 | us     |",
+            "| 4       | 2024-10-02 | eu     |",
+            "| 5       | 2024-10-02 | eu     |",
+            "+---------+------------+--------+",
+        ];
+        assert_batches_eq!(expected, &batches);
+
+        let batches = ctx
+            .sql("SELECT COUNT(*) AS n FROM tbl WHERE date = DATE '2024-10-01' AND numbers > 2")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_batches_eq!(["+---+", "| n |", "+---+", "| 1 |", "+---+"], &batches);
     }
 }
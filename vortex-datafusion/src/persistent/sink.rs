@@ -0,0 +1,212 @@
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::datasource::physical_plan::FileSinkConfig;
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion_common::{internal_err, not_impl_err, Result as DFResult};
+use datafusion_physical_plan::insert::DataSink;
+use datafusion_physical_plan::metrics::MetricsSet;
+use datafusion_physical_plan::{DisplayAs, DisplayFormatType};
+use futures::{FutureExt, StreamExt, TryStreamExt};
+use log::warn;
+use object_store::path::Path;
+use object_store::WriteMultipart;
+use uuid::Uuid;
+use vortex::arrow::FromArrowType;
+use vortex::stream::ArrayStreamAdapter;
+use vortex::Array;
+use vortex_dtype::DType;
+use vortex_error::{vortex_err, VortexError, VortexResult};
+use vortex_sampling_compressor::SamplingCompressor;
+use vortex_serde::io::{ObjectStoreWriter, VortexWrite};
+use vortex_serde::layouts::LayoutWriter;
+
+use crate::persistent::format::VORTEX_EXTENSION;
+
+/// [`DataSink`] writing DataFusion record batches into a single Vortex file, backing `COPY TO` and
+/// `INSERT INTO` on Vortex listing tables.
+///
+/// If the table path is a directory the file is given a random name inside it, otherwise the path
+/// is used as the file name.
+pub struct VortexSink {
+    config: FileSinkConfig,
+    compressor: Arc<SamplingCompressor<'static>>,
+}
+
+impl VortexSink {
+    pub fn new(config: FileSinkConfig, compressor: SamplingCompressor<'static>) -> Self {
+        Self {
+            config,
+            compressor: Arc::new(compressor),
+        }
+    }
+
+    fn output_path(&self) -> DFResult<Path> {
+        let Some(base) = self.config.table_paths.first() else {
+            return internal_err!("Vortex sink requires an output path");
+        };
+        Ok(if base.is_collection() {
+            base.prefix()
+                .child(format!("{}.{VORTEX_EXTENSION}", Uuid::new_v4().simple()))
+        } else {
+            base.prefix().clone()
+        })
+    }
+}
+
+impl fmt::Debug for VortexSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VortexSink")
+            .field("table_paths", &self.config.table_paths)
+            .field("compressor", &self.compressor)
+            .finish()
+    }
+}
+
+impl DisplayAs for VortexSink {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VortexSink: ")?;
+        match self.config.table_paths.first() {
+            Some(path) => write!(f, "path={path}"),
+            None => write!(f, "path=<none>"),
+        }
+    }
+}
+
+#[async_trait]
+impl DataSink for VortexSink {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        None
+    }
+
+    async fn write_all(
+        &self,
+        data: SendableRecordBatchStream,
+        context: &Arc<TaskContext>,
+    ) -> DFResult<u64> {
+        if self.config.overwrite {
+            return not_impl_err!("Overwriting Vortex tables is not supported");
+        }
+        if !self.config.table_partition_cols.is_empty() {
+            return not_impl_err!("Writing partitioned Vortex tables is not supported");
+        }
+
+        let object_store = context
+            .runtime_env()
+            .object_store(&self.config.object_store_url)?;
+        let location = self.output_path()?;
+
+        let dtype = DType::from_arrow(data.schema());
+        let mut row_count = 0;
+        let arrays = data
+            .map_err(VortexError::from)
+            .and_then(|batch| {
+                // Compression is CPU bound, keep it off the async runtime
+                let compressor = self.compressor.clone();
+                tokio::task::spawn_blocking(move || {
+                    let array = Array::try_from(batch)?;
+                    compressor
+                        .compress(&array, None)
+                        .map(|compressed| compressed.into_array())
+                })
+                .map(|compressed| {
+                    compressed
+                        .map_err(|e| vortex_err!("Compression task failed: {e}"))
+                        .and_then(|compressed| compressed)
+                })
+            })
+            .inspect_ok(|array| row_count += array.len() as u64)
+            .boxed();
+
+        // The concrete writer is used so the future stays `Send`, the writer returned by
+        // `ObjectStoreExt::vortex_writer` is opaque.
+        let multipart = WriteMultipart::new(object_store.put_multipart(&location).await?);
+        let mut writer = ObjectStoreWriter::new(multipart);
+        let written: VortexResult<()> = async {
+            LayoutWriter::new(&mut writer)
+                .write_array_columns_stream(ArrayStreamAdapter::new(dtype, arrays))
+                .await?
+                .finalize()
+                .await?;
+            Ok(())
+        }
+        .await;
+        if let Err(e) = written {
+            // Don't leave an incomplete upload behind
+            if let Err(abort_err) = writer.abort().await {
+                warn!("Failed to abort upload of {location}: {abort_err}");
+            }
+            return Err(e.into());
+        }
+        writer.shutdown().await?;
+
+        Ok(row_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{Int32Array, RecordBatch};
+    use arrow_schema::{DataType, Field, Schema};
+    use datafusion::datasource::listing::ListingTableUrl;
+    use datafusion::datasource::physical_plan::FileSinkConfig;
+    use datafusion::prelude::SessionContext;
+    use datafusion_common::DataFusionError;
+    use datafusion_execution::object_store::ObjectStoreUrl;
+    use datafusion_physical_plan::insert::DataSink;
+    use datafusion_physical_plan::stream::RecordBatchStreamAdapter;
+    use futures::stream;
+    use tempfile::tempdir;
+    use vortex_sampling_compressor::SamplingCompressor;
+
+    use crate::persistent::sink::VortexSink;
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn failed_write_aborts_upload() {
+        let dir = tempdir().unwrap();
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let sink = VortexSink::new(
+            FileSinkConfig {
+                object_store_url: ObjectStoreUrl::local_filesystem(),
+                file_groups: vec![],
+                table_paths: vec![ListingTableUrl::parse(format!(
+                    "{}/",
+                    dir.path().to_str().unwrap()
+                ))
+                .unwrap()],
+                output_schema: schema.clone(),
+                table_partition_cols: vec![],
+                overwrite: false,
+                keep_partition_by_columns: false,
+            },
+            SamplingCompressor::default(),
+        );
+
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )
+        .unwrap();
+        let data = Box::pin(RecordBatchStreamAdapter::new(
+            schema,
+            stream::iter(vec![
+                Ok(batch),
+                Err(DataFusionError::Execution("input failed".to_string())),
+            ]),
+        ));
+        let ctx = SessionContext::new();
+        assert!(sink.write_all(data, &ctx.task_ctx()).await.is_err());
+
+        // Neither the file nor the staged upload is left behind
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+}
@@ -17,6 +17,7 @@ use datafusion_physical_plan::{
     DisplayAs, DisplayFormatType, ExecutionMode, ExecutionPlan, PlanProperties,
 };
 use futures::{ready, Stream};
+use itertools::Itertools;
 use lazy_static::lazy_static;
 use pin_project::pin_project;
 use vortex::array::ChunkedArray;
@@ -122,7 +123,14 @@ impl ExecutionPlan for RowSelectorExec {
             .into());
         }
 
-        let filter_projection = self.filter_expr.references().into_iter().cloned().collect();
+        let filter_projection = self
+            .filter_expr
+            .references()
+            .into_iter()
+            .filter_map(|path| path.first())
+            .unique()
+            .cloned()
+            .collect();
         Ok(Box::pin(RowIndicesStream {
             chunked_array: self.chunked_array.clone(),
             chunk_idx: 0,
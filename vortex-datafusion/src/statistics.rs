@@ -1,58 +1,247 @@
+use arrow_schema::{DataType, Schema};
 use datafusion_common::stats::Precision;
 use datafusion_common::{ColumnStatistics, Result as DFResult, ScalarValue, Statistics};
 use itertools::Itertools;
 use vortex::array::ChunkedArray;
-use vortex::stats::{ArrayStatistics, Stat};
-use vortex_error::{vortex_err, VortexExpect, VortexResult};
+use vortex::arrow::infer_data_type;
+use vortex::compute::{count_valid, min_max, MinMaxResult};
+use vortex::stats::{ArrayStatistics, Stat, StatsSet};
+use vortex::{Array, ArrayDType};
+use vortex_dtype::DType;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
 
+/// Statistics of the projected columns of an in-memory table.
+///
+/// Exact min, max and null counts let DataFusion answer `MIN`, `MAX` and `COUNT` aggregates without
+/// scanning the table. DataFusion's column statistics have no sum, so `SUM` is always computed
+/// from the scan.
 pub fn chunked_array_df_stats(array: &ChunkedArray, projection: &[usize]) -> DFResult<Statistics> {
+    let struct_dtype = array
+        .dtype()
+        .as_struct()
+        .ok_or_else(|| vortex_err!("Not a struct array"))?;
     let mut nbytes: usize = 0;
-    let column_statistics = array.as_ref().with_dyn(|a| {
-        let struct_arr = a
-            .as_struct_array()
-            .ok_or_else(|| vortex_err!("Not a struct array"))?;
-        projection
-            .iter()
-            .map(|i| {
-                struct_arr
-                    .field(*i)
-                    .ok_or_else(|| vortex_err!("Projection references unknown field {i}"))
-            })
-            .map_ok(|arr| {
-                nbytes += arr.nbytes();
-                ColumnStatistics {
-                    null_count: arr
-                        .statistics()
-                        .get_as::<u64>(Stat::NullCount)
-                        .map(|n| n as usize)
-                        .map(Precision::Exact)
-                        .unwrap_or(Precision::Absent),
-                    max_value: arr
-                        .statistics()
-                        .get(Stat::Max)
-                        .map(|n| {
-                            ScalarValue::try_from(n)
-                                .vortex_expect("cannot convert scalar to df scalar")
-                        })
-                        .map(Precision::Exact)
-                        .unwrap_or(Precision::Absent),
-                    min_value: arr
-                        .statistics()
-                        .get(Stat::Min)
-                        .map(|n| {
-                            ScalarValue::try_from(n)
-                                .vortex_expect("cannot convert scalar to df scalar")
-                        })
-                        .map(Precision::Exact)
-                        .unwrap_or(Precision::Absent),
-                    distinct_count: Precision::Absent,
-                }
-            })
-            .collect::<VortexResult<Vec<_>>>()
-    })?;
+    let column_statistics = projection
+        .iter()
+        .map(|&i| {
+            let dtype = struct_dtype
+                .dtypes()
+                .get(i)
+                .ok_or_else(|| vortex_err!("Projection references unknown field {i}"))?;
+            // The fields of the chunks keep their statistics between calls, unlike the chunked
+            // field of the whole array
+            let chunks = array
+                .chunks()
+                .map(|chunk| {
+                    chunk
+                        .with_dyn(|a| a.as_struct_array().and_then(|s| s.field(i)))
+                        .ok_or_else(|| vortex_err!("Projection references unknown field {i}"))
+                })
+                .collect::<VortexResult<Vec<_>>>()?;
+            nbytes += chunks.iter().map(|chunk| chunk.nbytes()).sum::<usize>();
+            Ok(chunks_df_stats(&chunks, dtype))
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
     Ok(Statistics {
         num_rows: Precision::Exact(array.len()),
         total_byte_size: Precision::Exact(nbytes),
         column_statistics,
     })
 }
+
+/// Exact statistics of a column from the statistics of its chunks, as DataFusion scalars of the
+/// column's arrow type.
+fn chunks_df_stats(chunks: &[Array], dtype: &DType) -> ColumnStatistics {
+    let null_count = chunks
+        .iter()
+        .map(cached_null_count)
+        .sum::<VortexResult<usize>>()
+        .map(Precision::Exact)
+        .unwrap_or(Precision::Absent);
+
+    let Ok(data_type) = infer_data_type(dtype) else {
+        return ColumnStatistics {
+            null_count,
+            ..ColumnStatistics::new_unknown()
+        };
+    };
+    let df_scalar = |s: Option<Scalar>| {
+        s.and_then(|s| ScalarValue::try_from(s).ok())
+            .and_then(|s| s.cast_to(&data_type).ok())
+            .map(Precision::Exact)
+            .unwrap_or(Precision::Absent)
+    };
+
+    // Chunks without any valid values don't contribute to the min and max
+    let (min, max) = chunks
+        .iter()
+        .map(cached_min_max)
+        .fold_ok(None, MinMaxResult::merge)
+        .ok()
+        .flatten()
+        .map(|MinMaxResult { min, max }| (Some(min), Some(max)))
+        .unwrap_or_default();
+
+    ColumnStatistics {
+        null_count,
+        max_value: df_scalar(max),
+        min_value: df_scalar(min),
+        distinct_count: Precision::Absent,
+    }
+}
+
+/// [`min_max`] of an array, stored in its [`Stat::Min`] and [`Stat::Max`] statistics.
+fn cached_min_max(array: &Array) -> VortexResult<Option<MinMaxResult>> {
+    let stats = array.statistics();
+    if let (Some(min), Some(max)) = (stats.get(Stat::Min), stats.get(Stat::Max)) {
+        if !min.is_null() && !max.is_null() {
+            return Ok(Some(MinMaxResult { min, max }));
+        }
+    }
+
+    let result = min_max(array)?;
+    if let Some(MinMaxResult { min, max }) = &result {
+        stats.set(Stat::Min, min.clone());
+        stats.set(Stat::Max, max.clone());
+    }
+    Ok(result)
+}
+
+/// Number of nulls in an array, stored in its [`Stat::NullCount`] statistic.
+fn cached_null_count(array: &Array) -> VortexResult<usize> {
+    let stats = array.statistics();
+    if let Some(null_count) = stats.get_as::<u64>(Stat::NullCount) {
+        return Ok(null_count as usize);
+    }
+
+    let null_count = array.len() - count_valid(array)?;
+    stats.set(Stat::NullCount, Scalar::from(null_count as u64));
+    Ok(null_count)
+}
+
+/// Convert the column statistics of a Vortex file into DataFusion statistics for `schema`.
+///
+/// Columns are matched by name, file columns that are missing from the schema are ignored and
+/// schema columns that are missing from the file have unknown statistics.
+pub fn file_df_stats(
+    row_count: u64,
+    file_dtype: &DType,
+    column_stats: &[StatsSet],
+    schema: &Schema,
+) -> Statistics {
+    let names = file_dtype
+        .as_struct()
+        .map(|st| st.names().to_vec())
+        .unwrap_or_default();
+    let column_statistics = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            names
+                .iter()
+                .position(|n| n.as_ref() == field.name())
+                .and_then(|i| column_stats.get(i))
+                .map(|stats| column_df_stats(stats, field.data_type()))
+                .unwrap_or_else(ColumnStatistics::new_unknown)
+        })
+        .collect();
+
+    Statistics {
+        num_rows: Precision::Exact(row_count as usize),
+        total_byte_size: Precision::Absent,
+        column_statistics,
+    }
+}
+
+fn column_df_stats(stats: &StatsSet, data_type: &DataType) -> ColumnStatistics {
+    // Statistics must have the type of the table column, Vortex may produce e.g. Utf8 for Utf8View
+    let df_scalar = |stat| {
+        stats
+            .get(stat)
+            .and_then(|s| ScalarValue::try_from(s.clone()).ok())
+            .and_then(|s| s.cast_to(data_type).ok())
+            .map(Precision::Exact)
+            .unwrap_or(Precision::Absent)
+    };
+
+    ColumnStatistics {
+        null_count: stats
+            .get(Stat::NullCount)
+            .and_then(|n| u64::try_from(n).ok())
+            .map(|n| Precision::Exact(n as usize))
+            .unwrap_or(Precision::Absent),
+        max_value: df_scalar(Stat::Max),
+        min_value: df_scalar(Stat::Min),
+        distinct_count: Precision::Absent,
+    }
+}
+
+/// Combine the statistics of multiple files of the same table
+pub fn merge_df_stats(left: Statistics, right: &Statistics) -> Statistics {
+    Statistics {
+        num_rows: left.num_rows.add(&right.num_rows),
+        total_byte_size: left.total_byte_size.add(&right.total_byte_size),
+        column_statistics: left
+            .column_statistics
+            .into_iter()
+            .zip(right.column_statistics.iter())
+            .map(|(l, r)| ColumnStatistics {
+                null_count: l.null_count.add(&r.null_count),
+                max_value: l.max_value.max(&r.max_value),
+                min_value: l.min_value.min(&r.min_value),
+                distinct_count: Precision::Absent,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion_common::stats::Precision;
+    use datafusion_common::ScalarValue;
+    use vortex::array::{ChunkedArray, PrimitiveArray, StructArray};
+    use vortex::stats::{ArrayStatistics, Stat};
+    use vortex::{Array, ArrayDType, IntoArray};
+
+    use crate::statistics::chunked_array_df_stats;
+
+    fn chunk(values: Vec<Option<i32>>) -> Array {
+        StructArray::from_fields(&[("a", PrimitiveArray::from_nullable_vec(values).into_array())])
+            .unwrap()
+            .into_array()
+    }
+
+    #[test]
+    fn chunked_stats_are_cached() {
+        let chunks = vec![
+            chunk(vec![Some(3), None]),
+            chunk(vec![None, None]),
+            chunk(vec![Some(-1), Some(7)]),
+        ];
+        let dtype = chunks[0].dtype().clone();
+        let array = ChunkedArray::try_new(chunks, dtype).unwrap();
+
+        let stats = chunked_array_df_stats(&array, &[0]).unwrap();
+        let column = &stats.column_statistics[0];
+        assert_eq!(column.null_count, Precision::Exact(3));
+        assert_eq!(
+            column.min_value,
+            Precision::Exact(ScalarValue::Int32(Some(-1)))
+        );
+        assert_eq!(
+            column.max_value,
+            Precision::Exact(ScalarValue::Int32(Some(7)))
+        );
+
+        // The statistics are stored on the fields of the chunks for the next call
+        let field = array
+            .chunk(0)
+            .unwrap()
+            .with_dyn(|a| a.as_struct_array().and_then(|s| s.field(0)))
+            .unwrap();
+        assert_eq!(field.statistics().get_as::<i32>(Stat::Max), Some(3));
+        assert_eq!(field.statistics().get_as::<u64>(Stat::NullCount), Some(1));
+        assert_eq!(chunked_array_df_stats(&array, &[0]).unwrap(), stats);
+    }
+}
@@ -1,19 +1,26 @@
 #![cfg(feature = "arrow")]
 
-use arrow_schema::{DataType, TimeUnit as ArrowTimeUnit};
+use arrow_schema::{DataType, IntervalUnit as ArrowIntervalUnit, TimeUnit as ArrowTimeUnit};
 use vortex_dtype::ExtDType;
 use vortex_error::{vortex_bail, vortex_panic, VortexError, VortexExpect as _, VortexResult};
 
-use crate::temporal::{TemporalMetadata, DATE_ID, TIMESTAMP_ID, TIME_ID};
-use crate::unit::TimeUnit;
+use crate::temporal::{TemporalMetadata, DATE_ID, DURATION_ID, INTERVAL_ID, TIMESTAMP_ID, TIME_ID};
+use crate::unit::{IntervalUnit, TimeUnit};
 
 /// Construct an extension type from the provided temporal Arrow type.
 ///
-/// Supported types are Date32, Date64, Time32, Time64, Timestamp.
+/// Supported types are Date32, Date64, Time32, Time64, Timestamp, Duration and Interval.
+///
+/// panics if the data_type is not a temporal type, see [`try_make_temporal_ext_dtype`]
 pub fn make_temporal_ext_dtype(data_type: &DataType) -> ExtDType {
-    assert!(data_type.is_temporal(), "Must receive a temporal DataType");
+    try_make_temporal_ext_dtype(data_type)
+        .vortex_expect("make_temporal_ext_dtype must be called with a temporal DataType")
+}
 
-    match data_type {
+/// Construct an extension type from the provided temporal Arrow type, failing for
+/// non-temporal types.
+pub fn try_make_temporal_ext_dtype(data_type: &DataType) -> VortexResult<ExtDType> {
+    Ok(match data_type {
         DataType::Timestamp(time_unit, time_zone) => {
             let time_unit = TimeUnit::from(time_unit);
             let tz = time_zone.clone().map(|s| s.to_string());
@@ -45,8 +52,16 @@ pub fn make_temporal_ext_dtype(data_type: &DataType) -> ExtDType {
             DATE_ID.clone(),
             Some(TemporalMetadata::Date(TimeUnit::Ms).into()),
         ),
-        _ => unimplemented!("{data_type} conversion"),
-    }
+        DataType::Duration(time_unit) => ExtDType::new(
+            DURATION_ID.clone(),
+            Some(TemporalMetadata::Duration(time_unit.into()).into()),
+        ),
+        DataType::Interval(interval_unit) => ExtDType::new(
+            INTERVAL_ID.clone(),
+            Some(TemporalMetadata::Interval(interval_unit.into()).into()),
+        ),
+        _ => vortex_bail!("{data_type} is not a temporal type"),
+    })
 }
 
 /// Convert temporal ExtDType to a corresponding arrow DataType
@@ -81,6 +96,12 @@ pub fn make_arrow_temporal_dtype(ext_dtype: &ExtDType) -> DataType {
                 vortex_panic!(InvalidArgument: "Invalid TimeUnit {} for {}", time_unit, ext_dtype.id())
             }
         },
+        TemporalMetadata::Duration(time_unit) => DataType::Duration(
+            ArrowTimeUnit::try_from(time_unit).unwrap_or_else(|_| {
+                vortex_panic!(InvalidArgument: "Invalid TimeUnit {} for {}", time_unit, ext_dtype.id())
+            }),
+        ),
+        TemporalMetadata::Interval(interval_unit) => DataType::Interval(interval_unit.into()),
     }
 }
 
@@ -101,6 +122,26 @@ impl From<ArrowTimeUnit> for TimeUnit {
     }
 }
 
+impl From<&ArrowIntervalUnit> for IntervalUnit {
+    fn from(value: &ArrowIntervalUnit) -> Self {
+        match value {
+            ArrowIntervalUnit::YearMonth => Self::YearMonth,
+            ArrowIntervalUnit::DayTime => Self::DayTime,
+            ArrowIntervalUnit::MonthDayNano => Self::MonthDayNano,
+        }
+    }
+}
+
+impl From<IntervalUnit> for ArrowIntervalUnit {
+    fn from(value: IntervalUnit) -> Self {
+        match value {
+            IntervalUnit::YearMonth => Self::YearMonth,
+            IntervalUnit::DayTime => Self::DayTime,
+            IntervalUnit::MonthDayNano => Self::MonthDayNano,
+        }
+    }
+}
+
 impl TryFrom<TimeUnit> for ArrowTimeUnit {
     type Error = VortexError;
 
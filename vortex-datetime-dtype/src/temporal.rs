@@ -1,20 +1,29 @@
 use std::fmt::Display;
 
 use jiff::civil::{Date, Time};
-use jiff::{Timestamp, Zoned};
+use jiff::{Span, Timestamp, Zoned};
 use lazy_static::lazy_static;
 use vortex_dtype::ExtID;
 
-use crate::unit::TimeUnit;
+use crate::unit::{IntervalUnit, TimeUnit};
 
 lazy_static! {
     pub static ref TIME_ID: ExtID = ExtID::from("vortex.time");
     pub static ref DATE_ID: ExtID = ExtID::from("vortex.date");
     pub static ref TIMESTAMP_ID: ExtID = ExtID::from("vortex.timestamp");
+    pub static ref DURATION_ID: ExtID = ExtID::from("vortex.duration");
+    pub static ref INTERVAL_ID: ExtID = ExtID::from("vortex.interval");
 }
 
 pub fn is_temporal_ext_type(id: &ExtID) -> bool {
-    [&DATE_ID as &ExtID, &TIME_ID, &TIMESTAMP_ID].contains(&id)
+    [
+        &DATE_ID as &ExtID,
+        &TIME_ID,
+        &TIMESTAMP_ID,
+        &DURATION_ID,
+        &INTERVAL_ID,
+    ]
+    .contains(&id)
 }
 
 /// Metadata for TemporalArray.
@@ -25,6 +34,8 @@ pub enum TemporalMetadata {
     Time(TimeUnit),
     Date(TimeUnit),
     Timestamp(TimeUnit, Option<String>),
+    Duration(TimeUnit),
+    Interval(IntervalUnit),
 }
 
 pub enum TemporalJiff {
@@ -32,6 +43,7 @@ pub enum TemporalJiff {
     Date(Date),
     Timestamp(Timestamp),
     Zoned(Zoned),
+    Span(Span),
 }
 
 impl Display for TemporalJiff {
@@ -41,6 +53,7 @@ impl Display for TemporalJiff {
             TemporalJiff::Date(d) => write!(f, "{}", d),
             TemporalJiff::Timestamp(ts) => write!(f, "{}", ts),
             TemporalJiff::Zoned(z) => write!(f, "{}", z),
+            TemporalJiff::Span(s) => write!(f, "{}", s),
         }
     }
 }
@@ -48,12 +61,25 @@ impl Display for TemporalJiff {
 impl TemporalMetadata {
     /// Retrieve the time unit associated with the array.
     ///
-    /// All temporal arrays have a single intrinsic time unit for all of its numeric values.
-    pub fn time_unit(&self) -> TimeUnit {
+    /// All temporal arrays except intervals have a single intrinsic time unit for all of its
+    /// numeric values. Intervals are made of several components and return `None`, see
+    /// [`Self::interval_unit`].
+    pub fn time_unit(&self) -> Option<TimeUnit> {
         match self {
             TemporalMetadata::Time(time_unit)
             | TemporalMetadata::Date(time_unit)
-            | TemporalMetadata::Timestamp(time_unit, _) => *time_unit,
+            | TemporalMetadata::Timestamp(time_unit, _)
+            | TemporalMetadata::Duration(time_unit) => Some(*time_unit),
+            TemporalMetadata::Interval(_) => None,
+        }
+    }
+
+    /// Retrieve the layout of the components of an interval.
+    pub fn interval_unit(&self) -> Option<IntervalUnit> {
+        if let TemporalMetadata::Interval(interval_unit) = self {
+            Some(*interval_unit)
+        } else {
+            None
         }
     }
 
@@ -93,6 +119,10 @@ impl TemporalMetadata {
                     .checked_add(unit.to_jiff_span(v)?)?
                     .intz(tz)?,
             )),
+            TemporalMetadata::Duration(unit) => Ok(TemporalJiff::Span(unit.to_jiff_span(v)?)),
+            TemporalMetadata::Interval(unit) => {
+                vortex_bail!("{} intervals can't be represented by jiff", unit)
+            }
         }
     }
 }
@@ -111,6 +141,8 @@ impl TryFrom<&ExtDType> for TemporalMetadata {
             x if x == TIME_ID.as_ref() => decode_time_metadata(metadata),
             x if x == DATE_ID.as_ref() => decode_date_metadata(metadata),
             x if x == TIMESTAMP_ID.as_ref() => decode_timestamp_metadata(metadata),
+            x if x == DURATION_ID.as_ref() => decode_duration_metadata(metadata),
+            x if x == INTERVAL_ID.as_ref() => decode_interval_metadata(metadata),
             _ => {
                 vortex_bail!("ExtDType must be one of the known temporal types")
             }
@@ -148,20 +180,41 @@ fn decode_timestamp_metadata(ext_meta: &ExtMetadata) -> VortexResult<TemporalMet
     Ok(TemporalMetadata::Timestamp(time_unit, Some(tz)))
 }
 
+fn decode_duration_metadata(ext_meta: &ExtMetadata) -> VortexResult<TemporalMetadata> {
+    let tag = ext_meta.as_ref()[0];
+    let time_unit =
+        TimeUnit::try_from(tag).map_err(|e| vortex_err!(ComputeError: "invalid unit tag: {e}"))?;
+    Ok(TemporalMetadata::Duration(time_unit))
+}
+
+fn decode_interval_metadata(ext_meta: &ExtMetadata) -> VortexResult<TemporalMetadata> {
+    let tag = ext_meta.as_ref()[0];
+    let interval_unit = IntervalUnit::try_from(tag)
+        .map_err(|e| vortex_err!(ComputeError: "invalid interval unit tag: {e}"))?;
+    Ok(TemporalMetadata::Interval(interval_unit))
+}
+
 impl From<TemporalMetadata> for ExtMetadata {
     /// Infallibly serialize a `TemporalMetadata` as an `ExtMetadata` so it can be attached to
     /// an `ExtensionArray`.
     fn from(value: TemporalMetadata) -> Self {
         match value {
-            // Time32/Time64 and Date32/Date64 only need to encode the unit in their metadata
-            // The unit also unambiguously maps to the integer width of the backing array for all.
-            TemporalMetadata::Time(time_unit) | TemporalMetadata::Date(time_unit) => {
+            // Time32/Time64, Date32/Date64 and Duration only need to encode the unit in their
+            // metadata. The unit also unambiguously maps to the integer width of the backing array
+            // for all.
+            TemporalMetadata::Time(time_unit)
+            | TemporalMetadata::Date(time_unit)
+            | TemporalMetadata::Duration(time_unit) => {
                 let mut meta = Vec::new();
                 let unit_tag: u8 = time_unit.into();
                 meta.push(unit_tag);
 
                 ExtMetadata::from(meta.as_slice())
             }
+            TemporalMetadata::Interval(interval_unit) => {
+                let unit_tag: u8 = interval_unit.into();
+                ExtMetadata::from([unit_tag].as_slice())
+            }
             // Store both the time unit and zone in the metadata
             TemporalMetadata::Timestamp(time_unit, time_zone) => {
                 let mut meta = Vec::new();
@@ -190,7 +243,7 @@ impl From<TemporalMetadata> for ExtMetadata {
 mod tests {
     use vortex_dtype::{ExtDType, ExtMetadata};
 
-    use crate::{TemporalMetadata, TimeUnit, TIMESTAMP_ID};
+    use crate::{IntervalUnit, TemporalMetadata, TimeUnit, DURATION_ID, INTERVAL_ID, TIMESTAMP_ID};
 
     #[test]
     fn test_roundtrip_metadata() {
@@ -215,4 +268,29 @@ mod tests {
             TemporalMetadata::Timestamp(TimeUnit::Ms, Some("UTC".to_string()))
         );
     }
+
+    #[test]
+    fn test_roundtrip_duration_interval_metadata() {
+        for (id, metadata) in [
+            (
+                DURATION_ID.clone(),
+                TemporalMetadata::Duration(TimeUnit::Us),
+            ),
+            (
+                INTERVAL_ID.clone(),
+                TemporalMetadata::Interval(IntervalUnit::MonthDayNano),
+            ),
+        ] {
+            let ext_dtype = ExtDType::new(id, Some(metadata.clone().into()));
+            assert_eq!(TemporalMetadata::try_from(&ext_dtype).unwrap(), metadata);
+        }
+        assert_eq!(
+            TemporalMetadata::Interval(IntervalUnit::DayTime).interval_unit(),
+            Some(IntervalUnit::DayTime)
+        );
+        assert_eq!(
+            TemporalMetadata::Duration(TimeUnit::Us).interval_unit(),
+            None
+        );
+    }
 }
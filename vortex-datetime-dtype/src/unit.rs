@@ -3,7 +3,8 @@ use std::fmt::{Display, Formatter};
 use jiff::Span;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde::{Deserialize, Serialize};
-use vortex_error::VortexResult;
+use vortex_dtype::{DType, Nullability, PType, StructDType};
+use vortex_error::{vortex_bail, VortexResult};
 
 #[derive(
     Debug,
@@ -51,3 +52,112 @@ impl Display for TimeUnit {
         }
     }
 }
+
+/// The layout of an interval, mirroring the interval types of Arrow.
+///
+/// Unlike durations, intervals are made of calendar components (months and days) that don't
+/// have a fixed length, so each component is stored separately.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    IntoPrimitive,
+    TryFromPrimitive,
+)]
+#[repr(u8)]
+pub enum IntervalUnit {
+    /// A number of months, stored as `i32`.
+    YearMonth,
+    /// A number of days and milliseconds, stored as a struct of two `i32`s.
+    DayTime,
+    /// A number of months, days and nanoseconds, stored as a struct of `i32`, `i32` and `i64`.
+    MonthDayNano,
+}
+
+impl IntervalUnit {
+    /// The names and primitive types of the components of this interval.
+    pub fn components(&self) -> &'static [(&'static str, PType)] {
+        match self {
+            IntervalUnit::YearMonth => &[("months", PType::I32)],
+            IntervalUnit::DayTime => &[("days", PType::I32), ("milliseconds", PType::I32)],
+            IntervalUnit::MonthDayNano => &[
+                ("months", PType::I32),
+                ("days", PType::I32),
+                ("nanoseconds", PType::I64),
+            ],
+        }
+    }
+
+    /// The [`DType`] of the storage array of intervals with this unit.
+    ///
+    /// Year-month intervals are stored as a single primitive, the other intervals as a struct
+    /// with one non-nullable field per component.
+    pub fn storage_dtype(&self, nullability: Nullability) -> DType {
+        match self.components() {
+            [(_, ptype)] => DType::Primitive(*ptype, nullability),
+            components => DType::Struct(
+                StructDType::new(
+                    components.iter().map(|(name, _)| (*name).into()).collect(),
+                    components
+                        .iter()
+                        .map(|(_, ptype)| DType::Primitive(*ptype, Nullability::NonNullable))
+                        .collect(),
+                ),
+                nullability,
+            ),
+        }
+    }
+
+    /// Render the components of an interval, in the order of [`Self::components`].
+    ///
+    /// Components of an interval may have different signs, so unlike durations they can't be
+    /// represented by a [`Span`].
+    pub fn format_components(&self, components: &[i64]) -> VortexResult<String> {
+        let (months, days, nanos) = match (self, components) {
+            (IntervalUnit::YearMonth, [months]) => (*months, 0, 0),
+            (IntervalUnit::DayTime, [days, millis]) => (0, *days, *millis as i128 * 1_000_000),
+            (IntervalUnit::MonthDayNano, [months, days, nanos]) => (*months, *days, *nanos as i128),
+            _ => vortex_bail!(
+                "Expected {} components for {} interval, got {}",
+                self.components().len(),
+                self,
+                components.len()
+            ),
+        };
+
+        let mut parts = Vec::new();
+        if months != 0 {
+            parts.push(format!("{months} mons"));
+        }
+        if days != 0 {
+            parts.push(format!("{days} days"));
+        }
+        if nanos != 0 || parts.is_empty() {
+            let sign = if nanos < 0 { "-" } else { "" };
+            let nanos = nanos.unsigned_abs();
+            parts.push(format!(
+                "{sign}{}.{:09} secs",
+                nanos / 1_000_000_000,
+                nanos % 1_000_000_000
+            ));
+        }
+        Ok(parts.join(" "))
+    }
+}
+
+impl Display for IntervalUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::YearMonth => write!(f, "year_month"),
+            Self::DayTime => write!(f, "day_time"),
+            Self::MonthDayNano => write!(f, "month_day_nano"),
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use std::fmt::{Display, Formatter};
+
+use lazy_static::lazy_static;
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::{DType, ExtDType, ExtID, ExtMetadata, Nullability, PType, StructDType};
+
+lazy_static! {
+    pub static ref DECIMAL_ID: ExtID = ExtID::from("vortex.decimal");
+}
+
+/// The largest precision that fits into a single 64-bit storage word.
+pub const MAX_DECIMAL64_PRECISION: u8 = 18;
+/// The largest precision that can be represented by a 128-bit integer.
+pub const MAX_DECIMAL128_PRECISION: u8 = 38;
+/// The largest precision that can be represented by a 256-bit integer.
+pub const MAX_DECIMAL256_PRECISION: u8 = 76;
+
+/// Field names of the storage words of a wide decimal, most significant word first.
+pub const DECIMAL_WORD_NAMES: [&str; 4] = ["w0", "w1", "w2", "w3"];
+
+pub fn is_decimal_ext_type(id: &ExtID) -> bool {
+    id == &*DECIMAL_ID
+}
+
+/// Metadata for the `vortex.decimal` extension type.
+///
+/// A decimal holds an unscaled integer `v` representing the value `v * 10^-scale`. The storage of
+/// the unscaled integer depends only on the precision:
+///
+/// * precision <= 18: a single `i64` primitive.
+/// * precision <= 38: a struct of two 64-bit words, i.e. an `i128`.
+/// * precision <= 76: a struct of four 64-bit words, i.e. an `i256`.
+///
+/// Wide values are split into two's complement words, the most significant word (`w0`) is an
+/// `i64`, the remaining words are `u64`. Every word is stored as its own non-nullable column so
+/// that each one can be compressed independently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecimalMetadata {
+    precision: u8,
+    scale: i8,
+}
+
+impl DecimalMetadata {
+    pub fn try_new(precision: u8, scale: i8) -> VortexResult<Self> {
+        if precision == 0 || precision > MAX_DECIMAL256_PRECISION {
+            vortex_bail!(
+                "Decimal precision must be between 1 and {}, got {}",
+                MAX_DECIMAL256_PRECISION,
+                precision
+            );
+        }
+        if scale > 0 && scale as u8 > precision {
+            vortex_bail!(
+                "Decimal scale {} must not be greater than precision {}",
+                scale,
+                precision
+            );
+        }
+        Ok(Self { precision, scale })
+    }
+
+    /// Total number of decimal digits.
+    #[inline]
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Number of digits after the decimal point.
+    #[inline]
+    pub fn scale(&self) -> i8 {
+        self.scale
+    }
+
+    /// The number of 64-bit words used to store each unscaled value.
+    pub fn storage_words(&self) -> usize {
+        if self.precision <= MAX_DECIMAL64_PRECISION {
+            1
+        } else if self.precision <= MAX_DECIMAL128_PRECISION {
+            2
+        } else {
+            4
+        }
+    }
+
+    /// The [`DType`] of the storage array for decimals with this metadata.
+    pub fn storage_dtype(&self, nullability: Nullability) -> DType {
+        match self.storage_words() {
+            1 => DType::Primitive(PType::I64, nullability),
+            words => DType::Struct(
+                StructDType::new(
+                    DECIMAL_WORD_NAMES[..words]
+                        .iter()
+                        .map(|name| (*name).into())
+                        .collect(),
+                    (0..words)
+                        .map(|i| {
+                            let ptype = if i == 0 { PType::I64 } else { PType::U64 };
+                            DType::Primitive(ptype, Nullability::NonNullable)
+                        })
+                        .collect(),
+                ),
+                nullability,
+            ),
+        }
+    }
+
+    pub fn ext_dtype(&self) -> ExtDType {
+        ExtDType::new(DECIMAL_ID.clone(), Some((*self).into()))
+    }
+
+    pub fn dtype(&self, nullability: Nullability) -> DType {
+        DType::Extension(self.ext_dtype(), nullability)
+    }
+}
+
+impl Display for DecimalMetadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "decimal({}, {})", self.precision, self.scale)
+    }
+}
+
+impl TryFrom<&ExtDType> for DecimalMetadata {
+    type Error = VortexError;
+
+    fn try_from(ext_dtype: &ExtDType) -> Result<Self, Self::Error> {
+        if !is_decimal_ext_type(ext_dtype.id()) {
+            vortex_bail!(
+                "Expected {} extension type, got {}",
+                *DECIMAL_ID,
+                ext_dtype.id()
+            );
+        }
+        let metadata = ext_dtype
+            .metadata()
+            .ok_or_else(|| vortex_err!("Decimal ExtDType is missing metadata"))?;
+        let [precision, scale] = metadata.as_ref() else {
+            vortex_bail!("Decimal metadata must be exactly two bytes");
+        };
+        Self::try_new(*precision, *scale as i8)
+    }
+}
+
+impl TryFrom<&DType> for DecimalMetadata {
+    type Error = VortexError;
+
+    fn try_from(dtype: &DType) -> Result<Self, Self::Error> {
+        match dtype {
+            DType::Extension(ext_dtype, _) => Self::try_from(ext_dtype),
+            _ => Err(vortex_err!("Expected decimal dtype, got {}", dtype)),
+        }
+    }
+}
+
+impl From<DecimalMetadata> for ExtMetadata {
+    fn from(value: DecimalMetadata) -> Self {
+        ExtMetadata::from([value.precision, value.scale as u8].as_slice())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DType, DecimalMetadata, Nullability, PType};
+
+    #[test]
+    fn metadata_roundtrip() {
+        let metadata = DecimalMetadata::try_new(38, -2).unwrap();
+        let ext_dtype = metadata.ext_dtype();
+        assert_eq!(DecimalMetadata::try_from(&ext_dtype).unwrap(), metadata);
+    }
+
+    #[test]
+    fn invalid_metadata() {
+        assert!(DecimalMetadata::try_new(0, 0).is_err());
+        assert!(DecimalMetadata::try_new(77, 0).is_err());
+        assert!(DecimalMetadata::try_new(5, 6).is_err());
+    }
+
+    #[test]
+    fn storage_dtype() {
+        assert_eq!(
+            DecimalMetadata::try_new(18, 2)
+                .unwrap()
+                .storage_dtype(Nullability::Nullable),
+            DType::Primitive(PType::I64, Nullability::Nullable)
+        );
+        assert!(matches!(
+            DecimalMetadata::try_new(19, 2)
+                .unwrap()
+                .storage_dtype(Nullability::NonNullable),
+            DType::Struct(words, _) if words.names().len() == 2
+        ));
+        assert!(matches!(
+            DecimalMetadata::try_new(76, 2)
+                .unwrap()
+                .storage_dtype(Nullability::NonNullable),
+            DType::Struct(words, _) if words.names().len() == 4
+        ));
+    }
+
+    #[cfg(feature = "proto")]
+    #[test]
+    fn from_proto_decimal() {
+        use crate::proto::dtype as pb;
+        use crate::proto::dtype::d_type::DtypeType;
+
+        let proto = pb::DType {
+            dtype_type: Some(DtypeType::Decimal(pb::Decimal {
+                precision: 20,
+                scale: 3,
+                nullable: true,
+            })),
+        };
+        assert_eq!(
+            DType::try_from(&proto).unwrap(),
+            DecimalMetadata::try_new(20, 3)
+                .unwrap()
+                .dtype(Nullability::Nullable)
+        );
+    }
+}
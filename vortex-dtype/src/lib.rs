@@ -1,5 +1,6 @@
 #![cfg(target_endian = "little")]
 
+pub use decimal::*;
 pub use dtype::*;
 pub use extension::*;
 pub use half;
@@ -8,6 +9,7 @@ pub use ptype::*;
 
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
+mod decimal;
 mod dtype;
 mod extension;
 pub mod field;
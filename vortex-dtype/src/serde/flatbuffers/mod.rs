@@ -5,7 +5,9 @@ use itertools::Itertools;
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_flatbuffers::{FlatBufferRoot, WriteFlatBuffer};
 
-use crate::{flatbuffers as fb, DType, ExtDType, ExtID, ExtMetadata, PType, StructDType};
+use crate::{
+    flatbuffers as fb, DType, DecimalMetadata, ExtDType, ExtID, ExtMetadata, PType, StructDType,
+};
 
 mod project;
 pub use project::*;
@@ -77,6 +79,15 @@ impl TryFrom<fb::DType<'_>> for DType {
                     fb_struct.nullable().into(),
                 ))
             }
+            fb::Type::Decimal => {
+                let fb_decimal = fb
+                    .type__as_decimal()
+                    .ok_or_else(|| vortex_err!("failed to parse decimal from flatbuffer"))?;
+                let scale = i8::try_from(fb_decimal.scale())
+                    .map_err(|_| vortex_err!("invalid decimal scale in flatbuffer"))?;
+                Ok(DecimalMetadata::try_new(fb_decimal.precision(), scale)?
+                    .dtype(fb_decimal.nullable().into()))
+            }
             fb::Type::Extension => {
                 let fb_ext = fb
                     .type__as_extension()
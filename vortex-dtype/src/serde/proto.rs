@@ -6,7 +6,7 @@ use crate::field::{Field, FieldPath};
 use crate::proto::dtype as pb;
 use crate::proto::dtype::d_type::DtypeType;
 use crate::proto::dtype::field::FieldType;
-use crate::{DType, ExtDType, ExtID, ExtMetadata, PType, StructDType};
+use crate::{DType, DecimalMetadata, ExtDType, ExtID, ExtMetadata, PType, StructDType};
 
 impl TryFrom<&pb::DType> for DType {
     type Error = VortexError;
@@ -20,7 +20,13 @@ impl TryFrom<&pb::DType> for DType {
             DtypeType::Null(_) => Ok(Self::Null),
             DtypeType::Bool(b) => Ok(Self::Bool(b.nullable.into())),
             DtypeType::Primitive(p) => Ok(Self::Primitive(p.r#type().into(), p.nullable.into())),
-            DtypeType::Decimal(_) => todo!("Not Implemented"),
+            DtypeType::Decimal(d) => Ok(DecimalMetadata::try_new(
+                u8::try_from(d.precision)
+                    .map_err(|_| vortex_err!(InvalidSerde: "Invalid decimal precision"))?,
+                i8::try_from(d.scale)
+                    .map_err(|_| vortex_err!(InvalidSerde: "Invalid decimal scale"))?,
+            )?
+            .dtype(d.nullable.into())),
             DtypeType::Utf8(u) => Ok(Self::Utf8(u.nullable.into())),
             DtypeType::Binary(b) => Ok(Self::Binary(b.nullable.into())),
             DtypeType::Struct(s) => Ok(Self::Struct(
@@ -2,13 +2,18 @@ use std::any::Any;
 use std::sync::Arc;
 
 use vortex::aliases::hash_set::HashSet;
-use vortex::compute::{and, compare, or, Operator as ArrayOperator};
+use vortex::compute::{
+    and, arithmetic, compare, or, ArithmeticOperator, ArithmeticOptions, Operator as ArrayOperator,
+};
 use vortex::Array;
 use vortex_dtype::field::Field;
 use vortex_error::VortexResult;
 
 use crate::{unbox_any, Operator, VortexExpr};
 
+/// Integer overflow wraps around like in DataFusion, so pushed down filters match its results.
+const ARITHMETIC_OPTIONS: ArithmeticOptions = ArithmeticOptions { wrapping: true };
+
 #[derive(Debug, Clone)]
 pub struct BinaryExpr {
     lhs: Arc<dyn VortexExpr>,
@@ -52,10 +57,15 @@ impl VortexExpr for BinaryExpr {
             Operator::Gte => compare(lhs, rhs, ArrayOperator::Gte),
             Operator::And => and(lhs, rhs),
             Operator::Or => or(lhs, rhs),
+            Operator::Add => arithmetic(lhs, rhs, ArithmeticOperator::Add, ARITHMETIC_OPTIONS),
+            Operator::Sub => arithmetic(lhs, rhs, ArithmeticOperator::Sub, ARITHMETIC_OPTIONS),
+            Operator::Mul => arithmetic(lhs, rhs, ArithmeticOperator::Mul, ARITHMETIC_OPTIONS),
+            Operator::Div => arithmetic(lhs, rhs, ArithmeticOperator::Div, ARITHMETIC_OPTIONS),
+            Operator::Rem => arithmetic(lhs, rhs, ArithmeticOperator::Rem, ARITHMETIC_OPTIONS),
         }
     }
 
-    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a Field>) {
+    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a [Field]>) {
         self.lhs.collect_references(references);
         self.rhs.collect_references(references);
     }
@@ -69,3 +79,67 @@ impl PartialEq<dyn Any> for BinaryExpr {
             .unwrap_or(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vortex::array::{PrimitiveArray, StructArray};
+    use vortex::{IntoArray, IntoArrayVariant};
+
+    use crate::{BinaryExpr, Column, Literal, Operator, VortexExpr};
+
+    #[test]
+    fn evaluate_arithmetic() {
+        let st = StructArray::from_fields(&[
+            (
+                "price",
+                PrimitiveArray::from(vec![10i64, 25, 3]).into_array(),
+            ),
+            (
+                "qty",
+                PrimitiveArray::from_nullable_vec(vec![Some(2i64), None, Some(7)]).into_array(),
+            ),
+        ])
+        .unwrap();
+
+        let total = BinaryExpr::new(
+            Arc::new(BinaryExpr::new(
+                Arc::new(Column::from("price".to_string())),
+                Operator::Mul,
+                Arc::new(Column::from("qty".to_string())),
+            )),
+            Operator::Sub,
+            Arc::new(Literal::new(1i64.into())),
+        );
+        let result = total
+            .evaluate(st.as_ref())
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(result.maybe_null_slice::<i64>()[0], 19);
+        assert_eq!(result.maybe_null_slice::<i64>()[2], 20);
+        assert!(!result.validity().is_valid(1));
+    }
+
+    #[test]
+    fn evaluate_arithmetic_wraps() {
+        let st = StructArray::from_fields(&[(
+            "year",
+            PrimitiveArray::from(vec![1788u16, 1789, 1790]).into_array(),
+        )])
+        .unwrap();
+
+        let since = BinaryExpr::new(
+            Arc::new(Column::from("year".to_string())),
+            Operator::Sub,
+            Arc::new(Literal::new(1789u16.into())),
+        );
+        let result = since
+            .evaluate(st.as_ref())
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(result.maybe_null_slice::<u16>(), [u16::MAX, 0, 1]);
+    }
+}
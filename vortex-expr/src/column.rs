@@ -1,26 +1,50 @@
 use std::any::Any;
 
 use vortex::aliases::hash_set::HashSet;
-use vortex::array::StructArray;
-use vortex::variants::StructArrayTrait;
-use vortex::Array;
-use vortex_dtype::field::Field;
+use vortex::array::{
+    BoolArray, ExtensionArray, ListArray, PrimitiveArray, StructArray, VarBinViewArray,
+};
+use vortex::validity::ArrayValidity;
+use vortex::variants::{ExtensionArrayTrait, StructArrayTrait};
+use vortex::{Array, ArrayDType, Canonical, IntoArray, IntoCanonical};
+use vortex_dtype::field::{Field, FieldPath};
 use vortex_error::{vortex_err, VortexResult};
 
 use crate::{unbox_any, VortexExpr};
 
 #[derive(Debug, PartialEq, Hash, Clone, Eq)]
 pub struct Column {
-    field: Field,
+    path: FieldPath,
 }
 
 impl Column {
     pub fn new(field: Field) -> Self {
-        Self { field }
+        Self {
+            path: FieldPath::from(field),
+        }
+    }
+
+    /// Reference a field nested in struct columns, the first element of the path is the top level
+    /// column that contains it.
+    pub fn new_nested(path: FieldPath) -> Self {
+        assert!(
+            !path.path().is_empty(),
+            "Column path must reference a top level column"
+        );
+        Self { path }
     }
 
+    /// The top level column that's referenced
     pub fn field(&self) -> &Field {
-        &self.field
+        &self.path.path()[0]
+    }
+
+    pub fn path(&self) -> &FieldPath {
+        &self.path
+    }
+
+    pub fn is_nested(&self) -> bool {
+        self.path.path().len() > 1
     }
 }
 
@@ -41,19 +65,78 @@ impl VortexExpr for Column {
         self
     }
 
+    /// A nested field is null wherever one of the structs on its path is null, whatever value the
+    /// field holds in that row. The validity of the batch itself doesn't apply to its columns.
     fn evaluate(&self, batch: &Array) -> VortexResult<Array> {
-        let s = StructArray::try_from(batch)?;
-
-        match &self.field {
-            Field::Name(n) => s.field_by_name(n),
-            Field::Index(i) => s.field(*i),
+        let mut array = batch.clone();
+        for (depth, field) in self.path.path().iter().enumerate() {
+            let s = StructArray::try_from(&array)?;
+            let child = match field {
+                Field::Name(n) => s.field_by_name(n),
+                Field::Index(i) => s.field(*i),
+            }
+            .ok_or_else(|| vortex_err!("Array doesn't contain child array {}", self.path))?;
+            array = if depth > 0 && s.dtype().is_nullable() {
+                with_parent_validity(child, &s)?
+            } else {
+                child
+            };
         }
-        .ok_or_else(|| vortex_err!("Array doesn't contain child array {}", self.field))
+        Ok(array)
+    }
+
+    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a [Field]>) {
+        references.insert(self.path.path());
     }
+}
 
-    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a Field>) {
-        references.insert(self.field());
+/// Null out the rows of a struct field where the struct itself is null.
+///
+/// Fields of structs without nulls are returned as they are.
+fn with_parent_validity(child: Array, parent: &StructArray) -> VortexResult<Array> {
+    let parent_validity = parent.logical_validity();
+    if parent_validity.all_valid() {
+        return Ok(child);
     }
+    let parent_validity = parent_validity.into_validity();
+
+    Ok(match child.into_canonical()? {
+        Canonical::Null(a) => a.into_array(),
+        Canonical::Bool(a) => {
+            BoolArray::try_new(a.boolean_buffer(), a.validity().and(parent_validity)?)?.into_array()
+        }
+        Canonical::Primitive(a) => PrimitiveArray::new(
+            a.buffer().clone(),
+            a.ptype(),
+            a.validity().and(parent_validity)?,
+        )
+        .into_array(),
+        // The fields keep their encoding, only the validity of the struct changes
+        Canonical::Struct(a) => StructArray::try_new(
+            a.names().clone(),
+            a.children().collect(),
+            a.len(),
+            a.validity().and(parent_validity)?,
+        )?
+        .into_array(),
+        Canonical::List(a) => ListArray::try_new(
+            a.elements(),
+            a.offsets(),
+            a.validity().and(parent_validity)?,
+        )?
+        .into_array(),
+        Canonical::VarBinView(a) => VarBinViewArray::try_new(
+            a.views(),
+            a.buffers().collect(),
+            a.dtype().as_nullable(),
+            a.validity().and(parent_validity)?,
+        )?
+        .into_array(),
+        Canonical::Extension(a) => {
+            let storage = with_parent_validity(a.storage(), parent)?;
+            ExtensionArray::new(a.ext_dtype().clone(), storage).into_array()
+        }
+    })
 }
 
 impl PartialEq<dyn Any> for Column {
@@ -64,3 +147,101 @@ impl PartialEq<dyn Any> for Column {
             .unwrap_or(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use vortex::aliases::hash_set::HashSet;
+    use vortex::array::{Constant, ConstantArray, PrimitiveArray, StructArray};
+    use vortex::compute::unary::scalar_at;
+    use vortex::validity::Validity;
+    use vortex::{ArrayDType, ArrayDef, IntoArray, IntoArrayVariant};
+    use vortex_dtype::field::{Field, FieldPath};
+    use vortex_scalar::Scalar;
+
+    use crate::{Column, VortexExpr};
+
+    #[test]
+    fn evaluate_nested() {
+        let inner = StructArray::from_fields(&[
+            ("x", PrimitiveArray::from(vec![1, 2, 3]).into_array()),
+            ("y", PrimitiveArray::from(vec![4, 5, 6]).into_array()),
+        ])
+        .unwrap();
+        let st = StructArray::from_fields(&[
+            ("a", PrimitiveArray::from(vec![7, 8, 9]).into_array()),
+            ("b", inner.into_array()),
+        ])
+        .unwrap();
+
+        let column = Column::new_nested(FieldPath::from(vec![Field::from("b"), Field::from(1)]));
+        assert!(column.is_nested());
+        assert_eq!(column.field(), &Field::from("b"));
+        let values = column
+            .evaluate(st.as_ref())
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(values.maybe_null_slice::<i32>(), &[4, 5, 6]);
+    }
+
+    #[test]
+    fn evaluate_nested_with_null_parents() {
+        let inner = StructArray::try_new(
+            ["x".into()].into(),
+            vec![PrimitiveArray::from(vec![1, 2, 3, 4]).into_array()],
+            4,
+            Validity::from(vec![true, false, true, true]),
+        )
+        .unwrap();
+        let st = StructArray::try_new(
+            ["b".into()].into(),
+            vec![inner.into_array()],
+            4,
+            Validity::from(vec![true, true, true, false]),
+        )
+        .unwrap();
+
+        let column = Column::new_nested(FieldPath::from(vec![Field::from("b"), Field::from("x")]));
+        assert_eq!(
+            column.references(),
+            HashSet::from([[Field::from("b"), Field::from("x")].as_slice()])
+        );
+        let values = column.evaluate(st.as_ref()).unwrap();
+        assert!(values.dtype().is_nullable());
+        // Only the null in "b" applies, the last row is null in the batch itself
+        assert_eq!(
+            (0..values.len())
+                .map(|i| scalar_at(&values, i).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Scalar::from(Some(1)),
+                Scalar::from(None::<i32>),
+                Scalar::from(Some(3)),
+                Scalar::from(Some(4)),
+            ]
+        );
+    }
+
+    #[test]
+    fn evaluate_keeps_encoding_of_valid_parents() {
+        let inner = StructArray::try_new(
+            ["x".into()].into(),
+            vec![ConstantArray::new(1, 3).into_array()],
+            3,
+            Validity::AllValid,
+        )
+        .unwrap();
+        // The validity of the batch doesn't apply to its columns
+        let st = StructArray::try_new(
+            ["b".into()].into(),
+            vec![inner.into_array()],
+            3,
+            Validity::from(vec![true, false, true]),
+        )
+        .unwrap();
+
+        let column = Column::new_nested(FieldPath::from(vec![Field::from("b"), Field::from("x")]));
+        let values = column.evaluate(st.as_ref()).unwrap();
+        assert_eq!(values.encoding().id(), Constant::ID);
+    }
+}
@@ -2,12 +2,15 @@
 
 use std::sync::Arc;
 
+use datafusion_common::ScalarValue;
 use datafusion_expr::Operator as DFOperator;
-use datafusion_physical_expr::{expressions, PhysicalExpr};
+use datafusion_physical_expr::{expressions, PhysicalExpr, ScalarFunctionExpr};
+use vortex::compute::LikeOptions;
+use vortex_dtype::field::{Field, FieldPath};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_scalar::Scalar;
 
-use crate::{BinaryExpr, Column, Literal, Operator, VortexExpr};
+use crate::{BinaryExpr, Column, InList, IsNull, Like, Literal, Not, Operator, VortexExpr};
 
 pub fn convert_expr_to_vortex(
     physical_expr: Arc<dyn PhysicalExpr>,
@@ -16,13 +19,28 @@ pub fn convert_expr_to_vortex(
         .as_any()
         .downcast_ref::<expressions::BinaryExpr>()
     {
+        let operator = *binary_expr.op();
+        if let Some(options) = like_options(operator) {
+            let child = convert_expr_to_vortex(binary_expr.left().clone())?;
+            let pattern = like_pattern(binary_expr.right())?;
+            return Ok(Arc::new(Like::new(child, pattern, options)) as _);
+        }
+
         let left = convert_expr_to_vortex(binary_expr.left().clone())?;
         let right = convert_expr_to_vortex(binary_expr.right().clone())?;
-        let operator = *binary_expr.op();
 
         return Ok(Arc::new(BinaryExpr::new(left, operator.try_into()?, right)) as _);
     }
 
+    if let Some(get_field) = physical_expr
+        .as_any()
+        .downcast_ref::<ScalarFunctionExpr>()
+        .filter(|f| f.name() == "get_field")
+    {
+        let path = nested_field_path(get_field)?;
+        return Ok(Arc::new(Column::new_nested(path)) as _);
+    }
+
     if let Some(col_expr) = physical_expr.as_any().downcast_ref::<expressions::Column>() {
         let expr = Column::from(col_expr.name().to_owned());
 
@@ -37,9 +55,137 @@ pub fn convert_expr_to_vortex(
         return Ok(Arc::new(Literal::new(value)) as _);
     }
 
+    if let Some(is_null) = physical_expr
+        .as_any()
+        .downcast_ref::<expressions::IsNullExpr>()
+    {
+        let child = convert_expr_to_vortex(is_null.arg().clone())?;
+        return Ok(Arc::new(IsNull::new(child)) as _);
+    }
+
+    if let Some(is_not_null) = physical_expr
+        .as_any()
+        .downcast_ref::<expressions::IsNotNullExpr>()
+    {
+        let child = convert_expr_to_vortex(is_not_null.arg().clone())?;
+        return Ok(Arc::new(Not::new(Arc::new(IsNull::new(child)))) as _);
+    }
+
+    if let Some(not) = physical_expr
+        .as_any()
+        .downcast_ref::<expressions::NotExpr>()
+    {
+        let child = convert_expr_to_vortex(not.arg().clone())?;
+        return Ok(Arc::new(Not::new(child)) as _);
+    }
+
+    if let Some(in_list) = physical_expr
+        .as_any()
+        .downcast_ref::<expressions::InListExpr>()
+    {
+        let child = convert_expr_to_vortex(in_list.expr().clone())?;
+        let values = in_list
+            .list()
+            .iter()
+            .map(|value| {
+                value
+                    .as_any()
+                    .downcast_ref::<expressions::Literal>()
+                    .map(|lit| Scalar::from(lit.value().clone()))
+                    .ok_or_else(|| {
+                        vortex_err!("IN lists can only be converted with literal values")
+                    })
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+        let expr = Arc::new(InList::new(child, values)) as _;
+        return Ok(if in_list.negated() {
+            Arc::new(Not::new(expr)) as _
+        } else {
+            expr
+        });
+    }
+
+    if let Some(like) = physical_expr
+        .as_any()
+        .downcast_ref::<expressions::LikeExpr>()
+    {
+        let child = convert_expr_to_vortex(like.expr().clone())?;
+        let pattern = like_pattern(like.pattern())?;
+        let options = LikeOptions {
+            negated: like.negated(),
+            case_insensitive: like.case_insensitive(),
+            regex: false,
+        };
+        return Ok(Arc::new(Like::new(child, pattern, options)) as _);
+    }
+
     vortex_bail!("Couldn't convert DataFusion physical expression to a vortex expression")
 }
 
+/// The variant of `LIKE` or of a regular expression match expressed by a binary operator, if it is
+/// one
+fn like_options(operator: DFOperator) -> Option<LikeOptions> {
+    let (negated, case_insensitive, regex) = match operator {
+        DFOperator::LikeMatch => (false, false, false),
+        DFOperator::ILikeMatch => (false, true, false),
+        DFOperator::NotLikeMatch => (true, false, false),
+        DFOperator::NotILikeMatch => (true, true, false),
+        DFOperator::RegexMatch => (false, false, true),
+        DFOperator::RegexIMatch => (false, true, true),
+        DFOperator::RegexNotMatch => (true, false, true),
+        DFOperator::RegexNotIMatch => (true, true, true),
+        _ => return None,
+    };
+    Some(LikeOptions {
+        negated,
+        case_insensitive,
+        regex,
+    })
+}
+
+fn like_pattern(pattern: &Arc<dyn PhysicalExpr>) -> VortexResult<String> {
+    match pattern
+        .as_any()
+        .downcast_ref::<expressions::Literal>()
+        .map(|lit| lit.value())
+    {
+        Some(
+            ScalarValue::Utf8(Some(pattern))
+            | ScalarValue::LargeUtf8(Some(pattern))
+            | ScalarValue::Utf8View(Some(pattern)),
+        ) => Ok(pattern.clone()),
+        _ => vortex_bail!("LIKE and regex patterns can only be converted from string literals"),
+    }
+}
+
+/// Resolve a chain of `get_field` calls on a column into the path of the nested field
+fn nested_field_path(get_field: &ScalarFunctionExpr) -> VortexResult<FieldPath> {
+    let [source, name] = get_field.args() else {
+        vortex_bail!("get_field expects two arguments")
+    };
+    let Some(ScalarValue::Utf8(Some(name)) | ScalarValue::LargeUtf8(Some(name))) = name
+        .as_any()
+        .downcast_ref::<expressions::Literal>()
+        .map(|lit| lit.value())
+    else {
+        vortex_bail!("Only named struct fields can be referenced")
+    };
+
+    let mut path = if let Some(col_expr) = source.as_any().downcast_ref::<expressions::Column>() {
+        vec![Field::from(col_expr.name())]
+    } else if let Some(inner) = source
+        .as_any()
+        .downcast_ref::<ScalarFunctionExpr>()
+        .filter(|f| f.name() == "get_field")
+    {
+        nested_field_path(inner)?.path().to_vec()
+    } else {
+        vortex_bail!("get_field can only be converted on columns")
+    };
+    path.push(Field::from(name.as_str()));
+    Ok(FieldPath::from(path))
+}
+
 impl TryFrom<DFOperator> for Operator {
     type Error = VortexError;
 
@@ -53,6 +199,11 @@ impl TryFrom<DFOperator> for Operator {
             DFOperator::GtEq => Ok(Operator::Gte),
             DFOperator::And => Ok(Operator::And),
             DFOperator::Or => Ok(Operator::Or),
+            DFOperator::Plus => Ok(Operator::Add),
+            DFOperator::Minus => Ok(Operator::Sub),
+            DFOperator::Multiply => Ok(Operator::Mul),
+            DFOperator::Divide => Ok(Operator::Div),
+            DFOperator::Modulo => Ok(Operator::Rem),
             DFOperator::IsDistinctFrom
             | DFOperator::IsNotDistinctFrom
             | DFOperator::RegexMatch
@@ -70,12 +221,7 @@ impl TryFrom<DFOperator> for Operator {
             | DFOperator::BitwiseShiftLeft
             | DFOperator::StringConcat
             | DFOperator::AtArrow
-            | DFOperator::ArrowAt
-            | DFOperator::Plus
-            | DFOperator::Minus
-            | DFOperator::Multiply
-            | DFOperator::Divide
-            | DFOperator::Modulo => Err(vortex_err!("Unsupported datafusion operator {value}")),
+            | DFOperator::ArrowAt => Err(vortex_err!("Unsupported datafusion operator {value}")),
         }
     }
 }
@@ -0,0 +1,144 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use vortex::aliases::hash_set::HashSet;
+use vortex::array::{BoolArray, ConstantArray};
+use vortex::compute::{compare, or, Operator};
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::{Array, ArrayDType, IntoArray};
+use vortex_dtype::field::Field;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::{unbox_any, VortexExpr};
+
+/// True for the rows where the child expression equals any of the values, null where the child
+/// is null.
+#[derive(Debug, Clone)]
+pub struct InList {
+    child: Arc<dyn VortexExpr>,
+    values: Vec<Scalar>,
+}
+
+impl InList {
+    pub fn new(child: Arc<dyn VortexExpr>, values: Vec<Scalar>) -> Self {
+        Self { child, values }
+    }
+
+    pub fn child(&self) -> &Arc<dyn VortexExpr> {
+        &self.child
+    }
+
+    pub fn values(&self) -> &[Scalar] {
+        &self.values
+    }
+}
+
+impl VortexExpr for InList {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn evaluate(&self, batch: &Array) -> VortexResult<Array> {
+        let array = self.child.evaluate(batch)?;
+        let len = array.len();
+
+        // Values outside of the known bounds of the array can't match any row
+        let min = array.statistics().get(Stat::Min);
+        let max = array.statistics().get(Stat::Max);
+        let candidates = self.values.iter().filter(|value| {
+            !(min.as_ref().is_some_and(|min| *value < min)
+                || max.as_ref().is_some_and(|max| *value > max))
+        });
+
+        let mut matches = None;
+        for value in candidates {
+            let eq = compare(&array, ConstantArray::new(value.clone(), len), Operator::Eq)?;
+            matches = Some(match matches {
+                None => eq,
+                Some(matches) => or(matches, eq)?,
+            });
+        }
+
+        match matches {
+            Some(matches) => Ok(matches),
+            None if !array.dtype().is_nullable() => Ok(ConstantArray::new(false, len).into_array()),
+            None => Ok(BoolArray::from_vec(
+                vec![false; len],
+                array.with_dyn(|a| a.logical_validity()).into_validity(),
+            )
+            .into_array()),
+        }
+    }
+
+    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a [Field]>) {
+        self.child.collect_references(references);
+    }
+}
+
+impl PartialEq<dyn Any> for InList {
+    fn eq(&self, other: &dyn Any) -> bool {
+        unbox_any(other)
+            .downcast_ref::<Self>()
+            .map(|x| x.values == self.values && x.child.eq(&self.child))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vortex::array::{PrimitiveArray, StructArray};
+    use vortex::validity::ArrayValidity;
+    use vortex::{IntoArray, IntoArrayVariant};
+
+    use crate::{Column, InList, Not, VortexExpr};
+
+    #[test]
+    fn evaluate_in_list() {
+        let st = StructArray::from_fields(&[(
+            "a",
+            PrimitiveArray::from_nullable_vec(vec![Some(1), None, Some(3), Some(4)]).into_array(),
+        )])
+        .unwrap();
+
+        let in_list = Arc::new(InList::new(
+            Arc::new(Column::from("a".to_string())),
+            vec![3.into(), 1.into(), 10.into()],
+        ));
+        let result = in_list.evaluate(st.as_ref()).unwrap().into_bool().unwrap();
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false, true, false]
+        );
+        assert!(!result.is_valid(1));
+
+        let not_in_list = Not::new(in_list);
+        let result = not_in_list
+            .evaluate(st.as_ref())
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert!(!result.is_valid(1));
+        assert!(result.boolean_buffer().value(3));
+    }
+
+    #[test]
+    fn values_outside_of_stats() {
+        let st = StructArray::from_fields(&[(
+            "a",
+            PrimitiveArray::from_nullable_vec(vec![Some(1), None, Some(3)]).into_array(),
+        )])
+        .unwrap();
+
+        let in_list = InList::new(
+            Arc::new(Column::from("a".to_string())),
+            vec![10.into(), 20.into()],
+        );
+        let result = in_list.evaluate(st.as_ref()).unwrap().into_bool().unwrap();
+        assert_eq!(result.boolean_buffer().count_set_bits(), 0);
+        assert!(result.is_valid(0));
+        assert!(!result.is_valid(1));
+    }
+}
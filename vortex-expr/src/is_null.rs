@@ -0,0 +1,108 @@
+use std::any::Any;
+use std::ops::Not as _;
+use std::sync::Arc;
+
+use vortex::aliases::hash_set::HashSet;
+use vortex::array::{BoolArray, ConstantArray};
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::validity::{LogicalValidity, Validity};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::field::Field;
+use vortex_error::VortexResult;
+
+use crate::{unbox_any, VortexExpr};
+
+/// True for the rows where the child expression evaluates to null.
+#[derive(Debug, Clone)]
+pub struct IsNull {
+    child: Arc<dyn VortexExpr>,
+}
+
+impl IsNull {
+    pub fn new(child: Arc<dyn VortexExpr>) -> Self {
+        Self { child }
+    }
+
+    pub fn child(&self) -> &Arc<dyn VortexExpr> {
+        &self.child
+    }
+}
+
+impl VortexExpr for IsNull {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn evaluate(&self, batch: &Array) -> VortexResult<Array> {
+        let array = self.child.evaluate(batch)?;
+        let len = array.len();
+        if !array.dtype().is_nullable() {
+            return Ok(ConstantArray::new(false, len).into_array());
+        }
+        match array.statistics().get_as::<u64>(Stat::NullCount) {
+            Some(0) => return Ok(ConstantArray::new(false, len).into_array()),
+            Some(n) if n == len as u64 => return Ok(ConstantArray::new(true, len).into_array()),
+            _ => {}
+        }
+
+        Ok(match array.with_dyn(|a| a.logical_validity()) {
+            LogicalValidity::AllValid(len) => ConstantArray::new(false, len).into_array(),
+            LogicalValidity::AllInvalid(len) => ConstantArray::new(true, len).into_array(),
+            LogicalValidity::Array(validity) => BoolArray::try_new(
+                validity.into_bool()?.boolean_buffer().not(),
+                Validity::NonNullable,
+            )?
+            .into_array(),
+        })
+    }
+
+    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a [Field]>) {
+        self.child.collect_references(references);
+    }
+}
+
+impl PartialEq<dyn Any> for IsNull {
+    fn eq(&self, other: &dyn Any) -> bool {
+        unbox_any(other)
+            .downcast_ref::<Self>()
+            .map(|x| x.child.eq(&self.child))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vortex::array::{PrimitiveArray, StructArray};
+    use vortex::{IntoArray, IntoArrayVariant};
+
+    use crate::{Column, IsNull, VortexExpr};
+
+    #[test]
+    fn evaluate_is_null() {
+        let st = StructArray::from_fields(&[
+            (
+                "a",
+                PrimitiveArray::from_nullable_vec(vec![Some(1), None, Some(3)]).into_array(),
+            ),
+            ("b", PrimitiveArray::from(vec![1, 2, 3]).into_array()),
+        ])
+        .unwrap();
+
+        let is_null = IsNull::new(Arc::new(Column::from("a".to_string())));
+        let result = is_null.evaluate(st.as_ref()).unwrap().into_bool().unwrap();
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, false]
+        );
+
+        let non_nullable = IsNull::new(Arc::new(Column::from("b".to_string())));
+        let result = non_nullable
+            .evaluate(st.as_ref())
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(result.boolean_buffer().count_set_bits(), 0);
+    }
+}
@@ -8,14 +8,22 @@ mod binary;
 mod column;
 pub mod datafusion;
 mod identity;
+mod in_list;
+mod is_null;
+mod like;
 mod literal;
+mod not;
 mod operators;
 mod select;
 
 pub use binary::*;
 pub use column::*;
 pub use identity::*;
+pub use in_list::*;
+pub use is_null::*;
+pub use like::*;
 pub use literal::*;
+pub use not::*;
 pub use operators::*;
 pub use select::*;
 use vortex::Array;
@@ -31,10 +39,12 @@ pub trait VortexExpr: Debug + Send + Sync + PartialEq<dyn Any> {
     fn evaluate(&self, batch: &Array) -> VortexResult<Array>;
 
     /// Accumulate all field references from this expression and its children in the provided set
-    fn collect_references<'a>(&'a self, _references: &mut HashSet<&'a Field>) {}
+    ///
+    /// References are paths of fields, starting with the top level column that contains them.
+    fn collect_references<'a>(&'a self, _references: &mut HashSet<&'a [Field]>) {}
 
     /// Accumulate all field references from this expression and its children in a new set
-    fn references(&self) -> HashSet<&Field> {
+    fn references(&self) -> HashSet<&[Field]> {
         let mut refs = HashSet::new();
         self.collect_references(&mut refs);
         refs
@@ -0,0 +1,108 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use vortex::aliases::hash_set::HashSet;
+use vortex::compute::{like, LikeOptions};
+use vortex::Array;
+use vortex_dtype::field::Field;
+use vortex_error::VortexResult;
+
+use crate::{unbox_any, VortexExpr};
+
+/// Matches the utf8 child expression against a SQL `LIKE` pattern or a regular expression, nulls
+/// stay null.
+#[derive(Debug, Clone)]
+pub struct Like {
+    child: Arc<dyn VortexExpr>,
+    pattern: String,
+    options: LikeOptions,
+}
+
+impl Like {
+    pub fn new(child: Arc<dyn VortexExpr>, pattern: String, options: LikeOptions) -> Self {
+        Self {
+            child,
+            pattern,
+            options,
+        }
+    }
+
+    pub fn child(&self) -> &Arc<dyn VortexExpr> {
+        &self.child
+    }
+
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    pub fn options(&self) -> LikeOptions {
+        self.options
+    }
+}
+
+impl VortexExpr for Like {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn evaluate(&self, batch: &Array) -> VortexResult<Array> {
+        like(self.child.evaluate(batch)?, &self.pattern, self.options)
+    }
+
+    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a [Field]>) {
+        self.child.collect_references(references);
+    }
+}
+
+impl PartialEq<dyn Any> for Like {
+    fn eq(&self, other: &dyn Any) -> bool {
+        unbox_any(other)
+            .downcast_ref::<Self>()
+            .map(|x| {
+                x.child.eq(&self.child) && x.pattern == self.pattern && x.options == self.options
+            })
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vortex::array::{StructArray, VarBinViewArray};
+    use vortex::compute::LikeOptions;
+    use vortex::validity::ArrayValidity;
+    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::{Column, Like, VortexExpr};
+
+    #[test]
+    fn evaluate_like() {
+        let st = StructArray::from_fields(&[(
+            "a",
+            VarBinViewArray::from_iter(
+                [Some("Vortex"), None, Some("vortices"), Some("arrow")],
+                DType::Utf8(Nullability::Nullable),
+            )
+            .into_array(),
+        )])
+        .unwrap();
+
+        let like = Like::new(
+            Arc::new(Column::from("a".to_string())),
+            "vort%".to_string(),
+            LikeOptions {
+                negated: false,
+                case_insensitive: true,
+                regex: false,
+            },
+        );
+        let result = like.evaluate(st.as_ref()).unwrap().into_bool().unwrap();
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false, true, false]
+        );
+        assert!(!result.is_valid(1));
+    }
+}
@@ -0,0 +1,82 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use vortex::aliases::hash_set::HashSet;
+use vortex::array::ConstantArray;
+use vortex::variants::BoolArrayTrait;
+use vortex::{Array, IntoArrayVariant};
+use vortex_dtype::field::Field;
+use vortex_error::VortexResult;
+
+use crate::{unbox_any, VortexExpr};
+
+/// Boolean negation of the child expression, nulls stay null.
+#[derive(Debug, Clone)]
+pub struct Not {
+    child: Arc<dyn VortexExpr>,
+}
+
+impl Not {
+    pub fn new(child: Arc<dyn VortexExpr>) -> Self {
+        Self { child }
+    }
+
+    pub fn child(&self) -> &Arc<dyn VortexExpr> {
+        &self.child
+    }
+}
+
+impl VortexExpr for Not {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn evaluate(&self, batch: &Array) -> VortexResult<Array> {
+        let array = self.child.evaluate(batch)?;
+        if let Ok(constant) = ConstantArray::try_from(&array) {
+            return constant.invert();
+        }
+        array.into_bool()?.invert()
+    }
+
+    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a [Field]>) {
+        self.child.collect_references(references);
+    }
+}
+
+impl PartialEq<dyn Any> for Not {
+    fn eq(&self, other: &dyn Any) -> bool {
+        unbox_any(other)
+            .downcast_ref::<Self>()
+            .map(|x| x.child.eq(&self.child))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vortex::array::{BoolArray, StructArray};
+    use vortex::validity::ArrayValidity;
+    use vortex::{IntoArray, IntoArrayVariant};
+
+    use crate::{Column, Not, VortexExpr};
+
+    #[test]
+    fn evaluate_not() {
+        let st = StructArray::from_fields(&[(
+            "a",
+            BoolArray::from_iter([Some(true), None, Some(false)]).into_array(),
+        )])
+        .unwrap();
+
+        let not = Not::new(Arc::new(Column::from("a".to_string())));
+        let result = not.evaluate(st.as_ref()).unwrap().into_bool().unwrap();
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, true]
+        );
+        assert!(!result.is_valid(1));
+    }
+}
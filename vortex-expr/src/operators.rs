@@ -14,6 +14,12 @@ pub enum Operator {
     // boolean algebra
     And,
     Or,
+    // arithmetic
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
 }
 
 impl Display for Operator {
@@ -27,6 +33,11 @@ impl Display for Operator {
             Operator::Lte => "<=",
             Operator::And => "and",
             Operator::Or => "or",
+            Operator::Add => "+",
+            Operator::Sub => "-",
+            Operator::Mul => "*",
+            Operator::Div => "/",
+            Operator::Rem => "%",
         };
         Display::fmt(display, f)
     }
@@ -41,21 +52,59 @@ impl Operator {
             Operator::Gte => Some(Operator::Lt),
             Operator::Lt => Some(Operator::Gte),
             Operator::Lte => Some(Operator::Gt),
-            Operator::And | Operator::Or => None,
+            Operator::And
+            | Operator::Or
+            | Operator::Add
+            | Operator::Sub
+            | Operator::Mul
+            | Operator::Div
+            | Operator::Rem => None,
         }
     }
 
-    /// Change the sides of the operator, where changing lhs and rhs won't change the result of the operation
-    pub fn swap(self) -> Self {
+    /// Change the sides of the operator, where changing lhs and rhs won't change the result of the operation.
+    ///
+    /// Returns `None` for operators that can't swap their operands, like subtraction.
+    pub fn swap(self) -> Option<Self> {
         match self {
-            Operator::Eq => Operator::Eq,
-            Operator::NotEq => Operator::NotEq,
-            Operator::Gt => Operator::Lt,
-            Operator::Gte => Operator::Lte,
-            Operator::Lt => Operator::Gt,
-            Operator::Lte => Operator::Gte,
-            Operator::And => Operator::And,
-            Operator::Or => Operator::Or,
+            Operator::Eq => Some(Operator::Eq),
+            Operator::NotEq => Some(Operator::NotEq),
+            Operator::Gt => Some(Operator::Lt),
+            Operator::Gte => Some(Operator::Lte),
+            Operator::Lt => Some(Operator::Gt),
+            Operator::Lte => Some(Operator::Gte),
+            Operator::And => Some(Operator::And),
+            Operator::Or => Some(Operator::Or),
+            Operator::Add => Some(Operator::Add),
+            Operator::Mul => Some(Operator::Mul),
+            Operator::Sub | Operator::Div | Operator::Rem => None,
         }
     }
+
+    /// Whether the operator computes a numeric value from its operands
+    pub fn is_arithmetic(self) -> bool {
+        matches!(
+            self,
+            Operator::Add | Operator::Sub | Operator::Mul | Operator::Div | Operator::Rem
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Operator;
+
+    #[test]
+    fn swap_operands() {
+        assert_eq!(Operator::Gt.swap(), Some(Operator::Lt));
+        assert_eq!(Operator::And.swap(), Some(Operator::And));
+        assert_eq!(Operator::Mul.swap(), Some(Operator::Mul));
+    }
+
+    #[test]
+    fn swap_non_commutative() {
+        assert_eq!(Operator::Sub.swap(), None);
+        assert_eq!(Operator::Div.swap(), None);
+        assert_eq!(Operator::Rem.swap(), None);
+    }
 }
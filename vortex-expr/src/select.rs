@@ -1,4 +1,5 @@
 use std::any::Any;
+use std::slice;
 
 use vortex::aliases::hash_set::HashSet;
 use vortex::Array;
@@ -59,11 +60,11 @@ impl VortexExpr for Select {
         })
     }
 
-    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a Field>) {
+    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a [Field]>) {
         match self {
-            Select::Include(f) => references.extend(f.iter()),
+            Select::Include(f) => references.extend(f.iter().map(slice::from_ref)),
             // It's weird that we treat the references of exclusions and inclusions the same, we need to have a wrapper around Field in the return
-            Select::Exclude(e) => references.extend(e.iter()),
+            Select::Exclude(e) => references.extend(e.iter().map(slice::from_ref)),
         }
     }
 }
@@ -96,11 +96,13 @@ impl flatbuffers::SimpleToVerifyInSlice for MessageVersion {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_COMPRESSION: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_COMPRESSION: u8 = 0;
+pub const ENUM_MAX_COMPRESSION: u8 = 2;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_COMPRESSION: [Compression; 1] = [
+pub const ENUM_VALUES_COMPRESSION: [Compression; 3] = [
   Compression::None,
+  Compression::LZ4,
+  Compression::ZSTD,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -109,16 +111,22 @@ pub struct Compression(pub u8);
 #[allow(non_upper_case_globals)]
 impl Compression {
   pub const None: Self = Self(0);
+  pub const LZ4: Self = Self(1);
+  pub const ZSTD: Self = Self(2);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 0;
+  pub const ENUM_MAX: u8 = 2;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::None,
+    Self::LZ4,
+    Self::ZSTD,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
     match self {
       Self::None => Some("None"),
+      Self::LZ4 => Some("LZ4"),
+      Self::ZSTD => Some("ZSTD"),
       _ => None,
     }
   }
@@ -284,6 +292,7 @@ impl core::fmt::Debug for Buffer {
       .field("offset", &self.offset())
       .field("padding", &self.padding())
       .field("compression", &self.compression())
+      .field("uncompressed_length", &self.uncompressed_length())
       .finish()
   }
 }
@@ -328,11 +337,13 @@ impl<'a> Buffer {
     offset: u64,
     padding: u16,
     compression: Compression,
+    uncompressed_length: u32,
   ) -> Self {
     let mut s = Self([0; 16]);
     s.set_offset(offset);
     s.set_padding(padding);
     s.set_compression(compression);
+    s.set_uncompressed_length(uncompressed_length);
     s
   }
 
@@ -423,6 +434,35 @@ impl<'a> Buffer {
     }
   }
 
+  pub fn uncompressed_length(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[12..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_uncompressed_length(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[12..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
 }
 
 pub enum SchemaOffset {}
@@ -0,0 +1,60 @@
+use vortex::aliases::hash_set::HashSet;
+use vortex::array::{Extension, ExtensionArray, TemporalArray};
+use vortex::encoding::EncodingRef;
+use vortex::variants::ExtensionArrayTrait;
+use vortex::{Array, ArrayDef, IntoArray};
+use vortex_error::VortexResult;
+
+use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
+use crate::SamplingCompressor;
+
+/// Compresses extension arrays by compressing their storage array.
+///
+/// The storage of e.g. decimals (unscaled integers) and intervals (month, day and sub-day
+/// components) is made of integer columns that the other compressors handle well. Other temporal
+/// arrays are not handled here, timestamps and durations are left to the
+/// [`DateTimePartsCompressor`](crate::compressors::date_time_parts::DateTimePartsCompressor),
+/// which splits them up first.
+#[derive(Debug)]
+pub struct ExtensionCompressor;
+
+impl EncodingCompressor for ExtensionCompressor {
+    fn id(&self) -> &str {
+        Extension::ID.as_ref()
+    }
+
+    fn cost(&self) -> u8 {
+        0
+    }
+
+    fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
+        if !array.is_encoding(Extension::ID)
+            || TemporalArray::try_from(array)
+                .is_ok_and(|t| t.temporal_metadata().interval_unit().is_none())
+        {
+            return None;
+        }
+        Some(self)
+    }
+
+    fn compress<'a>(
+        &'a self,
+        array: &Array,
+        like: Option<CompressionTree<'a>>,
+        ctx: SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let ext_array = ExtensionArray::try_from(array)?;
+        let storage = ctx
+            .named("storage")
+            .compress(&ext_array.storage(), like.as_ref().and_then(|l| l.child(0)))?;
+
+        Ok(CompressedArray::new(
+            ExtensionArray::new(ext_array.ext_dtype().clone(), storage.array).into_array(),
+            Some(CompressionTree::new(self, vec![storage.path])),
+        ))
+    }
+
+    fn used_encodings(&self) -> HashSet<EncodingRef> {
+        HashSet::from([])
+    }
+}
@@ -18,6 +18,7 @@ pub mod constant;
 pub mod date_time_parts;
 pub mod delta;
 pub mod dict;
+pub mod extension;
 pub mod r#for;
 pub mod fsst;
 pub mod roaring_bool;
@@ -32,6 +32,7 @@ use crate::compressors::alp::ALPCompressor;
 use crate::compressors::constant::ConstantCompressor;
 use crate::compressors::date_time_parts::DateTimePartsCompressor;
 use crate::compressors::dict::DictCompressor;
+use crate::compressors::extension::ExtensionCompressor;
 use crate::compressors::r#for::FoRCompressor;
 use crate::compressors::runend::DEFAULT_RUN_END_COMPRESSOR;
 use crate::compressors::sparse::SparseCompressor;
@@ -46,13 +47,14 @@ mod constants;
 mod sampling;
 
 lazy_static! {
-    pub static ref DEFAULT_COMPRESSORS: [CompressorRef<'static>; 9] = [
+    pub static ref DEFAULT_COMPRESSORS: [CompressorRef<'static>; 10] = [
         &ALPCompressor as CompressorRef,
         &BITPACK_WITH_PATCHES,
         &DateTimePartsCompressor,
         &DEFAULT_RUN_END_COMPRESSOR,
         // &DeltaCompressor,
         &DictCompressor,
+        &ExtensionCompressor,
         &FoRCompressor,
         &FSSTCompressor,
         // &RoaringBoolCompressor,
@@ -61,11 +63,12 @@ lazy_static! {
         &ZigZagCompressor,
     ];
 
-    pub static ref FASTEST_COMPRESSORS: [CompressorRef<'static>; 7] = [
+    pub static ref FASTEST_COMPRESSORS: [CompressorRef<'static>; 8] = [
         &BITPACK_WITH_PATCHES,
         &DateTimePartsCompressor,
         &DEFAULT_RUN_END_COMPRESSOR, // replace with FastLanes RLE
         &DictCompressor, // replace with FastLanes Dictionary
+        &ExtensionCompressor,
         &FoRCompressor,
         &SparseCompressor,
         &ZigZagCompressor,
@@ -3,13 +3,14 @@ use std::ops::Add;
 use chrono::TimeDelta;
 use vortex::aliases::hash_set::HashSet;
 use vortex::array::builder::VarBinBuilder;
-use vortex::array::{BoolArray, PrimitiveArray, StructArray, TemporalArray};
+use vortex::array::{BoolArray, DecimalArray, PrimitiveArray, StructArray, TemporalArray};
 use vortex::validity::Validity;
 use vortex::{Array, ArrayDType, IntoArray};
-use vortex_dtype::{DType, FieldName, FieldNames, Nullability};
+use vortex_dtype::{DType, DecimalMetadata, FieldName, FieldNames, Nullability};
 use vortex_sampling_compressor::compressors::alp::ALPCompressor;
 use vortex_sampling_compressor::compressors::date_time_parts::DateTimePartsCompressor;
 use vortex_sampling_compressor::compressors::dict::DictCompressor;
+use vortex_sampling_compressor::compressors::extension::ExtensionCompressor;
 use vortex_sampling_compressor::compressors::r#for::FoRCompressor;
 use vortex_sampling_compressor::compressors::roaring_bool::RoaringBoolCompressor;
 use vortex_sampling_compressor::compressors::roaring_int::RoaringIntCompressor;
@@ -24,7 +25,7 @@ mod tests {
     use vortex::array::{Bool, ChunkedArray, VarBin};
     use vortex::variants::{ArrayVariants, StructArrayTrait};
     use vortex::ArrayDef;
-    use vortex_datetime_dtype::TimeUnit;
+    use vortex_datetime_dtype::{IntervalUnit, TimeUnit};
     use vortex_datetime_parts::DateTimeParts;
     use vortex_dict::Dict;
     use vortex_fastlanes::FoR;
@@ -47,6 +48,7 @@ mod tests {
                 &DateTimePartsCompressor,
                 &DeltaCompressor,
                 &DictCompressor,
+                &ExtensionCompressor,
                 &FoRCompressor,
                 &FSSTCompressor,
                 &RoaringBoolCompressor,
@@ -64,6 +66,9 @@ mod tests {
             ("varbin_col", make_string_column(65536)),
             ("binary_col", make_binary_column(65536)),
             ("timestamp_col", make_timestamp_column(65536)),
+            ("decimal_col", make_decimal_column(65536)),
+            ("duration_col", make_duration_column(65536)),
+            ("interval_col", make_interval_column(65536)),
         ];
 
         let fields: Vec<Array> = def.iter().map(|(_, arr)| arr.clone()).collect();
@@ -100,6 +105,9 @@ mod tests {
         let varbins: Vec<Array> = (0..4).map(|_| make_string_column(chunk_size)).collect();
         let binaries: Vec<Array> = (0..4).map(|_| make_binary_column(chunk_size)).collect();
         let timestamps: Vec<Array> = (0..4).map(|_| make_timestamp_column(chunk_size)).collect();
+        let decimals: Vec<Array> = (0..4).map(|_| make_decimal_column(chunk_size)).collect();
+        let durations: Vec<Array> = (0..4).map(|_| make_duration_column(chunk_size)).collect();
+        let intervals: Vec<Array> = (0..4).map(|_| make_interval_column(chunk_size)).collect();
 
         fn chunked(arrays: Vec<Array>) -> Array {
             let dtype = arrays[0].dtype().clone();
@@ -113,6 +121,9 @@ mod tests {
                 "varbin_col".into(),
                 "binary_col".into(),
                 "timestamp_col".into(),
+                "decimal_col".into(),
+                "duration_col".into(),
+                "interval_col".into(),
             ]
             .into(),
             vec![
@@ -121,6 +132,9 @@ mod tests {
                 chunked(varbins),
                 chunked(binaries),
                 chunked(timestamps),
+                chunked(decimals),
+                chunked(durations),
+                chunked(intervals),
             ],
             chunk_size * 4,
             Validity::NonNullable,
@@ -184,6 +198,38 @@ mod tests {
         for chunk in timestamp_col.chunks() {
             assert_eq!(chunk.encoding().id(), DateTimeParts::ID);
         }
+
+        let decimal_col: ChunkedArray = struct_array
+            .field_by_name("decimal_col")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        for chunk in decimal_col.chunks() {
+            assert!(DecimalArray::try_from(&chunk).is_ok());
+        }
+        // The most significant words are all zero, the least significant words are a sequence that
+        // bit-packs into 16 bits, i.e. far below the 16 bytes per value of the canonical storage.
+        assert!(decimal_col.as_ref().nbytes() < chunk_size * 4 * 4);
+
+        let duration_col: ChunkedArray = struct_array
+            .field_by_name("duration_col")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        for chunk in duration_col.chunks() {
+            assert_eq!(chunk.encoding().id(), DateTimeParts::ID);
+        }
+
+        let interval_col: ChunkedArray = struct_array
+            .field_by_name("interval_col")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        for chunk in interval_col.chunks() {
+            assert!(TemporalArray::try_from(&chunk).is_ok());
+        }
+        // Both components compress far below the 8 bytes per value of the canonical storage.
+        assert!(interval_col.as_ref().nbytes() < chunk_size * 4 * 2);
     }
 
     fn make_primitive_column(count: usize) -> Array {
@@ -223,6 +269,16 @@ mod tests {
             .into_array()
     }
 
+    fn make_decimal_column(count: usize) -> Array {
+        DecimalArray::from_i128(
+            (0..count).map(|i| 1_000_000 + i as i128),
+            Validity::NonNullable,
+            DecimalMetadata::try_new(30, 2).unwrap(),
+        )
+        .unwrap()
+        .into()
+    }
+
     fn make_timestamp_column(count: usize) -> Array {
         // Make new timestamps in incrementing order from EPOCH.
         let t0 = chrono::NaiveDateTime::default().and_utc();
@@ -240,4 +296,30 @@ mod tests {
             None,
         ))
     }
+
+    fn make_duration_column(count: usize) -> Array {
+        let durations: Vec<i64> = (0..count).map(|i| (i as i64 % 3_600) * 1_000).collect();
+        Array::from(TemporalArray::new_duration(
+            PrimitiveArray::from_vec(durations, Validity::NonNullable).into_array(),
+            TimeUnit::Ms,
+        ))
+    }
+
+    fn make_interval_column(count: usize) -> Array {
+        let storage = StructArray::try_new(
+            FieldNames::from(["days".into(), "milliseconds".into()]),
+            vec![
+                PrimitiveArray::from((0..count).map(|i| (i % 30) as i32).collect::<Vec<_>>())
+                    .into_array(),
+                PrimitiveArray::from(vec![0i32; count]).into_array(),
+            ],
+            count,
+            Validity::NonNullable,
+        )
+        .unwrap();
+        Array::from(TemporalArray::new_interval(
+            storage.into_array(),
+            IntervalUnit::DayTime,
+        ))
+    }
 }
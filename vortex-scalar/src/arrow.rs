@@ -1,11 +1,12 @@
 use std::sync::Arc;
 
 use arrow_array::*;
-use vortex_datetime_dtype::{is_temporal_ext_type, TemporalMetadata, TimeUnit};
-use vortex_dtype::{DType, PType};
+use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano};
+use vortex_datetime_dtype::{is_temporal_ext_type, IntervalUnit, TemporalMetadata, TimeUnit};
+use vortex_dtype::{is_decimal_ext_type, DType, PType, MAX_DECIMAL128_PRECISION};
 use vortex_error::{vortex_bail, VortexError};
 
-use crate::{PValue, Scalar};
+use crate::{interval_components, DecimalScalar, PValue, Scalar};
 
 macro_rules! value_to_arrow_scalar {
     ($V:expr, $AR:ty) => {
@@ -67,9 +68,28 @@ impl TryFrom<&Scalar> for Arc<dyn Datum> {
                 todo!("list scalar conversion")
             }
             DType::Extension(ext, _) => {
+                if is_decimal_ext_type(ext.id()) {
+                    let decimal = DecimalScalar::try_from(value)?;
+                    let metadata = decimal.metadata();
+                    return if metadata.precision() <= MAX_DECIMAL128_PRECISION {
+                        let array =
+                            Decimal128Array::from(vec![decimal.value().map(|v| v.as_i128())])
+                                .with_precision_and_scale(metadata.precision(), metadata.scale())?;
+                        Ok(Arc::new(arrow_array::Scalar::new(array)))
+                    } else {
+                        let array = Decimal256Array::from(vec![decimal.value()])
+                            .with_precision_and_scale(metadata.precision(), metadata.scale())?;
+                        Ok(Arc::new(arrow_array::Scalar::new(array)))
+                    };
+                }
+
                 if is_temporal_ext_type(ext.id()) {
                     let metadata = TemporalMetadata::try_from(ext)?;
-                    let pv = value.value.as_pvalue()?;
+                    // Intervals with several components aren't stored as a single primitive.
+                    let pv = match metadata {
+                        TemporalMetadata::Interval(_) => None,
+                        _ => value.value.as_pvalue()?,
+                    };
                     return match metadata {
                         TemporalMetadata::Time(u) => match u {
                             TimeUnit::Ns => value_to_arrow_scalar!(
@@ -122,6 +142,59 @@ impl TryFrom<&Scalar> for Arc<dyn Datum> {
                                 vortex_bail!("Unsupported TimeUnit {u} for {}", ext.id())
                             }
                         },
+                        TemporalMetadata::Duration(u) => match u {
+                            TimeUnit::Ns => value_to_arrow_scalar!(
+                                pv.and_then(|p| p.as_i64()),
+                                DurationNanosecondArray
+                            ),
+                            TimeUnit::Us => value_to_arrow_scalar!(
+                                pv.and_then(|p| p.as_i64()),
+                                DurationMicrosecondArray
+                            ),
+                            TimeUnit::Ms => value_to_arrow_scalar!(
+                                pv.and_then(|p| p.as_i64()),
+                                DurationMillisecondArray
+                            ),
+                            TimeUnit::S => value_to_arrow_scalar!(
+                                pv.and_then(|p| p.as_i64()),
+                                DurationSecondArray
+                            ),
+                            TimeUnit::D => {
+                                vortex_bail!("Unsupported TimeUnit {u} for {}", ext.id())
+                            }
+                        },
+                        TemporalMetadata::Interval(u) => {
+                            let components = interval_components(&value.value)?;
+                            match u {
+                                IntervalUnit::YearMonth => value_to_arrow_scalar!(
+                                    components.and_then(|c| match c.as_slice() {
+                                        [months] => Some(*months as i32),
+                                        _ => None,
+                                    }),
+                                    IntervalYearMonthArray
+                                ),
+                                IntervalUnit::DayTime => value_to_arrow_scalar!(
+                                    components.and_then(|c| match c.as_slice() {
+                                        [days, millis] => {
+                                            Some(IntervalDayTime::new(*days as i32, *millis as i32))
+                                        }
+                                        _ => None,
+                                    }),
+                                    IntervalDayTimeArray
+                                ),
+                                IntervalUnit::MonthDayNano => value_to_arrow_scalar!(
+                                    components.and_then(|c| match c.as_slice() {
+                                        [months, days, nanos] => Some(IntervalMonthDayNano::new(
+                                            *months as i32,
+                                            *days as i32,
+                                            *nanos
+                                        )),
+                                        _ => None,
+                                    }),
+                                    IntervalMonthDayNanoArray
+                                ),
+                            }
+                        }
                     };
                 }
 
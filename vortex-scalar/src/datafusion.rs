@@ -1,12 +1,15 @@
 #![cfg(feature = "datafusion")]
+use arrow_buffer::{i256, IntervalDayTime, IntervalMonthDayNano};
 use datafusion_common::ScalarValue;
 use vortex_buffer::Buffer;
 use vortex_datetime_dtype::arrow::make_temporal_ext_dtype;
-use vortex_datetime_dtype::{is_temporal_ext_type, TemporalMetadata, TimeUnit};
-use vortex_dtype::{DType, Nullability, PType};
-use vortex_error::VortexError;
+use vortex_datetime_dtype::{is_temporal_ext_type, IntervalUnit, TemporalMetadata, TimeUnit};
+use vortex_dtype::{
+    is_decimal_ext_type, DType, DecimalMetadata, Nullability, PType, MAX_DECIMAL128_PRECISION,
+};
+use vortex_error::{VortexError, VortexExpect as _};
 
-use crate::{PValue, Scalar};
+use crate::{interval_components, DecimalScalar, PValue, Scalar};
 
 impl TryFrom<Scalar> for ScalarValue {
     type Error = VortexError;
@@ -64,10 +67,33 @@ impl TryFrom<Scalar> for ScalarValue {
             DType::List(..) => {
                 todo!("list scalar conversion")
             }
-            DType::Extension(ext, _) => {
+            DType::Extension(ext, n) => {
+                if is_decimal_ext_type(ext.id()) {
+                    let dtype = DType::Extension(ext, n);
+                    let decimal = DecimalScalar::try_new(&dtype, &value.value)?;
+                    let metadata = decimal.metadata();
+                    return Ok(if metadata.precision() <= MAX_DECIMAL128_PRECISION {
+                        ScalarValue::Decimal128(
+                            decimal.value().map(|v| v.as_i128()),
+                            metadata.precision(),
+                            metadata.scale(),
+                        )
+                    } else {
+                        ScalarValue::Decimal256(
+                            decimal.value(),
+                            metadata.precision(),
+                            metadata.scale(),
+                        )
+                    });
+                }
+
                 if is_temporal_ext_type(ext.id()) {
                     let metadata = TemporalMetadata::try_from(&ext)?;
-                    let pv = value.value.as_pvalue()?;
+                    // Intervals with several components aren't stored as a single primitive.
+                    let pv = match metadata {
+                        TemporalMetadata::Interval(_) => None,
+                        _ => value.value.as_pvalue()?,
+                    };
                     return Ok(match metadata {
                         TemporalMetadata::Time(u) => match u {
                             TimeUnit::Ns => {
@@ -110,6 +136,59 @@ impl TryFrom<Scalar> for ScalarValue {
                                 unreachable!("Unsupported TimeUnit {u} for {}", ext.id())
                             }
                         },
+                        TemporalMetadata::Duration(u) => match u {
+                            TimeUnit::Ns => {
+                                ScalarValue::DurationNanosecond(pv.and_then(|p| p.as_i64()))
+                            }
+                            TimeUnit::Us => {
+                                ScalarValue::DurationMicrosecond(pv.and_then(|p| p.as_i64()))
+                            }
+                            TimeUnit::Ms => {
+                                ScalarValue::DurationMillisecond(pv.and_then(|p| p.as_i64()))
+                            }
+                            TimeUnit::S => ScalarValue::DurationSecond(pv.and_then(|p| p.as_i64())),
+                            TimeUnit::D => {
+                                unreachable!("Unsupported TimeUnit {u} for {}", ext.id())
+                            }
+                        },
+                        TemporalMetadata::Interval(u) => {
+                            let components = interval_components(&value.value)?;
+                            match u {
+                                IntervalUnit::YearMonth => {
+                                    ScalarValue::IntervalYearMonth(components.and_then(|c| {
+                                        match c.as_slice() {
+                                            [months] => Some(*months as i32),
+                                            _ => None,
+                                        }
+                                    }))
+                                }
+                                IntervalUnit::DayTime => {
+                                    ScalarValue::IntervalDayTime(components.and_then(|c| {
+                                        match c.as_slice() {
+                                            [days, millis] => Some(IntervalDayTime::new(
+                                                *days as i32,
+                                                *millis as i32,
+                                            )),
+                                            _ => None,
+                                        }
+                                    }))
+                                }
+                                IntervalUnit::MonthDayNano => {
+                                    ScalarValue::IntervalMonthDayNano(components.and_then(|c| {
+                                        match c.as_slice() {
+                                            [months, days, nanos] => {
+                                                Some(IntervalMonthDayNano::new(
+                                                    *months as i32,
+                                                    *days as i32,
+                                                    *nanos,
+                                                ))
+                                            }
+                                            _ => None,
+                                        }
+                                    }))
+                                }
+                            }
+                        }
                     });
                 }
 
@@ -159,15 +238,51 @@ impl From<ScalarValue> for Scalar {
             | ScalarValue::TimestampSecond(v, _)
             | ScalarValue::TimestampMillisecond(v, _)
             | ScalarValue::TimestampMicrosecond(v, _)
-            | ScalarValue::TimestampNanosecond(v, _) => v.map(|i| {
+            | ScalarValue::TimestampNanosecond(v, _)
+            | ScalarValue::DurationSecond(v)
+            | ScalarValue::DurationMillisecond(v)
+            | ScalarValue::DurationMicrosecond(v)
+            | ScalarValue::DurationNanosecond(v) => v.map(|i| {
                 let ext_dtype = make_temporal_ext_dtype(&value.data_type());
                 Scalar::new(
                     DType::Extension(ext_dtype, Nullability::Nullable),
                     crate::ScalarValue::Primitive(PValue::I64(i)),
                 )
             }),
+            ScalarValue::IntervalYearMonth(v) => {
+                v.map(|months| interval_scalar(IntervalUnit::YearMonth, &[months as i64]))
+            }
+            ScalarValue::IntervalDayTime(v) => v.map(|v| {
+                interval_scalar(
+                    IntervalUnit::DayTime,
+                    &[v.days as i64, v.milliseconds as i64],
+                )
+            }),
+            ScalarValue::IntervalMonthDayNano(v) => v.map(|v| {
+                interval_scalar(
+                    IntervalUnit::MonthDayNano,
+                    &[v.months as i64, v.days as i64, v.nanoseconds],
+                )
+            }),
+            ScalarValue::Decimal128(v, precision, scale) => {
+                v.map(|v| decimal_scalar(i256::from_i128(v), precision, scale))
+            }
+            ScalarValue::Decimal256(v, precision, scale) => {
+                v.map(|v| decimal_scalar(v, precision, scale))
+            }
             _ => unimplemented!("Can't convert {value:?} value to a Vortex scalar"),
         }
         .unwrap_or_else(|| Scalar::null(DType::Null))
     }
 }
+
+fn decimal_scalar(value: i256, precision: u8, scale: i8) -> Scalar {
+    DecimalMetadata::try_new(precision, scale)
+        .and_then(|metadata| Scalar::decimal(value, metadata, Nullability::Nullable))
+        .vortex_expect("DataFusion decimal must be a valid Vortex decimal")
+}
+
+fn interval_scalar(interval_unit: IntervalUnit, components: &[i64]) -> Scalar {
+    Scalar::interval(interval_unit, components, Nullability::Nullable)
+        .vortex_expect("DataFusion interval must be a valid Vortex interval")
+}
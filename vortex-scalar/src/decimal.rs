@@ -0,0 +1,305 @@
+use std::sync::Arc;
+
+use arrow_buffer::i256;
+use vortex_dtype::{DType, DecimalMetadata, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::value::ScalarValue;
+use crate::{PValue, Scalar};
+
+/// A scalar of the `vortex.decimal` extension type.
+///
+/// The value is the unscaled integer, i.e. `value * 10^-scale` is the logical decimal value.
+pub struct DecimalScalar<'a> {
+    dtype: &'a DType,
+    metadata: DecimalMetadata,
+    value: Option<i256>,
+}
+
+impl<'a> DecimalScalar<'a> {
+    pub fn try_new(dtype: &'a DType, value: &ScalarValue) -> VortexResult<Self> {
+        let metadata = DecimalMetadata::try_from(dtype)?;
+        let value = match value {
+            ScalarValue::Null => None,
+            ScalarValue::Primitive(p) => Some(i256::from_i128(i64::try_from(*p)? as i128)),
+            ScalarValue::List(words) => Some(decimal_from_words(
+                &words
+                    .iter()
+                    .map(|w| {
+                        w.as_pvalue()?
+                            .map(|p| p.reinterpret_cast(vortex_dtype::PType::U64))
+                            .ok_or_else(|| vortex_err!("Decimal storage words cannot be null"))
+                            .and_then(u64::try_from)
+                    })
+                    .collect::<VortexResult<Vec<_>>>()?,
+            )),
+            _ => vortex_bail!("Expected decimal storage value, found {:?}", value),
+        };
+        Ok(Self {
+            dtype,
+            metadata,
+            value,
+        })
+    }
+
+    #[inline]
+    pub fn dtype(&self) -> &'a DType {
+        self.dtype
+    }
+
+    #[inline]
+    pub fn metadata(&self) -> DecimalMetadata {
+        self.metadata
+    }
+
+    /// The unscaled integer value of the decimal.
+    pub fn value(&self) -> Option<i256> {
+        self.value
+    }
+
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        let target = DecimalMetadata::try_from(dtype)
+            .map_err(|_| vortex_err!("Can't cast {} scalar to {}", self.dtype, dtype))?;
+        match self.value {
+            None => Ok(Scalar::null(dtype.clone())),
+            Some(value) => Scalar::decimal(
+                rescale(value, self.metadata.scale(), target.scale())?,
+                target,
+                dtype.nullability(),
+            ),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Scalar> for DecimalScalar<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
+        DecimalScalar::try_new(value.dtype(), &value.value)
+    }
+}
+
+impl Scalar {
+    /// Create a decimal scalar from its unscaled integer value.
+    pub fn decimal(
+        value: i256,
+        metadata: DecimalMetadata,
+        nullability: Nullability,
+    ) -> VortexResult<Self> {
+        validate_precision(value, metadata.precision())?;
+        Ok(Self {
+            dtype: metadata.dtype(nullability),
+            value: decimal_scalar_value(value, metadata)?,
+        })
+    }
+}
+
+/// Encode an unscaled decimal value as the [`ScalarValue`] of its storage dtype.
+pub fn decimal_scalar_value(value: i256, metadata: DecimalMetadata) -> VortexResult<ScalarValue> {
+    let words = decimal_to_words(value, metadata.storage_words())?;
+    Ok(match words.as_slice() {
+        [w0] => ScalarValue::Primitive(PValue::I64(*w0 as i64)),
+        [w0, rest @ ..] => ScalarValue::List(
+            [ScalarValue::Primitive(PValue::I64(*w0 as i64))]
+                .into_iter()
+                .chain(rest.iter().map(|w| ScalarValue::Primitive(PValue::U64(*w))))
+                .collect::<Arc<[_]>>(),
+        ),
+        [] => vortex_bail!("Decimals must have at least one storage word"),
+    })
+}
+
+/// Split an unscaled decimal into `num_words` 64-bit two's complement words, most significant
+/// word first.
+pub fn decimal_to_words(value: i256, num_words: usize) -> VortexResult<Vec<u64>> {
+    if !(1..=4).contains(&num_words) {
+        vortex_bail!("Decimals are stored in 1 to 4 words, got {}", num_words);
+    }
+    let bytes = value.to_le_bytes();
+    let words = (0..num_words)
+        .rev()
+        .map(|i| {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&bytes[i * 8..(i + 1) * 8]);
+            u64::from_le_bytes(word)
+        })
+        .collect::<Vec<_>>();
+    if decimal_from_words(&words) != value {
+        vortex_bail!(
+            "Decimal value {} does not fit into {} storage words",
+            value,
+            num_words
+        );
+    }
+    Ok(words)
+}
+
+/// Reassemble an unscaled decimal from its two's complement words, most significant word first.
+pub fn decimal_from_words(words: &[u64]) -> i256 {
+    let sign_word = match words.first() {
+        Some(w) if (*w as i64) < 0 => u64::MAX,
+        _ => 0,
+    };
+    let mut bytes = [0u8; 32];
+    for i in 0..4 {
+        let word = if i < words.len() {
+            words[words.len() - 1 - i]
+        } else {
+            sign_word
+        };
+        bytes[i * 8..(i + 1) * 8].copy_from_slice(&word.to_le_bytes());
+    }
+    i256::from_le_bytes(bytes)
+}
+
+/// The exclusive bound `10^precision` of the absolute unscaled values of a decimal.
+pub fn precision_bound(precision: u8) -> VortexResult<i256> {
+    i256::from_i128(10)
+        .checked_pow(precision as u32)
+        .ok_or_else(|| vortex_err!("Invalid decimal precision {}", precision))
+}
+
+/// Check that an unscaled decimal value has at most `precision` digits.
+pub fn validate_precision(value: i256, precision: u8) -> VortexResult<()> {
+    let bound = precision_bound(precision)?;
+    if value >= bound || value <= bound.wrapping_neg() {
+        vortex_bail!(
+            "Decimal {} is too large to be stored with precision {}",
+            value,
+            precision
+        );
+    }
+    Ok(())
+}
+
+/// Rescale an unscaled decimal value from one scale to another, failing if digits would be lost.
+pub fn rescale(value: i256, from_scale: i8, to_scale: i8) -> VortexResult<i256> {
+    let diff = to_scale as i32 - from_scale as i32;
+    let factor = i256::from_i128(10)
+        .checked_pow(diff.unsigned_abs())
+        .ok_or_else(|| vortex_err!("Decimal rescale factor overflows"))?;
+    if diff >= 0 {
+        value
+            .checked_mul(factor)
+            .ok_or_else(|| vortex_err!("Decimal {} overflows when rescaled", value))
+    } else {
+        if value.wrapping_rem(factor) != i256::ZERO {
+            vortex_bail!(
+                "Decimal {} cannot be rescaled from {} to {} without losing precision",
+                value,
+                from_scale,
+                to_scale
+            );
+        }
+        Ok(value.wrapping_div(factor))
+    }
+}
+
+/// Render an unscaled decimal value with its decimal point.
+pub fn format_decimal(value: i256, scale: i8) -> String {
+    let digits = value.wrapping_abs().to_string();
+    let sign = if value.is_negative() { "-" } else { "" };
+    if scale <= 0 {
+        let zeros = if value == i256::ZERO {
+            0
+        } else {
+            scale.unsigned_abs() as usize
+        };
+        return format!("{sign}{digits}{}", "0".repeat(zeros));
+    }
+    let scale = scale as usize;
+    let digits = format!("{digits:0>width$}", width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{sign}{integer}.{fraction}")
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_buffer::i256;
+    use vortex_dtype::{DecimalMetadata, Nullability};
+
+    use crate::decimal::{decimal_from_words, decimal_to_words, format_decimal};
+    use crate::{DecimalScalar, Scalar};
+
+    #[test]
+    fn words_roundtrip() {
+        for value in [
+            i256::ZERO,
+            i256::from_i128(-1),
+            i256::from_i128(i64::MIN as i128),
+            i256::from_i128(i128::MAX),
+            i256::from_i128(i128::MIN),
+            i256::MAX,
+            i256::MIN,
+        ] {
+            assert_eq!(
+                decimal_from_words(&decimal_to_words(value, 4).unwrap()),
+                value
+            );
+        }
+        assert_eq!(
+            decimal_to_words(i256::from_i128(-2), 2).unwrap(),
+            vec![u64::MAX, u64::MAX - 1]
+        );
+        assert!(decimal_to_words(i256::from_i128(i128::MAX), 1).is_err());
+    }
+
+    #[test]
+    fn decimal_scalar() {
+        let metadata = DecimalMetadata::try_new(38, 2).unwrap();
+        let scalar =
+            Scalar::decimal(i256::from_i128(-12345), metadata, Nullability::Nullable).unwrap();
+        let decimal = DecimalScalar::try_from(&scalar).unwrap();
+        assert_eq!(decimal.value(), Some(i256::from_i128(-12345)));
+        assert_eq!(scalar.to_string(), "-123.45");
+
+        let rescaled = decimal
+            .cast(
+                &DecimalMetadata::try_new(10, 4)
+                    .unwrap()
+                    .dtype(Nullability::Nullable),
+            )
+            .unwrap();
+        assert_eq!(
+            DecimalScalar::try_from(&rescaled).unwrap().value(),
+            Some(i256::from_i128(-1234500))
+        );
+
+        assert!(decimal
+            .cast(
+                &DecimalMetadata::try_new(5, 2)
+                    .unwrap()
+                    .dtype(Nullability::Nullable),
+            )
+            .is_ok());
+        assert!(decimal
+            .cast(
+                &DecimalMetadata::try_new(6, 4)
+                    .unwrap()
+                    .dtype(Nullability::Nullable),
+            )
+            .is_err());
+        assert!(Scalar::decimal(
+            i256::from_i128(100_000),
+            DecimalMetadata::try_new(5, 0).unwrap(),
+            Nullability::NonNullable
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn decimal_ordering() {
+        let metadata = DecimalMetadata::try_new(38, 0).unwrap();
+        let a = Scalar::decimal(i256::from_i128(-5), metadata, Nullability::NonNullable).unwrap();
+        let b = Scalar::decimal(i256::from_i128(3), metadata, Nullability::NonNullable).unwrap();
+        assert!(a < b);
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(format_decimal(i256::from_i128(5), 3), "0.005");
+        assert_eq!(format_decimal(i256::from_i128(-5), 3), "-0.005");
+        assert_eq!(format_decimal(i256::from_i128(12), -2), "1200");
+        assert_eq!(format_decimal(i256::from_i128(12), 0), "12");
+    }
+}
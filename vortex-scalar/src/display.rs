@@ -2,10 +2,12 @@ use std::fmt::{Display, Formatter};
 
 use itertools::Itertools;
 use vortex_datetime_dtype::{is_temporal_ext_type, TemporalMetadata};
-use vortex_dtype::DType;
+use vortex_dtype::{is_decimal_ext_type, DType};
 
 use crate::binary::BinaryScalar;
+use crate::decimal::{format_decimal, DecimalScalar};
 use crate::extension::ExtScalar;
+use crate::interval::interval_components;
 use crate::struct_::StructScalar;
 use crate::utf8::Utf8Scalar;
 use crate::{PValue, Scalar, ScalarValue};
@@ -61,6 +63,17 @@ impl Display for Scalar {
             DType::List(..) => todo!(),
             DType::Extension(dtype, _) if is_temporal_ext_type(dtype.id()) => {
                 let metadata = TemporalMetadata::try_from(dtype).map_err(|_| std::fmt::Error)?;
+                if let Some(unit) = metadata.interval_unit() {
+                    return match interval_components(self.value()).map_err(|_| std::fmt::Error)? {
+                        None => write!(f, "null"),
+                        Some(components) => write!(
+                            f,
+                            "{}",
+                            unit.format_components(&components)
+                                .map_err(|_| std::fmt::Error)?
+                        ),
+                    };
+                }
                 match ExtScalar::try_from(self)
                     .map_err(|_| std::fmt::Error)?
                     .value()
@@ -79,6 +92,13 @@ impl Display for Scalar {
                     _ => Err(std::fmt::Error),
                 }
             }
+            DType::Extension(dtype, _) if is_decimal_ext_type(dtype.id()) => {
+                let decimal = DecimalScalar::try_from(self).map_err(|_| std::fmt::Error)?;
+                match decimal.value() {
+                    None => write!(f, "null"),
+                    Some(v) => write!(f, "{}", format_decimal(v, decimal.metadata().scale())),
+                }
+            }
             DType::Extension(..) => todo!(),
         }
     }
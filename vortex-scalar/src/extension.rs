@@ -1,8 +1,8 @@
-use vortex_dtype::{DType, ExtDType};
+use vortex_dtype::{is_decimal_ext_type, DType, ExtDType};
 use vortex_error::{vortex_bail, VortexError, VortexResult};
 
 use crate::value::ScalarValue;
-use crate::Scalar;
+use crate::{DecimalScalar, Scalar};
 
 pub struct ExtScalar<'a> {
     dtype: &'a DType,
@@ -30,8 +30,13 @@ impl<'a> ExtScalar<'a> {
         self.value
     }
 
-    pub fn cast(&self, _dtype: &DType) -> VortexResult<Scalar> {
-        todo!()
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        match self.dtype {
+            DType::Extension(ext, _) if is_decimal_ext_type(ext.id()) => {
+                DecimalScalar::try_new(self.dtype, self.value)?.cast(dtype)
+            }
+            _ => vortex_bail!("Can't cast {} scalar to {}", self.dtype, dtype),
+        }
     }
 }
 
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use vortex_datetime_dtype::{IntervalUnit, TemporalMetadata, INTERVAL_ID};
+use vortex_dtype::{DType, ExtDType, Nullability, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::value::ScalarValue;
+use crate::{PValue, Scalar};
+
+impl Scalar {
+    /// Create an interval scalar from its components, in the order of
+    /// [`IntervalUnit::components`].
+    pub fn interval(
+        interval_unit: IntervalUnit,
+        components: &[i64],
+        nullability: Nullability,
+    ) -> VortexResult<Self> {
+        Ok(Self {
+            dtype: DType::Extension(
+                ExtDType::new(
+                    INTERVAL_ID.clone(),
+                    Some(TemporalMetadata::Interval(interval_unit).into()),
+                ),
+                nullability,
+            ),
+            value: interval_scalar_value(interval_unit, components)?,
+        })
+    }
+}
+
+/// Encode the components of an interval as the [`ScalarValue`] of its storage dtype.
+pub fn interval_scalar_value(
+    interval_unit: IntervalUnit,
+    components: &[i64],
+) -> VortexResult<ScalarValue> {
+    if components.len() != interval_unit.components().len() {
+        vortex_bail!(
+            "Expected {} components for {} interval, got {}",
+            interval_unit.components().len(),
+            interval_unit,
+            components.len()
+        );
+    }
+
+    let values =
+        interval_unit
+            .components()
+            .iter()
+            .zip(components)
+            .map(|((name, ptype), v)| {
+                Ok(ScalarValue::Primitive(match ptype {
+                    PType::I32 => PValue::I32(i32::try_from(*v).map_err(|_| {
+                        vortex_err!("Interval {} {} does not fit into i32", name, v)
+                    })?),
+                    _ => PValue::I64(*v),
+                }))
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+
+    Ok(match values.as_slice() {
+        [value] => value.clone(),
+        _ => ScalarValue::List(values.into_iter().collect::<Arc<[_]>>()),
+    })
+}
+
+/// Decode the components of an interval from the [`ScalarValue`] of its storage dtype, returning
+/// `None` for null intervals.
+pub fn interval_components(value: &ScalarValue) -> VortexResult<Option<Vec<i64>>> {
+    let component = |v: &ScalarValue| -> VortexResult<i64> {
+        v.as_pvalue()?
+            .ok_or_else(|| vortex_err!("Interval components cannot be null"))
+            .and_then(i64::try_from)
+    };
+
+    Ok(match value {
+        ScalarValue::Null => None,
+        ScalarValue::Primitive(_) => Some(vec![component(value)?]),
+        ScalarValue::List(values) => Some(
+            values
+                .iter()
+                .map(component)
+                .collect::<VortexResult<Vec<_>>>()?,
+        ),
+        _ => vortex_bail!("Expected interval storage value, found {:?}", value),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_datetime_dtype::IntervalUnit;
+    use vortex_dtype::Nullability;
+
+    use crate::interval::interval_components;
+    use crate::Scalar;
+
+    #[test]
+    fn interval_roundtrip() {
+        let scalar = Scalar::interval(
+            IntervalUnit::MonthDayNano,
+            &[1, -2, 3_000_000_000],
+            Nullability::NonNullable,
+        )
+        .unwrap();
+        assert_eq!(
+            interval_components(scalar.value()).unwrap(),
+            Some(vec![1, -2, 3_000_000_000])
+        );
+        assert_eq!(scalar.to_string(), "1 mons -2 days 3.000000000 secs");
+
+        assert!(Scalar::interval(IntervalUnit::DayTime, &[1], Nullability::NonNullable).is_err());
+        assert!(Scalar::interval(
+            IntervalUnit::YearMonth,
+            &[i64::MAX],
+            Nullability::NonNullable
+        )
+        .is_err());
+    }
+}
@@ -9,8 +9,10 @@ mod arrow;
 mod binary;
 mod bool;
 mod datafusion;
+mod decimal;
 mod display;
 mod extension;
+mod interval;
 mod list;
 mod primitive;
 mod pvalue;
@@ -23,7 +25,9 @@ mod value;
 
 pub use binary::*;
 pub use bool::*;
+pub use decimal::*;
 pub use extension::*;
+pub use interval::*;
 pub use list::*;
 pub use primitive::*;
 pub use pvalue::*;
@@ -1,5 +1,5 @@
 use vortex_dtype::field::Field;
-use vortex_dtype::DType;
+use vortex_dtype::{DType, StructDType};
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
 use self::projection::Projection;
@@ -24,6 +24,7 @@ impl Schema {
                 s.project(fields.as_ref())
                     .map(|p| Self(DType::Struct(p, *n)))
             }
+            Projection::Nested(_) => project_nested(&self.0, &projection).map(Self),
         }
     }
 
@@ -46,8 +47,89 @@ impl Schema {
     }
 }
 
+fn project_nested(dtype: &DType, projection: &Projection) -> VortexResult<DType> {
+    let DType::Struct(s, n) = dtype else {
+        vortex_bail!("Can't project non struct types")
+    };
+    let Some(columns) = projection.columns(|f| s.field_info(f).map(|info| info.index))? else {
+        return Ok(dtype.clone());
+    };
+
+    let (names, dtypes): (Vec<_>, Vec<_>) = columns
+        .iter()
+        .map(|(idx, child)| {
+            let info = s.field_info(&Field::Index(*idx))?;
+            let child_dtype = match child {
+                Projection::All => info.dtype.clone(),
+                _ => project_nested(info.dtype, child)?,
+            };
+            Ok((info.name, child_dtype))
+        })
+        .collect::<VortexResult<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    Ok(DType::Struct(StructDType::new(names.into(), dtypes), *n))
+}
+
 impl From<Schema> for DType {
     fn from(value: Schema) -> Self {
         value.0
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::field::{Field, FieldPath};
+    use vortex_dtype::{DType, Nullability, PType, StructDType};
+
+    use crate::projection::Projection;
+    use crate::Schema;
+
+    #[test]
+    fn project_nested_fields() {
+        let inner = DType::Struct(
+            StructDType::new(
+                ["x".into(), "y".into()].into(),
+                vec![
+                    DType::Primitive(PType::I32, Nullability::NonNullable),
+                    DType::Utf8(Nullability::Nullable),
+                ],
+            ),
+            Nullability::Nullable,
+        );
+        let schema = Schema::new(DType::Struct(
+            StructDType::new(
+                ["a".into(), "b".into()].into(),
+                vec![DType::Bool(Nullability::NonNullable), inner],
+            ),
+            Nullability::NonNullable,
+        ));
+
+        let projected = schema
+            .project(Projection::Nested(vec![
+                FieldPath::from(vec![Field::from("b"), Field::from("y")]),
+                FieldPath::from(Field::from(0)),
+            ]))
+            .unwrap();
+        assert_eq!(
+            projected.dtype(),
+            &DType::Struct(
+                StructDType::new(
+                    ["b".into(), "a".into()].into(),
+                    vec![
+                        DType::Struct(
+                            StructDType::new(
+                                ["y".into()].into(),
+                                vec![DType::Utf8(Nullability::Nullable)]
+                            ),
+                            Nullability::Nullable
+                        ),
+                        DType::Bool(Nullability::NonNullable),
+                    ],
+                ),
+                Nullability::NonNullable,
+            )
+        );
+    }
+}
@@ -1,18 +1,63 @@
-use vortex_dtype::field::Field;
+use vortex_dtype::field::{Field, FieldPath};
+use vortex_error::VortexResult;
 
-// TODO(robert): Add ability to project nested columns.
-//  Until datafusion supports nested column pruning we should create a separate variant to implement it
 #[derive(Debug, Clone, Default)]
 pub enum Projection {
     #[default]
     All,
     Flat(Vec<Field>),
+    /// Select fields nested in struct columns, the first element of every path is a top level field.
+    ///
+    /// Intermediate structs only keep the selected children.
+    Nested(Vec<FieldPath>),
 }
 
 impl Projection {
     pub fn new(indices: impl AsRef<[usize]>) -> Self {
         Self::Flat(indices.as_ref().iter().copied().map(Field::from).collect())
     }
+
+    /// Group the projection by the top level fields it selects, in order of their first reference.
+    ///
+    /// Every field comes with the projection of its own children, which is [`Projection::All`] if the
+    /// whole field is selected. Returns `None` if all fields are selected.
+    pub fn columns<F>(&self, resolve: F) -> VortexResult<Option<Vec<(usize, Projection)>>>
+    where
+        F: Fn(&Field) -> VortexResult<usize>,
+    {
+        match self {
+            Projection::All => Ok(None),
+            Projection::Flat(fields) => fields
+                .iter()
+                .map(|f| resolve(f).map(|idx| (idx, Projection::All)))
+                .collect::<VortexResult<Vec<_>>>()
+                .map(Some),
+            Projection::Nested(paths) => {
+                let mut columns: Vec<(usize, Projection)> = Vec::new();
+                for path in paths {
+                    let Some((first, rest)) = path.path().split_first() else {
+                        // The root path selects everything
+                        return Ok(None);
+                    };
+                    let idx = resolve(first)?;
+                    let position = match columns.iter().position(|(c, _)| *c == idx) {
+                        Some(position) => position,
+                        None => {
+                            columns.push((idx, Projection::Nested(Vec::new())));
+                            columns.len() - 1
+                        }
+                    };
+                    let child = &mut columns[position].1;
+                    if rest.is_empty() {
+                        *child = Projection::All;
+                    } else if let Projection::Nested(child_paths) = child {
+                        child_paths.push(rest.iter().cloned().collect());
+                    }
+                }
+                Ok(Some(columns))
+            }
+        }
+    }
 }
 
 impl From<Vec<Field>> for Projection {
@@ -26,3 +71,9 @@ impl From<Vec<usize>> for Projection {
         Self::Flat(indices.into_iter().map(Field::from).collect())
     }
 }
+
+impl From<Vec<FieldPath>> for Projection {
+    fn from(paths: Vec<FieldPath>) -> Self {
+        Self::Nested(paths)
+    }
+}
@@ -30,17 +30,26 @@ impl<R: VortexReadAt> ChunkedArrayReader<R> {
             return self.take_rows_strict_sorted(indices).await;
         }
 
-        //         // Figure out which chunks are relevant to the read operation using the row_offsets array.
-        //         // Depending on whether there are more indices than chunks, we may wish to perform this
-        //         // join differently.
-        //
-        //         // Coalesce the chunks we care about by some metric.
-        //
-        //         // TODO(ngates): we could support read_into for array builders since we know the size
-        //         //  of the result.
-        //         // Read the relevant chunks.
-        // Reshuffle the result as per the original sort order.
-        unimplemented!("Unsorted 'take' operation is not supported yet")
+        // Read every distinct row once, in sorted order, and scatter the rows back into the order
+        // of the original indices afterwards.
+        let indices = try_cast(indices, PType::U64.into())?.into_primitive()?;
+        let indices = indices.maybe_null_slice::<u64>();
+        let mut permutation = (0..indices.len()).collect::<Vec<_>>();
+        permutation.sort_by_key(|&i| indices[i]);
+
+        let mut unique_indices: Vec<u64> = Vec::with_capacity(indices.len());
+        let mut positions = vec![0u64; indices.len()];
+        for i in permutation {
+            if unique_indices.last() != Some(&indices[i]) {
+                unique_indices.push(indices[i]);
+            }
+            positions[i] = (unique_indices.len() - 1) as u64;
+        }
+
+        let sorted_rows = self
+            .take_rows_strict_sorted(&PrimitiveArray::from(unique_indices).into_array())
+            .await?;
+        take(sorted_rows, PrimitiveArray::from(positions).into_array())
     }
 
     /// Take rows from a chunked array given strict sorted indices.
@@ -267,4 +276,42 @@ mod test {
         assert_eq!(result.maybe_null_slice::<i32>(), &[0, 10, 999]);
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_take_unsorted_rows() -> VortexResult<()> {
+        let writer = chunked_array()?;
+
+        let array_layout = writer.array_layouts()[0].clone();
+        let byte_offsets = PrimitiveArray::from(array_layout.chunks.byte_offsets.clone());
+        let row_offsets = PrimitiveArray::from(array_layout.chunks.row_offsets.clone());
+
+        let buffer = Buffer::from(writer.into_inner());
+
+        let mut msgs =
+            block_on(async { MessageReader::try_new(Cursor::new(buffer.clone())).await })?;
+        let dtype = Arc::new(block_on(async { msgs.read_dtype().await })?);
+
+        let mut reader = ChunkedArrayReader::try_new(
+            buffer,
+            Arc::new(Context::default()),
+            dtype,
+            byte_offsets.into_array(),
+            row_offsets.into_array(),
+        )
+        .unwrap();
+
+        let result = block_on(async {
+            reader
+                .take_rows(
+                    &PrimitiveArray::from(vec![10_000u64 - 1, 10, 0, 5_432, 10, 2_001])
+                        .into_array(),
+                )
+                .await
+        })?
+        .into_primitive()?;
+
+        assert_eq!(result.maybe_null_slice::<i32>(), &[999, 10, 0, 432, 10, 1]);
+        Ok(())
+    }
 }
@@ -0,0 +1,187 @@
+use std::io;
+use std::io::{Read, Write};
+
+use arrow_buffer::MutableBuffer;
+use vortex_buffer::Buffer;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_flatbuffers::message::Compression;
+
+/// Buffers longer than this are always written uncompressed, and readers refuse to decompress
+/// buffers declaring a longer uncompressed length.
+pub(crate) const MAX_UNCOMPRESSED_LENGTH: usize = 1 << 30;
+
+/// General-purpose codec applied to each buffer of a batch message.
+///
+/// Buffers are compressed independently. A buffer that does not shrink when compressed is written
+/// uncompressed, so readers never pay a decompression cost for incompressible data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BufferCompression {
+    /// Write buffers as-is.
+    #[default]
+    None,
+    /// LZ4 frame format.
+    Lz4,
+    /// Zstandard with the given compression level, zero selects the library default.
+    Zstd(i32),
+}
+
+/// A buffer as it is written to a batch message.
+pub(crate) struct CompressedBuffer {
+    pub buffer: Buffer,
+    pub compression: Compression,
+    /// Length of the buffer before compression, zero if it is not compressed.
+    pub uncompressed_length: u32,
+}
+
+impl CompressedBuffer {
+    fn uncompressed(buffer: Buffer) -> Self {
+        Self {
+            buffer,
+            compression: Compression::None,
+            uncompressed_length: 0,
+        }
+    }
+}
+
+impl BufferCompression {
+    /// Compress the buffer, returning the compressed bytes and the codec that was applied.
+    pub(crate) fn compress(&self, buffer: Buffer) -> io::Result<CompressedBuffer> {
+        if buffer.len() > MAX_UNCOMPRESSED_LENGTH {
+            return Ok(CompressedBuffer::uncompressed(buffer));
+        }
+
+        let (compressed, compression) = match self {
+            Self::None => return Ok(CompressedBuffer::uncompressed(buffer)),
+            Self::Lz4 => {
+                let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+                encoder.write_all(buffer.as_slice())?;
+                (
+                    encoder.finish().map_err(io::Error::other)?,
+                    Compression::LZ4,
+                )
+            }
+            Self::Zstd(level) => (
+                zstd::encode_all(buffer.as_slice(), *level)?,
+                Compression::ZSTD,
+            ),
+        };
+
+        if compressed.len() < buffer.len() {
+            Ok(CompressedBuffer {
+                uncompressed_length: buffer.len() as u32,
+                buffer: Buffer::from(compressed),
+                compression,
+            })
+        } else {
+            Ok(CompressedBuffer::uncompressed(buffer))
+        }
+    }
+}
+
+/// Reverse the codec recorded for a buffer in a batch message.
+///
+/// The buffer is decompressed into an aligned allocation of its declared uncompressed length, and
+/// must decompress to exactly that many bytes.
+pub(crate) fn decompress(
+    buffer: Buffer,
+    compression: Compression,
+    uncompressed_length: u32,
+) -> VortexResult<Buffer> {
+    if compression == Compression::None {
+        return Ok(buffer);
+    }
+
+    let uncompressed_length = uncompressed_length as usize;
+    if uncompressed_length > MAX_UNCOMPRESSED_LENGTH {
+        vortex_bail!(
+            InvalidSerde: "Compressed buffer declares {} bytes, more than the limit of {}",
+            uncompressed_length,
+            MAX_UNCOMPRESSED_LENGTH
+        );
+    }
+
+    let mut decompressed = MutableBuffer::from_len_zeroed(uncompressed_length);
+    match compression {
+        Compression::LZ4 => read_exactly(
+            lz4_flex::frame::FrameDecoder::new(buffer.as_slice()),
+            decompressed.as_slice_mut(),
+        )
+        .map_err(|e| vortex_err!(InvalidSerde: "Failed to decompress LZ4 buffer: {}", e))?,
+        Compression::ZSTD => zstd::stream::read::Decoder::with_buffer(buffer.as_slice())
+            .and_then(|decoder| read_exactly(decoder, decompressed.as_slice_mut()))
+            .map_err(|e| vortex_err!(InvalidSerde: "Failed to decompress Zstd buffer: {}", e))?,
+        _ => vortex_bail!(InvalidSerde: "Unknown buffer compression {:?}", compression),
+    }
+    Ok(Buffer::from(decompressed))
+}
+
+/// Fill `buf` from the reader, failing if the reader ends early or has data left over.
+fn read_exactly(mut reader: impl Read, buf: &mut [u8]) -> io::Result<()> {
+    reader.read_exact(buf)?;
+    if reader.read(&mut [0u8])? != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "buffer is longer than its declared length",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_buffer::Buffer;
+    use vortex_flatbuffers::message::Compression;
+
+    use crate::compression::{decompress, BufferCompression, MAX_UNCOMPRESSED_LENGTH};
+    use crate::ALIGNMENT;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn roundtrip() {
+        let data = Buffer::from((0u32..4096).map(|i| i % 7).collect::<Vec<_>>());
+        for (codec, expected) in [
+            (BufferCompression::None, Compression::None),
+            (BufferCompression::Lz4, Compression::LZ4),
+            (BufferCompression::Zstd(0), Compression::ZSTD),
+        ] {
+            let compressed = codec.compress(data.clone()).unwrap();
+            assert_eq!(compressed.compression, expected);
+            let decompressed = decompress(
+                compressed.buffer,
+                compressed.compression,
+                compressed.uncompressed_length,
+            )
+            .unwrap();
+            assert_eq!(decompressed, data);
+            if expected != Compression::None {
+                assert_eq!(decompressed.as_ptr().align_offset(ALIGNMENT), 0);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn declared_length_is_checked() {
+        let data = Buffer::from(vec![0u8; 4096]);
+        for codec in [BufferCompression::Lz4, BufferCompression::Zstd(0)] {
+            let compressed = codec.compress(data.clone()).unwrap();
+            for declared in [0, 4095, 4097, MAX_UNCOMPRESSED_LENGTH as u32 + 1] {
+                assert!(
+                    decompress(compressed.buffer.clone(), compressed.compression, declared)
+                        .is_err()
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn incompressible_falls_back() {
+        let data = Buffer::from(vec![1u8, 2, 3]);
+        for codec in [BufferCompression::Lz4, BufferCompression::Zstd(3)] {
+            let compressed = codec.compress(data.clone()).unwrap();
+            assert_eq!(compressed.compression, Compression::None);
+            assert_eq!(compressed.buffer, data);
+        }
+    }
+}
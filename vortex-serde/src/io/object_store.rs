@@ -103,11 +103,17 @@ impl ObjectStoreReadAt {
     }
 
     pub async fn try_new_from_url(url: &str) -> VortexResult<Self> {
-        let (object_store, location) = Self::better_parse_url(url)?;
-        Ok(Self::new(Arc::from(object_store), location))
+        let (object_store, location) = object_store_from_url(url)?;
+        Ok(Self::new(object_store, location))
     }
 }
 
+/// Create the object store that serves the URL, along with the path of the URL within the store.
+pub fn object_store_from_url(url: &str) -> VortexResult<(Arc<dyn ObjectStore>, Path)> {
+    let (object_store, location) = ObjectStoreReadAt::better_parse_url(url)?;
+    Ok((Arc::from(object_store), location))
+}
+
 impl VortexReadAt for ObjectStoreReadAt {
     async fn read_at_into(&self, pos: u64, mut buffer: BytesMut) -> io::Result<BytesMut> {
         let start_range = pos as usize;
@@ -119,6 +125,11 @@ impl VortexReadAt for ObjectStoreReadAt {
         Ok(buffer)
     }
 
+    fn performance_hint(&self) -> usize {
+        // Every range is a separate request, which is worth downloading an extra megabyte to avoid
+        1024 * 1024
+    }
+
     async fn size(&self) -> u64 {
         self.object_store
             .head(&self.location)
@@ -145,6 +156,14 @@ impl ObjectStoreWriter {
             multipart: Some(multipart),
         }
     }
+
+    /// Abort the upload, discarding everything written so far.
+    pub async fn abort(&mut self) -> VortexResult<()> {
+        if let Some(mp) = self.multipart.take() {
+            mp.abort().await?;
+        }
+        Ok(())
+    }
 }
 
 impl VortexWrite for ObjectStoreWriter {
@@ -166,10 +185,14 @@ impl VortexWrite for ObjectStoreWriter {
     }
 
     async fn shutdown(&mut self) -> std::io::Result<()> {
-        let mp = mem::take(&mut self.multipart);
-        mp.ok_or_else(|| io::Error::new(io::ErrorKind::Other, "multipart already finished"))?
-            .finish()
-            .await?;
+        let mut mp = mem::take(&mut self.multipart)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "multipart already finished"))?;
+        // `finish` only aborts the upload if completing it fails, not if uploading a part does
+        if let Err(e) = mp.wait_for_capacity(0).await {
+            let _ = mp.abort().await;
+            return Err(e.into());
+        }
+        mp.finish().await?;
         Ok(())
     }
 }
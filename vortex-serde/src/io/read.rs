@@ -19,8 +19,9 @@ pub trait VortexReadAt: Send + Sync {
         buffer: BytesMut,
     ) -> impl Future<Output = io::Result<BytesMut>> + Send;
 
-    // TODO(ngates): the read implementation should be able to hint at its latency/throughput
-    //  allowing the caller to make better decisions about how to coalesce reads.
+    /// The number of bytes that are cheaper to read than to issue a separate request for.
+    ///
+    /// Readers use this as the largest gap between two byte ranges that are merged into one read.
     fn performance_hint(&self) -> usize {
         0
     }
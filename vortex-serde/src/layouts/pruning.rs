@@ -1,15 +1,27 @@
-// This code doesn't have usage outside of tests yet, remove once usage is added
-#![allow(dead_code)]
-
+use std::collections::BTreeSet;
+use std::iter;
+use std::ops::Range;
 use std::sync::Arc;
 
+use arrow_buffer::BooleanBufferBuilder;
 use vortex::aliases::hash_map::{Entry, HashMap};
+use vortex::aliases::hash_set::HashSet;
+use vortex::array::{BoolArray, PrimitiveArray, StructArray};
+use vortex::compute::take;
 use vortex::stats::Stat;
+use vortex::validity::Validity;
+use vortex::{Array, IntoArray, IntoArrayVariant};
 use vortex_dtype::field::Field;
 use vortex_dtype::Nullability;
-use vortex_expr::{BinaryExpr, Column, Literal, Operator, VortexExpr};
+use vortex_error::{vortex_err, VortexResult};
+use vortex_expr::{BinaryExpr, Column, InList, IsNull, Literal, Operator, VortexExpr};
 use vortex_scalar::Scalar;
 
+use crate::layouts::null_as_false;
+
+/// Expression over chunk statistics that evaluates to true for chunks that can't contain any
+/// rows matching the original expression.
+#[derive(Debug)]
 pub struct PruningPredicate {
     expr: Arc<dyn VortexExpr>,
     stats_to_fetch: HashMap<Field, Vec<Stat>>,
@@ -23,31 +35,326 @@ impl PruningPredicate {
             stats_to_fetch,
         }
     }
+
+    /// Statistics of every column required to evaluate this predicate
+    pub fn stats_to_fetch(&self) -> &HashMap<Field, Vec<Stat>> {
+        &self.stats_to_fetch
+    }
+
+    /// Evaluate the predicate against a struct array of statistics with one row per chunk,
+    /// returning a boolean mask that's true for chunks that can be skipped.
+    ///
+    /// Statistics are expected in fields named by [`stat_column_name`].
+    pub fn evaluate(&self, stats: &Array) -> VortexResult<Array> {
+        null_as_false(self.expr.evaluate(stats)?.into_bool()?)
+    }
+}
+
+/// Sorted, non-overlapping ranges of rows that can be skipped when reading a file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrunedRanges {
+    ranges: Vec<Range<u64>>,
+}
+
+impl PrunedRanges {
+    pub fn new(ranges: Vec<Range<u64>>) -> Self {
+        Self { ranges }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Ranges of rows that are pruned by either of the two
+    pub fn union(&self, other: &PrunedRanges) -> PrunedRanges {
+        let mut all = self
+            .ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .cloned()
+            .collect::<Vec<_>>();
+        all.sort_by_key(|r| r.start);
+
+        let mut ranges: Vec<Range<u64>> = Vec::with_capacity(all.len());
+        for range in all {
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => ranges.push(range),
+            }
+        }
+        PrunedRanges::new(ranges)
+    }
+
+    /// Drop the rows that fall into any of the pruned ranges from a boolean mask over all rows
+    pub fn filter_mask(&self, mask: &BoolArray) -> VortexResult<BoolArray> {
+        let selection = mask.boolean_buffer();
+        let mut retained = BooleanBufferBuilder::new(selection.len());
+        let mut start = 0;
+        for range in self
+            .ranges
+            .iter()
+            .map(|r| r.start as usize..r.end as usize)
+            .chain(iter::once(selection.len()..selection.len()))
+        {
+            if range.start > start {
+                retained.append_buffer(&selection.slice(start, range.start - start));
+            }
+            start = range.end;
+        }
+        BoolArray::try_new(retained.finish(), Validity::NonNullable)
+    }
+
+    /// Whether the given row falls into any of the pruned ranges
+    pub fn contains(&self, row: u64) -> bool {
+        let idx = self.ranges.partition_point(|r| r.end <= row);
+        self.ranges
+            .get(idx)
+            .map(|r| r.contains(&row))
+            .unwrap_or(false)
+    }
+}
+
+/// Compute the ranges of rows that can't match the predicate given the chunk metadata of columns.
+///
+/// `columns` resolves fields referenced by the predicate to column indices and `chunk_metadata`
+/// contains the metadata of every column that's going to be read. Returned ranges are aligned to
+/// chunk boundaries of all of these columns, such that every column can skip its chunks in full.
+pub fn pruned_ranges(
+    predicate: &PruningPredicate,
+    columns: &HashMap<Field, usize>,
+    chunk_metadata: &HashMap<usize, Array>,
+    row_count: u64,
+) -> VortexResult<PrunedRanges> {
+    let row_offsets = chunk_row_offsets(chunk_metadata)?;
+
+    let filter_columns = predicate
+        .stats_to_fetch()
+        .keys()
+        .map(|f| {
+            columns
+                .get(f)
+                .copied()
+                .ok_or_else(|| vortex_err!("Field {f} wasn't resolved to a column"))
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
+
+    // Split the rows at every chunk boundary of the filtered columns and collect the statistics
+    // of every resulting segment
+    let segments = filter_columns
+        .iter()
+        .filter_map(|c| row_offsets.get(c))
+        .flatten()
+        .copied()
+        .filter(|o| *o < row_count)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    if segments.is_empty() {
+        return Ok(PrunedRanges::default());
+    }
+
+    let mut names = Vec::new();
+    let mut fields = Vec::new();
+    let mut seen = HashSet::new();
+    for (field, column) in predicate.stats_to_fetch().keys().zip(filter_columns) {
+        let (Some(offsets), Some(metadata)) =
+            (row_offsets.get(&column), chunk_metadata.get(&column))
+        else {
+            return Ok(PrunedRanges::default());
+        };
+        let chunk_indices = PrimitiveArray::from(
+            segments
+                .iter()
+                .map(|s| offsets.partition_point(|o| o <= s).saturating_sub(1) as u64)
+                .collect::<Vec<_>>(),
+        )
+        .into_array();
+
+        for stat in &predicate.stats_to_fetch()[field] {
+            let name = stat_column_name(field, *stat);
+            if !seen.insert(name.clone()) {
+                continue;
+            }
+            let Some(values) = metadata.with_dyn(|a| {
+                a.as_struct_array()
+                    .and_then(|s| s.field_by_name(&stat.to_string()))
+            }) else {
+                // Statistic wasn't persisted for this column, nothing can be pruned
+                return Ok(PrunedRanges::default());
+            };
+            names.push(match name {
+                Field::Name(n) => n.into(),
+                Field::Index(i) => i.to_string().into(),
+            });
+            fields.push(take(values, &chunk_indices)?);
+        }
+    }
+
+    let stats = StructArray::try_new(names.into(), fields, segments.len(), Validity::NonNullable)?;
+    let pruned = predicate
+        .evaluate(stats.as_ref())?
+        .into_bool()?
+        .boolean_buffer();
+
+    let mut ranges: Vec<Range<u64>> = Vec::new();
+    for (i, _) in pruned.iter().enumerate().filter(|(_, p)| *p) {
+        let start = segments[i];
+        let end = segments.get(i + 1).copied().unwrap_or(row_count);
+        match ranges.last_mut() {
+            Some(last) if last.end == start => last.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+
+    // Shrink ranges to boundaries shared by chunks of every column
+    let common_boundaries = common_boundaries(&row_offsets, row_count);
+
+    Ok(PrunedRanges::new(
+        ranges
+            .into_iter()
+            .filter_map(|r| {
+                let start = common_boundaries.range(r.start..).next()?;
+                let end = common_boundaries.range(..=r.end).next_back()?;
+                (start < end).then(|| *start..*end)
+            })
+            .collect(),
+    ))
+}
+
+/// Compute the ranges of rows that aren't selected by the row mask.
+///
+/// Ranges are aligned to chunk boundaries shared by every column in `chunk_metadata`.
+pub fn mask_pruned_ranges(
+    mask: &BoolArray,
+    chunk_metadata: &HashMap<usize, Array>,
+    row_count: u64,
+) -> VortexResult<PrunedRanges> {
+    let boundaries = common_boundaries(&chunk_row_offsets(chunk_metadata)?, row_count)
+        .into_iter()
+        .collect::<Vec<_>>();
+    let selection = mask.boolean_buffer();
+
+    let mut ranges: Vec<Range<u64>> = Vec::new();
+    for segment in boundaries.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        if selection
+            .slice(start as usize, (end - start) as usize)
+            .count_set_bits()
+            != 0
+        {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(last) if last.end == start => last.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+    Ok(PrunedRanges::new(ranges))
+}
+
+/// Row offsets of the chunks of every column
+fn chunk_row_offsets(
+    chunk_metadata: &HashMap<usize, Array>,
+) -> VortexResult<HashMap<usize, Vec<u64>>> {
+    chunk_metadata
+        .iter()
+        .map(|(idx, metadata)| {
+            metadata
+                .with_dyn(|a| {
+                    a.as_struct_array()
+                        .and_then(|s| s.field_by_name("row_offset"))
+                })
+                .ok_or_else(|| vortex_err!("Chunk metadata is missing row offsets"))?
+                .into_primitive()
+                .map(|offsets| (*idx, offsets.maybe_null_slice::<u64>().to_vec()))
+        })
+        .collect()
+}
+
+/// Row offsets at which every column starts a new chunk, including the end of the file
+fn common_boundaries(row_offsets: &HashMap<usize, Vec<u64>>, row_count: u64) -> BTreeSet<u64> {
+    let mut common_boundaries = row_offsets
+        .values()
+        .map(|offsets| offsets.iter().copied().collect::<BTreeSet<_>>())
+        .reduce(|acc, offsets| acc.intersection(&offsets).copied().collect())
+        .unwrap_or_default();
+    common_boundaries.insert(row_count);
+    common_boundaries
 }
 
 fn convert_to_pruning_expression(
     expr: &Arc<dyn VortexExpr>,
 ) -> (Arc<dyn VortexExpr>, HashMap<Field, Vec<Stat>>) {
     // Anything that can't be translated has to be represented as
-    // boolean true expression, i.e. the value might be in that chunk
-    let fallback = Arc::new(Literal::new(Scalar::bool(true, Nullability::NonNullable)));
+    // boolean false expression, i.e. the value might be in that chunk
+    let fallback = Arc::new(Literal::new(Scalar::bool(false, Nullability::NonNullable)));
     // TODO(robert): Add support for boolean column expressions,
     //  i.e. if column is of bool dtype it's valid to filter on it directly as a predicate
     if expr.as_any().downcast_ref::<Column>().is_some() {
         return (fallback, HashMap::new());
     }
 
+    // A chunk without nulls can't contain any rows that are null
+    if let Some(is_null) = expr.as_any().downcast_ref::<IsNull>() {
+        return match is_null.child().as_any().downcast_ref::<Column>() {
+            Some(col) if !col.is_nested() => {
+                let null_count =
+                    Arc::new(Column::new(stat_column_name(col.field(), Stat::NullCount)));
+                (
+                    Arc::new(BinaryExpr::new(
+                        null_count,
+                        Operator::Eq,
+                        Arc::new(Literal::new(0u64.into())),
+                    )),
+                    HashMap::from_iter([(col.field().clone(), vec![Stat::NullCount])]),
+                )
+            }
+            _ => (fallback, HashMap::new()),
+        };
+    }
+
+    // An IN list is pruned like the disjunction of equalities with each of its values
+    if let Some(in_list) = expr.as_any().downcast_ref::<InList>() {
+        return in_list
+            .values()
+            .iter()
+            .map(|value| {
+                Arc::new(BinaryExpr::new(
+                    in_list.child().clone(),
+                    Operator::Eq,
+                    Arc::new(Literal::new(value.clone())),
+                )) as Arc<dyn VortexExpr>
+            })
+            .reduce(|lhs, rhs| Arc::new(BinaryExpr::new(lhs, Operator::Or, rhs)))
+            .map(|disjunction| convert_to_pruning_expression(&disjunction))
+            .unwrap_or_else(|| (fallback, HashMap::new()));
+    }
+
     if let Some(bexp) = expr.as_any().downcast_ref::<BinaryExpr>() {
         if bexp.op() == Operator::Or || bexp.op() == Operator::And {
             let (rewritten_left, mut refs_lhs) = convert_to_pruning_expression(bexp.lhs());
             let (rewritten_right, refs_rhs) = convert_to_pruning_expression(bexp.rhs());
-            refs_lhs.extend(refs_rhs);
+            for (field, stats) in refs_rhs {
+                refs_lhs.entry(field).or_default().extend(stats);
+            }
+            // A chunk can be skipped for a conjunction if either side can be skipped and for a
+            // disjunction only if both sides can be skipped
+            let op = if bexp.op() == Operator::And {
+                Operator::Or
+            } else {
+                Operator::And
+            };
             return (
-                Arc::new(BinaryExpr::new(rewritten_left, bexp.op(), rewritten_right)),
+                Arc::new(BinaryExpr::new(rewritten_left, op, rewritten_right)),
                 refs_lhs,
             );
         }
 
+        // Statistics are only kept for top level columns
+        if references_nested_column(expr) {
+            return (fallback, HashMap::new());
+        }
+
         if let Some(col) = bexp.lhs().as_any().downcast_ref::<Column>() {
             return PruningPredicateRewriter::try_new(col.field().clone(), bexp.op(), bexp.rhs())
                 .and_then(PruningPredicateRewriter::rewrite)
@@ -55,19 +362,32 @@ fn convert_to_pruning_expression(
         };
 
         if let Some(col) = bexp.rhs().as_any().downcast_ref::<Column>() {
-            return PruningPredicateRewriter::try_new(
-                col.field().clone(),
-                bexp.op().swap(),
-                bexp.lhs(),
-            )
-            .and_then(PruningPredicateRewriter::rewrite)
-            .unwrap_or_else(|| (fallback, HashMap::new()));
+            return bexp
+                .op()
+                .swap()
+                .and_then(|op| {
+                    PruningPredicateRewriter::try_new(col.field().clone(), op, bexp.lhs())
+                })
+                .and_then(PruningPredicateRewriter::rewrite)
+                .unwrap_or_else(|| (fallback, HashMap::new()));
         };
     }
 
     (fallback, HashMap::new())
 }
 
+fn references_nested_column(expr: &Arc<dyn VortexExpr>) -> bool {
+    if let Some(col) = expr.as_any().downcast_ref::<Column>() {
+        return col.is_nested();
+    }
+
+    expr.as_any()
+        .downcast_ref::<BinaryExpr>()
+        .is_some_and(|bexp| {
+            references_nested_column(bexp.lhs()) || references_nested_column(bexp.rhs())
+        })
+}
+
 struct PruningPredicateRewriter<'a> {
     column: Field,
     operator: Operator,
@@ -85,7 +405,17 @@ impl<'a> PruningPredicateRewriter<'a> {
     ) -> Option<Self> {
         // TODO(robert): Simplify expression to guarantee that each column is not compared to itself
         //  For majority of cases self column references are likely not prunable
-        if other_exp.references().contains(&column) {
+        if other_exp
+            .references()
+            .iter()
+            .any(|path| path.first() == Some(&column))
+        {
+            return None;
+        }
+
+        // Bounds of the other expression are computed by replacing its columns with their min or
+        // max, which only holds if it grows with each of them
+        if !is_non_decreasing(other_exp) {
             return None;
         }
 
@@ -113,6 +443,20 @@ impl<'a> PruningPredicateRewriter<'a> {
             .unwrap_or_else(|| self.other_exp.clone())
     }
 
+    /// Compare the max of the column against the min of the other expression.
+    fn max_compared(&mut self, op: Operator) -> Arc<dyn VortexExpr> {
+        let max_col = Arc::new(Column::new(self.add_stat_reference(Stat::Max)));
+        let replaced_min = self.rewrite_other_exp(Stat::Min);
+        Arc::new(BinaryExpr::new(max_col, op, replaced_min))
+    }
+
+    /// Compare the min of the column against the max of the other expression.
+    fn min_compared(&mut self, op: Operator) -> Arc<dyn VortexExpr> {
+        let min_col = Arc::new(Column::new(self.add_stat_reference(Stat::Min)));
+        let replaced_max = self.rewrite_other_exp(Stat::Max);
+        Arc::new(BinaryExpr::new(min_col, op, replaced_max))
+    }
+
     fn rewrite(mut self) -> Option<PruningPredicateStats> {
         let expr: Option<Arc<dyn VortexExpr>> = match self.operator {
             Operator::Eq => {
@@ -152,32 +496,31 @@ impl<'a> PruningPredicateRewriter<'a> {
                     )),
                 )))
             }
-            Operator::Gt | Operator::Gte => {
-                let max_col = Arc::new(Column::new(self.add_stat_reference(Stat::Max)));
-                let replaced_min = self.rewrite_other_exp(Stat::Min);
-
-                Some(Arc::new(BinaryExpr::new(
-                    max_col,
-                    Operator::Lte,
-                    replaced_min,
-                )))
-            }
-            Operator::Lt | Operator::Lte => {
-                let min_col = Arc::new(Column::new(self.add_stat_reference(Stat::Min)));
-                let replaced_max = self.rewrite_other_exp(Stat::Max);
-
-                Some(Arc::new(BinaryExpr::new(
-                    min_col,
-                    Operator::Gte,
-                    replaced_max,
-                )))
-            }
+            // A chunk can only be skipped for `a >= x` if all of its values are below x, while
+            // `a > x` also skips a chunk whose max equals x. The same holds for the min of `<=`.
+            Operator::Gt => Some(self.max_compared(Operator::Lte)),
+            Operator::Gte => Some(self.max_compared(Operator::Lt)),
+            Operator::Lt => Some(self.min_compared(Operator::Gte)),
+            Operator::Lte => Some(self.min_compared(Operator::Gt)),
             _ => None,
         };
         expr.map(|e| (e, self.stats_to_fetch))
     }
 }
 
+/// Whether an expression never decreases when any of the columns it references increases
+fn is_non_decreasing(expr: &Arc<dyn VortexExpr>) -> bool {
+    let Some(bexp) = expr.as_any().downcast_ref::<BinaryExpr>() else {
+        return true;
+    };
+    match bexp.op() {
+        Operator::Add => is_non_decreasing(bexp.lhs()) && is_non_decreasing(bexp.rhs()),
+        Operator::Sub => is_non_decreasing(bexp.lhs()) && bexp.rhs().references().is_empty(),
+        op if op.is_arithmetic() => bexp.references().is_empty(),
+        _ => true,
+    }
+}
+
 fn replace_column_with_stat(
     expr: &Arc<dyn VortexExpr>,
     stat: Stat,
@@ -210,7 +553,7 @@ fn replace_column_with_stat(
     None
 }
 
-fn stat_column_name(field: &Field, stat: Stat) -> Field {
+pub fn stat_column_name(field: &Field, stat: Stat) -> Field {
     match field {
         Field::Name(n) => Field::Name(format!("{n}_{stat}")),
         Field::Index(i) => Field::Name(format!("{i}_{stat}")),
@@ -222,11 +565,18 @@ mod tests {
     use std::sync::Arc;
 
     use vortex::aliases::hash_map::HashMap;
+    use vortex::array::{BoolArray, PrimitiveArray, StructArray};
     use vortex::stats::Stat;
-    use vortex_dtype::field::Field;
-    use vortex_expr::{BinaryExpr, Column, Literal, Operator, VortexExpr};
+    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex_dtype::field::{Field, FieldPath};
+    use vortex_dtype::Nullability;
+    use vortex_expr::{BinaryExpr, Column, InList, IsNull, Literal, Operator, VortexExpr};
+    use vortex_scalar::Scalar;
 
-    use crate::layouts::pruning::{convert_to_pruning_expression, stat_column_name};
+    use crate::layouts::pruning::{
+        convert_to_pruning_expression, mask_pruned_ranges, pruned_ranges, stat_column_name,
+        PrunedRanges, PruningPredicate,
+    };
 
     #[test]
     pub fn pruning_equals() {
@@ -369,6 +719,72 @@ mod tests {
         assert_eq!(*converted, *expected_expr.as_any());
     }
 
+    #[test]
+    pub fn pruning_gt_arithmetic() {
+        let column = Field::from("a");
+        let other_col = Field::from("b");
+        let one = Arc::new(Literal::new(1.into()));
+        let gt_sum = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(column.clone())),
+            Operator::Gt,
+            Arc::new(BinaryExpr::new(
+                Arc::new(Column::new(other_col.clone())),
+                Operator::Add,
+                one.clone(),
+            )),
+        )) as _;
+
+        let (converted, refs) = convert_to_pruning_expression(&gt_sum);
+        assert_eq!(
+            refs,
+            HashMap::from_iter([
+                (column.clone(), vec![Stat::Max]),
+                (other_col.clone(), vec![Stat::Min])
+            ])
+        );
+        let expected_expr: Arc<dyn VortexExpr> = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(stat_column_name(&column, Stat::Max))),
+            Operator::Lte,
+            Arc::new(BinaryExpr::new(
+                Arc::new(Column::new(stat_column_name(&other_col, Stat::Min))),
+                Operator::Add,
+                one.clone(),
+            )),
+        ));
+        assert_eq!(*converted, *expected_expr.as_any());
+
+        // Subtracting a column turns its minimum into the maximum of the difference
+        let gt_difference = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(column.clone())),
+            Operator::Gt,
+            Arc::new(BinaryExpr::new(
+                one.clone(),
+                Operator::Sub,
+                Arc::new(Column::new(other_col.clone())),
+            )),
+        )) as _;
+        let (converted, refs) = convert_to_pruning_expression(&gt_difference);
+        assert!(refs.is_empty());
+        let fallback: Arc<dyn VortexExpr> =
+            Arc::new(Literal::new(Scalar::bool(false, Nullability::NonNullable)));
+        assert_eq!(*converted, *fallback.as_any());
+
+        // Arithmetic on the column itself can't be pruned
+        let sum_lt = Arc::new(BinaryExpr::new(
+            one,
+            Operator::Sub,
+            Arc::new(Column::new(column)),
+        )) as _;
+        let lt_sum = Arc::new(BinaryExpr::new(
+            Arc::new(Literal::new(5.into())),
+            Operator::Lt,
+            sum_lt,
+        )) as _;
+        let (converted, refs) = convert_to_pruning_expression(&lt_sum);
+        assert!(refs.is_empty());
+        assert_eq!(*converted, *fallback.as_any());
+    }
+
     #[test]
     pub fn pruning_gt_value() {
         let column = Field::from("a");
@@ -392,6 +808,36 @@ mod tests {
         assert_eq!(*converted, *expected_expr.as_any());
     }
 
+    #[test]
+    pub fn pruning_gte_lte_value() {
+        let column = Field::from("a");
+        let value = Arc::new(Literal::new(42.into()));
+        let pruned = |op| {
+            convert_to_pruning_expression(
+                &(Arc::new(BinaryExpr::new(
+                    Arc::new(Column::new(column.clone())),
+                    op,
+                    value.clone(),
+                )) as _),
+            )
+            .0
+        };
+
+        // Chunks whose max or min equals the value still contain matching rows
+        let expected_gte: Arc<dyn VortexExpr> = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(stat_column_name(&column, Stat::Max))),
+            Operator::Lt,
+            value.clone(),
+        ));
+        assert_eq!(*pruned(Operator::Gte), *expected_gte.as_any());
+        let expected_lte: Arc<dyn VortexExpr> = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(stat_column_name(&column, Stat::Min))),
+            Operator::Gt,
+            value.clone(),
+        ));
+        assert_eq!(*pruned(Operator::Lte), *expected_lte.as_any());
+    }
+
     #[test]
     pub fn pruning_lt_column() {
         let column = Field::from("a");
@@ -441,4 +887,178 @@ mod tests {
         ));
         assert_eq!(*converted, *expected_expr.as_any());
     }
+
+    #[test]
+    pub fn nested_columns_arent_pruned() {
+        let nested = Arc::new(Column::new_nested(FieldPath::from(vec![
+            Field::from("a"),
+            Field::from("b"),
+        ])));
+        let lt_expr = Arc::new(BinaryExpr::new(
+            nested,
+            Operator::Lt,
+            Arc::new(Literal::new(42.into())),
+        )) as _;
+
+        let (converted, refs) = convert_to_pruning_expression(&lt_expr);
+        assert!(refs.is_empty());
+        let expected_expr: Arc<dyn VortexExpr> =
+            Arc::new(Literal::new(Scalar::bool(false, Nullability::NonNullable)));
+        assert_eq!(*converted, *expected_expr.as_any());
+    }
+
+    #[test]
+    pub fn pruning_and_or() {
+        let column = Field::from("a");
+        let lt_expr: Arc<dyn VortexExpr> = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(column.clone())),
+            Operator::Lt,
+            Arc::new(Literal::new(10.into())),
+        ));
+        let gt_expr: Arc<dyn VortexExpr> = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(column.clone())),
+            Operator::Gt,
+            Arc::new(Literal::new(20.into())),
+        ));
+        let or_expr = Arc::new(BinaryExpr::new(
+            lt_expr.clone(),
+            Operator::Or,
+            gt_expr.clone(),
+        )) as _;
+
+        let (converted, refs) = convert_to_pruning_expression(&or_expr);
+        assert_eq!(
+            refs,
+            HashMap::from_iter([(column.clone(), vec![Stat::Min, Stat::Max])])
+        );
+        let expected_expr: Arc<dyn VortexExpr> = Arc::new(BinaryExpr::new(
+            convert_to_pruning_expression(&lt_expr).0,
+            Operator::And,
+            convert_to_pruning_expression(&gt_expr).0,
+        ));
+        assert_eq!(*converted, *expected_expr.as_any());
+    }
+
+    #[test]
+    pub fn prune_chunk_ranges() {
+        let column = Field::from("a");
+        let predicate = PruningPredicate::new(
+            &(Arc::new(BinaryExpr::new(
+                Arc::new(Column::new(column.clone())),
+                Operator::Gt,
+                Arc::new(Literal::new(25.into())),
+            )) as _),
+        );
+        let metadata = StructArray::from_fields(&[
+            (
+                "row_offset",
+                PrimitiveArray::from(vec![0u64, 10, 20]).into_array(),
+            ),
+            ("min", PrimitiveArray::from(vec![0i32, 10, 20]).into_array()),
+            ("max", PrimitiveArray::from(vec![9i32, 19, 29]).into_array()),
+        ])
+        .unwrap()
+        .into_array();
+        // Second column is chunked differently, only the first boundary is shared
+        let other_metadata = StructArray::from_fields(&[(
+            "row_offset",
+            PrimitiveArray::from(vec![0u64, 5, 10, 25]).into_array(),
+        )])
+        .unwrap()
+        .into_array();
+
+        let ranges = pruned_ranges(
+            &predicate,
+            &HashMap::from_iter([(column.clone(), 0)]),
+            &HashMap::from_iter([(0, metadata.clone())]),
+            30,
+        )
+        .unwrap();
+        assert!(ranges.contains(0) && ranges.contains(19) && !ranges.contains(20));
+
+        let ranges = pruned_ranges(
+            &predicate,
+            &HashMap::from_iter([(column, 0)]),
+            &HashMap::from_iter([(0, metadata), (1, other_metadata)]),
+            30,
+        )
+        .unwrap();
+        assert!(ranges.contains(0) && ranges.contains(9) && !ranges.contains(10));
+    }
+
+    #[test]
+    pub fn prune_masked_chunks() {
+        let metadata = StructArray::from_fields(&[(
+            "row_offset",
+            PrimitiveArray::from(vec![0u64, 2, 4, 6]).into_array(),
+        )])
+        .unwrap()
+        .into_array();
+        let mask = BoolArray::from(vec![false, true, false, false, false, false, false, true]);
+
+        let ranges = mask_pruned_ranges(&mask, &HashMap::from_iter([(0, metadata)]), 8).unwrap();
+        assert!(!ranges.contains(1) && ranges.contains(2) && ranges.contains(5));
+        assert!(!ranges.contains(6));
+
+        let ranges = ranges.union(&PrunedRanges::new(vec![0..2, 5..6]));
+        assert!(ranges.contains(0) && ranges.contains(5) && !ranges.contains(6));
+        assert_eq!(
+            ranges
+                .filter_mask(&mask)
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![false, true]
+        );
+    }
+
+    #[test]
+    pub fn prune_is_null_and_in_list() {
+        let column = Field::from("a");
+        let stat_name = |stat| match stat_column_name(&column, stat) {
+            Field::Name(name) => name,
+            Field::Index(_) => unreachable!(),
+        };
+        let (min, max, null_count) = (
+            stat_name(Stat::Min),
+            stat_name(Stat::Max),
+            stat_name(Stat::NullCount),
+        );
+        let stats = StructArray::from_fields(&[
+            (
+                min.as_str(),
+                PrimitiveArray::from(vec![0i32, 10, 20]).into_array(),
+            ),
+            (
+                max.as_str(),
+                PrimitiveArray::from(vec![9i32, 19, 29]).into_array(),
+            ),
+            (
+                null_count.as_str(),
+                PrimitiveArray::from(vec![0u64, 3, 0]).into_array(),
+            ),
+        ])
+        .unwrap()
+        .into_array();
+        let skipped = |expr: Arc<dyn VortexExpr>| {
+            PruningPredicate::new(&expr)
+                .evaluate(&stats)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>()
+        };
+
+        let is_null = Arc::new(IsNull::new(Arc::new(Column::new(column.clone()))));
+        assert_eq!(skipped(is_null), vec![true, false, true]);
+
+        let in_list = Arc::new(InList::new(
+            Arc::new(Column::new(column.clone())),
+            vec![5.into(), 25.into()],
+        ));
+        assert_eq!(skipped(in_list), vec![false, true, false]);
+    }
 }
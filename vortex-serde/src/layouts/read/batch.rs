@@ -1,7 +1,9 @@
 use std::mem;
 use std::sync::Arc;
 
+use rayon::prelude::*;
 use vortex::array::StructArray;
+use vortex::validity::Validity;
 use vortex::{Array, IntoArray};
 use vortex_error::{vortex_err, VortexResult};
 
@@ -12,6 +14,7 @@ pub struct BatchReader {
     names: Arc<[Arc<str>]>,
     children: Vec<Box<dyn LayoutReader>>,
     arrays: Vec<Option<Array>>,
+    has_validity: bool,
 }
 
 impl BatchReader {
@@ -21,24 +24,41 @@ impl BatchReader {
             names,
             children,
             arrays,
+            has_validity: false,
         }
     }
 
+    /// Produce nullable structs, with the validity read from the given layout
+    pub fn with_validity(mut self, validity: Box<dyn LayoutReader>) -> Self {
+        self.children.push(validity);
+        self.arrays.push(None);
+        self.has_validity = true;
+        self
+    }
+
     pub(crate) fn read(&mut self) -> VortexResult<Option<ReadResult>> {
-        let mut messages = Vec::new();
-        for (i, child_array) in self
-            .arrays
-            .iter_mut()
-            .enumerate()
-            .filter(|(_, a)| a.is_none())
-        {
-            match self.children[i].read_next()? {
-                Some(rr) => match rr {
-                    ReadResult::ReadMore(message) => {
-                        messages.extend(message);
+        // Children decode their own messages, every column of the batch is read in parallel
+        let reads = self
+            .children
+            .par_iter_mut()
+            .zip(self.arrays.par_iter_mut())
+            .filter(|(_, array)| array.is_none())
+            .map(|(child, array)| {
+                Ok(match child.read_next()? {
+                    Some(ReadResult::ReadMore(messages)) => Some(messages),
+                    Some(ReadResult::Batch(a)) => {
+                        *array = Some(a);
+                        Some(Vec::new())
                     }
-                    ReadResult::Batch(a) => *child_array = Some(a),
-                },
+                    None => None,
+                })
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        let mut messages = Vec::new();
+        for read in reads {
+            match read {
+                Some(m) => messages.extend(m),
                 None => {
                     debug_assert!(
                         self.arrays.iter().all(Option::is_none),
@@ -50,15 +70,25 @@ impl BatchReader {
         }
 
         if messages.is_empty() {
-            let child_arrays = mem::replace(&mut self.arrays, vec![None; self.children.len()])
+            let mut child_arrays = mem::replace(&mut self.arrays, vec![None; self.children.len()])
                 .into_iter()
                 .enumerate()
                 .map(|(i, a)| a.ok_or_else(|| vortex_err!("Missing child array at index {}", i)))
                 .collect::<VortexResult<Vec<_>>>()?;
-            Ok(Some(ReadResult::Batch(
+            let array = if self.has_validity {
+                let validity = child_arrays
+                    .pop()
+                    .ok_or_else(|| vortex_err!("Missing validity array"))?;
+                StructArray::try_new(
+                    self.names.clone(),
+                    child_arrays,
+                    validity.len(),
+                    Validity::Array(validity),
+                )?
+            } else {
                 StructArray::from_fields(&self.names.iter().zip(child_arrays).collect::<Vec<_>>())?
-                    .into_array(),
-            )))
+            };
+            Ok(Some(ReadResult::Batch(array.into_array())))
         } else {
             Ok(Some(ReadResult::ReadMore(messages)))
         }
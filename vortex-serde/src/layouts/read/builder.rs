@@ -1,16 +1,27 @@
+use std::collections::BTreeSet;
 use std::sync::{Arc, RwLock};
 
-use vortex::{Array, ArrayDType};
-use vortex_error::VortexResult;
+use itertools::Itertools;
+use vortex::aliases::hash_map::HashMap;
+use vortex::array::BoolArray;
+use vortex::{Array, ArrayDType, IntoArrayVariant};
+use vortex_dtype::field::Field;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_panic, VortexResult};
 use vortex_schema::projection::Projection;
+use vortex_schema::Schema;
 
 use crate::io::VortexReadAt;
+use crate::layouts::pruning::{mask_pruned_ranges, pruned_ranges, PrunedRanges, PruningPredicate};
 use crate::layouts::read::cache::{LayoutMessageCache, LazyDeserializedDType, RelativeLayoutCache};
+use crate::layouts::read::coalesce::CoalesceConfig;
 use crate::layouts::read::context::LayoutDeserializer;
 use crate::layouts::read::filtering::RowFilter;
-use crate::layouts::read::footer::LayoutDescriptorReader;
-use crate::layouts::read::stream::LayoutBatchStream;
-use crate::layouts::read::{Scan, DEFAULT_BATCH_SIZE};
+use crate::layouts::read::footer::{LayoutDescriptor, LayoutDescriptorReader};
+use crate::layouts::read::stream::{read_ranges, LayoutBatchStream};
+use crate::layouts::read::{
+    null_as_false, MetadataRead, Scan, DEFAULT_BATCH_SIZE, DEFAULT_READ_AHEAD,
+};
 
 pub struct LayoutReaderBuilder<R> {
     reader: R,
@@ -18,8 +29,12 @@ pub struct LayoutReaderBuilder<R> {
     projection: Option<Projection>,
     size: Option<u64>,
     indices: Option<Array>,
+    row_mask: Option<Array>,
     row_filter: Option<RowFilter>,
     batch_size: Option<usize>,
+    limit: Option<usize>,
+    coalesce: Option<CoalesceConfig>,
+    read_ahead: Option<usize>,
 }
 
 impl<R: VortexReadAt> LayoutReaderBuilder<R> {
@@ -31,7 +46,11 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
             row_filter: None,
             size: None,
             indices: None,
+            row_mask: None,
             batch_size: None,
+            limit: None,
+            coalesce: None,
+            read_ahead: None,
         }
     }
 
@@ -46,7 +65,6 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
     }
 
     pub fn with_indices(mut self, array: Array) -> Self {
-        // TODO(#441): Allow providing boolean masks
         assert!(
             array.dtype().is_int(),
             "Mask arrays have to be integer arrays"
@@ -55,6 +73,18 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         self
     }
 
+    /// Only read the rows that are set in the boolean mask, which must cover every row of the file.
+    ///
+    /// Null entries are treated as unselected. Chunks without any selected rows aren't fetched.
+    pub fn with_row_mask(mut self, mask: Array) -> Self {
+        assert!(
+            matches!(mask.dtype(), DType::Bool(_)),
+            "Row masks have to be boolean arrays"
+        );
+        self.row_mask = Some(mask);
+        self
+    }
+
     pub fn with_row_filter(mut self, row_filter: RowFilter) -> Self {
         self.row_filter = Some(row_filter);
         self
@@ -65,11 +95,37 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         self
     }
 
+    /// Stop reading once `limit` rows have been produced, after filtering.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Override how nearby byte ranges are merged into single reads, by default this is derived
+    /// from the reader's [`VortexReadAt::performance_hint`].
+    pub fn with_coalesce_config(mut self, coalesce: CoalesceConfig) -> Self {
+        self.coalesce = Some(coalesce);
+        self
+    }
+
+    /// Maximum number of batches that are filtered on the thread pool while the next ones are read,
+    /// defaults to [`DEFAULT_READ_AHEAD`].
+    pub fn with_read_ahead(mut self, read_ahead: usize) -> Self {
+        self.read_ahead = Some(read_ahead);
+        self
+    }
+
     pub async fn build(self) -> VortexResult<LayoutBatchStream<R>> {
         let footer = LayoutDescriptorReader::new(self.layout_serde.clone())
             .read_footer(&self.reader, self.size().await as u64)
             .await?;
         let batch_size = self.batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
+        // Never buffer more rows than the limit, so that layouts past the limit aren't fetched.
+        // With a row filter the limit counts filtered rows, so clamping would only shrink the reads.
+        let batch_size = match self.limit {
+            Some(limit) if self.row_filter.is_none() => batch_size.min(limit.max(1)),
+            _ => batch_size,
+        };
         // TODO(robert): Propagate projection immediately instead of delegating to layouts, needs more restructuring
         let footer_dtype = Arc::new(LazyDeserializedDType::from_bytes(
             footer.dtype_bytes()?,
@@ -77,26 +133,89 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         ));
         let read_projection = self.projection.unwrap_or_default();
 
-        let filter_projection = self
-            .row_filter
-            .as_ref()
-            .map(|f| f.references().into_iter().cloned().collect::<Vec<_>>())
-            .map(Projection::from);
+        let is_struct = footer.dtype()?.is_struct();
+        let filter_projection = self.row_filter.as_ref().map(|f| {
+            if is_struct {
+                Projection::from(
+                    f.references()
+                        .into_iter()
+                        .filter_map(|path| path.first())
+                        .unique()
+                        .cloned()
+                        .collect::<Vec<_>>(),
+                )
+            } else {
+                Projection::All
+            }
+        });
 
         let projected_dtype = match read_projection {
             Projection::All => footer.dtype()?,
             Projection::Flat(ref projection) => footer.projected_dtype(projection)?,
+            Projection::Nested(_) => Schema::new(footer.dtype()?)
+                .project(read_projection.clone())?
+                .into(),
+        };
+
+        let message_cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
+        let coalesce = self
+            .coalesce
+            .unwrap_or_else(|| CoalesceConfig::for_reader(&self.reader));
+
+        let row_mask = self
+            .row_mask
+            .map(|mask| {
+                if mask.len() as u64 != footer.row_count()? {
+                    vortex_bail!(
+                        "Row mask of length {} doesn't match the {} rows of the file",
+                        mask.len(),
+                        footer.row_count()?
+                    );
+                }
+                null_as_false(mask.into_bool()?)?.into_bool()
+            })
+            .transpose()?;
+        let predicate = self
+            .row_filter
+            .as_ref()
+            .map(RowFilter::pruning_predicate)
+            .filter(|p| !p.stats_to_fetch().is_empty());
+
+        let (reader, pruned_ranges) = if predicate.is_some() || row_mask.is_some() {
+            let (reader, chunk_metadata) = read_columns_chunk_metadata(
+                self.reader,
+                &footer,
+                footer_dtype.clone(),
+                message_cache.clone(),
+                read_columns(&footer, self.row_filter.as_ref(), &read_projection)?,
+                coalesce,
+            )
+            .await?;
+            let pruned_ranges = chunk_metadata
+                .map(|m| prune_chunks(&footer, &m, predicate.as_ref(), row_mask.as_ref()))
+                .transpose()?
+                .flatten();
+            (reader, pruned_ranges)
+        } else {
+            (self.reader, None)
         };
 
+        // Rows of pruned chunks are never read, the mask only has to cover the remaining rows
+        let row_mask = row_mask
+            .map(|mask| match pruned_ranges.as_ref() {
+                Some(ranges) => ranges.filter_mask(&mask),
+                None => Ok(mask),
+            })
+            .transpose()?;
+
         let scan = Scan {
             filter: self.row_filter.clone(),
             batch_size,
             projection: read_projection,
             indices: self.indices,
+            pruned_ranges: pruned_ranges.clone(),
         };
 
-        let message_cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
-
         let data_reader = footer.layout(
             scan.clone(),
             RelativeLayoutCache::new(message_cache.clone(), footer_dtype.clone()),
@@ -110,6 +229,7 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
                         batch_size,
                         projection,
                         indices: None,
+                        pruned_ranges,
                     },
                     RelativeLayoutCache::new(message_cache.clone(), footer_dtype),
                 )
@@ -117,13 +237,17 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
             .transpose()?;
 
         Ok(LayoutBatchStream::new(
-            self.reader,
+            reader,
             data_reader,
             filter_reader,
             message_cache,
             projected_dtype,
             scan,
-        ))
+            self.limit,
+        )
+        .with_coalesce_config(coalesce)
+        .with_read_ahead(self.read_ahead.unwrap_or(DEFAULT_READ_AHEAD))
+        .with_row_mask(row_mask))
     }
 
     async fn size(&self) -> u64 {
@@ -133,3 +257,139 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         }
     }
 }
+
+/// Every top level column that's read, either by the projection or by the filter.
+///
+/// Chunks have to be skipped consistently in all of them.
+fn read_columns(
+    footer: &LayoutDescriptor,
+    row_filter: Option<&RowFilter>,
+    read_projection: &Projection,
+) -> VortexResult<BTreeSet<usize>> {
+    let DType::Struct(file_dtype, _) = footer.dtype()? else {
+        // Files of non struct arrays consist of a single column
+        return Ok(BTreeSet::from([0]));
+    };
+
+    let mut columns = row_filter
+        .map(|f| f.references())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|path| path.first())
+        .map(|f| file_dtype.field_info(f).map(|info| info.index))
+        .collect::<VortexResult<BTreeSet<_>>>()?;
+    match read_projection.columns(|f| file_dtype.field_info(f).map(|info| info.index))? {
+        None => columns.extend(0..file_dtype.names().len()),
+        Some(projected) => columns.extend(projected.into_iter().map(|(idx, _)| idx)),
+    }
+    Ok(columns)
+}
+
+/// Read the chunk metadata of the given columns, if every one of them describes its chunks.
+///
+/// Metadata messages are left in the message cache for the layouts to reuse.
+async fn read_columns_chunk_metadata<R: VortexReadAt>(
+    reader: R,
+    footer: &LayoutDescriptor,
+    footer_dtype: Arc<LazyDeserializedDType>,
+    message_cache: Arc<RwLock<LayoutMessageCache>>,
+    columns: BTreeSet<usize>,
+    coalesce: CoalesceConfig,
+) -> VortexResult<(R, Option<HashMap<usize, Array>>)> {
+    let projection = if footer.dtype()?.is_struct() {
+        Projection::Flat(columns.iter().copied().map(Field::from).collect())
+    } else {
+        Projection::All
+    };
+    let (reader, metadata) = read_chunk_metadata(
+        reader,
+        footer,
+        footer_dtype,
+        message_cache,
+        projection,
+        coalesce,
+    )
+    .await?;
+    let Some(metadata) = metadata else {
+        return Ok((reader, None));
+    };
+
+    // Can't prune if any of the columns doesn't describe its chunks
+    Ok((
+        reader,
+        columns
+            .into_iter()
+            .zip(metadata)
+            .map(|(c, m)| m.map(|m| (c, m)))
+            .collect::<Option<HashMap<_, _>>>(),
+    ))
+}
+
+/// Determine which rows can't pass the filter or aren't selected by the row mask.
+fn prune_chunks(
+    footer: &LayoutDescriptor,
+    chunk_metadata: &HashMap<usize, Array>,
+    predicate: Option<&PruningPredicate>,
+    row_mask: Option<&BoolArray>,
+) -> VortexResult<Option<Arc<PrunedRanges>>> {
+    let row_count = footer.row_count()?;
+    let mut ranges = PrunedRanges::default();
+
+    if let Some(predicate) = predicate {
+        let DType::Struct(file_dtype, _) = footer.dtype()? else {
+            vortex_bail!("Top level dtype must be a struct")
+        };
+        let filter_columns = predicate
+            .stats_to_fetch()
+            .keys()
+            .map(|f| Ok((f.clone(), file_dtype.field_info(f)?.index)))
+            .collect::<VortexResult<HashMap<_, _>>>()?;
+        ranges = pruned_ranges(predicate, &filter_columns, chunk_metadata, row_count)?;
+    }
+
+    if let Some(row_mask) = row_mask {
+        ranges = ranges.union(&mask_pruned_ranges(row_mask, chunk_metadata, row_count)?);
+    }
+
+    Ok((!ranges.is_empty()).then(|| Arc::new(ranges)))
+}
+
+/// Read the chunk metadata arrays of the projected columns, one per column, if the layout has any.
+///
+/// Messages that are read are left in the message cache for the layouts to reuse.
+pub(crate) async fn read_chunk_metadata<R: VortexReadAt>(
+    mut reader: R,
+    footer: &LayoutDescriptor,
+    footer_dtype: Arc<LazyDeserializedDType>,
+    message_cache: Arc<RwLock<LayoutMessageCache>>,
+    projection: Projection,
+    coalesce: CoalesceConfig,
+) -> VortexResult<(R, Option<Vec<Option<Array>>>)> {
+    let mut metadata_reader = footer.layout(
+        Scan {
+            indices: None,
+            projection,
+            filter: None,
+            batch_size: DEFAULT_BATCH_SIZE,
+            pruned_ranges: None,
+        },
+        RelativeLayoutCache::new(message_cache.clone(), footer_dtype),
+    )?;
+
+    loop {
+        match metadata_reader.read_metadata()? {
+            Some(MetadataRead::ReadMore(messages)) => {
+                let (r, buffers) = read_ranges(reader, messages, coalesce).await?;
+                reader = r;
+                let mut cache_guard = message_cache.write().unwrap_or_else(|poison| {
+                    vortex_panic!("Failed to write to message cache: {poison}")
+                });
+                for (message_id, buf) in buffers {
+                    cache_guard.set(message_id, buf);
+                }
+            }
+            Some(MetadataRead::Batches(batches)) => return Ok((reader, Some(batches))),
+            None => return Ok((reader, None)),
+        }
+    }
+}
@@ -10,6 +10,7 @@ use vortex_dtype::DType;
 use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexResult};
 use vortex_flatbuffers::message;
 use vortex_schema::projection::Projection;
+use vortex_schema::Schema;
 
 use crate::layouts::read::{LayoutPartId, MessageId};
 
@@ -72,7 +73,9 @@ impl LazyDeserializedDType {
                 let projection = match proj {
                     Projection::All => Projection::Flat(projection.to_vec()),
                     // TODO(robert): Respect existing projection list, only really an issue for nested structs
-                    Projection::Flat(_) => vortex_bail!("Can't project already projected dtype"),
+                    Projection::Flat(_) | Projection::Nested(_) => {
+                        vortex_bail!("Can't project already projected dtype")
+                    }
                 };
                 Ok(Arc::new(LazyDeserializedDType::from_bytes(
                     b.clone(),
@@ -94,6 +97,10 @@ impl LazyDeserializedDType {
                     Projection::All => DType::try_from(fb_dtype)
                         .map_err(|e| vortex_err!(InvalidSerde: "Failed to parse DType: {e}")),
                     Projection::Flat(p) => deserialize_and_project(fb_dtype, p),
+                    Projection::Nested(_) => DType::try_from(fb_dtype)
+                        .map_err(|e| vortex_err!(InvalidSerde: "Failed to parse DType: {e}"))
+                        .and_then(|dtype| Schema::new(dtype).project(proj.clone()))
+                        .map(DType::from),
                 }
             }),
         }
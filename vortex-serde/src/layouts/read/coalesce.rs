@@ -0,0 +1,147 @@
+use bytes::Bytes;
+
+use crate::io::VortexReadAt;
+use crate::layouts::read::{Message, MessageId, INITIAL_READ_SIZE};
+use crate::stream_writer::ByteRange;
+
+/// Limits for merging the byte ranges of nearby messages into a single read.
+///
+/// Two ranges are read together if the gap between them is at most `max_gap` bytes and the
+/// merged read doesn't exceed `max_size` bytes. A single message larger than `max_size` is still
+/// read in one request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoalesceConfig {
+    pub max_gap: u64,
+    pub max_size: u64,
+}
+
+impl CoalesceConfig {
+    pub fn new(max_gap: u64, max_size: u64) -> Self {
+        Self { max_gap, max_size }
+    }
+
+    /// Coalesce reads according to the [`VortexReadAt::performance_hint`] of the reader.
+    pub fn for_reader<R: VortexReadAt>(reader: &R) -> Self {
+        Self::new(reader.performance_hint() as u64, INITIAL_READ_SIZE as u64)
+    }
+}
+
+/// A single read covering the byte ranges of one or more messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CoalescedRead {
+    pub(crate) range: ByteRange,
+    pub(crate) messages: Vec<Message>,
+}
+
+impl CoalescedRead {
+    /// Split the bytes of the merged range back into the bytes of each message.
+    pub(crate) fn split(self, bytes: Bytes) -> Vec<(MessageId, Bytes)> {
+        let begin = self.range.begin;
+        self.messages
+            .into_iter()
+            .map(|(id, range)| {
+                (
+                    id,
+                    bytes.slice((range.begin - begin) as usize..(range.end - begin) as usize),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Plan the reads for the given messages, merging ranges that are close to each other.
+pub(crate) fn coalesce_ranges(
+    mut messages: Vec<Message>,
+    config: CoalesceConfig,
+) -> Vec<CoalescedRead> {
+    messages.sort_by_key(|(_, range)| range.begin);
+
+    let mut reads: Vec<CoalescedRead> = Vec::new();
+    for (id, range) in messages {
+        match reads.last_mut() {
+            Some(read)
+                if range.begin <= read.range.end.saturating_add(config.max_gap)
+                    && range.end.max(read.range.end) - read.range.begin <= config.max_size =>
+            {
+                read.range.end = read.range.end.max(range.end);
+                read.messages.push((id, range));
+            }
+            _ => reads.push(CoalescedRead {
+                range,
+                messages: vec![(id, range)],
+            }),
+        }
+    }
+    reads
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use crate::layouts::read::coalesce::{coalesce_ranges, CoalesceConfig};
+    use crate::stream_writer::ByteRange;
+
+    #[test]
+    fn merges_within_gap() {
+        let reads = coalesce_ranges(
+            vec![
+                (vec![2], ByteRange::new(40, 50)),
+                (vec![0], ByteRange::new(0, 10)),
+                (vec![1], ByteRange::new(10, 20)),
+                (vec![3], ByteRange::new(100, 110)),
+            ],
+            CoalesceConfig::new(20, 1024),
+        );
+        assert_eq!(
+            reads.iter().map(|r| r.range).collect::<Vec<_>>(),
+            vec![ByteRange::new(0, 50), ByteRange::new(100, 110)]
+        );
+        assert_eq!(
+            reads[0]
+                .messages
+                .iter()
+                .map(|(id, _)| id[0])
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn respects_max_size() {
+        let reads = coalesce_ranges(
+            vec![
+                (vec![0], ByteRange::new(0, 10)),
+                (vec![1], ByteRange::new(10, 20)),
+                (vec![2], ByteRange::new(20, 60)),
+            ],
+            CoalesceConfig::new(0, 20),
+        );
+        assert_eq!(
+            reads.iter().map(|r| r.range).collect::<Vec<_>>(),
+            vec![ByteRange::new(0, 20), ByteRange::new(20, 60)]
+        );
+    }
+
+    #[test]
+    fn split_back_into_messages() {
+        let mut reads = coalesce_ranges(
+            vec![
+                (vec![0], ByteRange::new(2, 4)),
+                (vec![1], ByteRange::new(6, 9)),
+            ],
+            CoalesceConfig::new(4, 1024),
+        );
+        assert_eq!(reads.len(), 1);
+        let read = reads.remove(0);
+        let bytes = Bytes::from_static(b"abcdefg");
+        assert_eq!(read.range, ByteRange::new(2, 9));
+        assert_eq!(
+            read.split(bytes),
+            vec![
+                (vec![0], Bytes::from_static(b"ab")),
+                (vec![1], Bytes::from_static(b"efg"))
+            ]
+        );
+    }
+}
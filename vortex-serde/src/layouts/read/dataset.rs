@@ -0,0 +1,1292 @@
+#![cfg(feature = "object_store")]
+
+use std::iter;
+use std::sync::Arc;
+
+use arrow_buffer::BooleanBufferBuilder;
+use futures::channel::mpsc;
+use futures::SinkExt;
+use futures_util::stream::BoxStream;
+use futures_util::{stream, StreamExt, TryStreamExt};
+use object_store::path::Path;
+use object_store::ObjectStore;
+use vortex::array::{BoolArray, ConstantArray, StructArray, VarBinArray};
+use vortex::arrow::FromArrowArray;
+use vortex::compute::unary::try_cast;
+use vortex::stream::ArrayStreamAdapter;
+use vortex::validity::Validity;
+use vortex::variants::StructArrayTrait;
+use vortex::{Array, ArrayDType, Canonical, IntoArray, IntoArrayVariant, IntoCanonical};
+use vortex_dtype::field::Field;
+use vortex_dtype::{DType, FieldName, Nullability, StructDType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_expr::VortexExpr;
+use vortex_schema::projection::Projection;
+use vortex_schema::Schema;
+
+use crate::io::ObjectStoreReadAt;
+use crate::layouts::read::builder::LayoutReaderBuilder;
+use crate::layouts::read::context::LayoutDeserializer;
+use crate::layouts::read::filtering::RowFilter;
+use crate::layouts::read::footer::LayoutDescriptorReader;
+
+/// Number of file footers that are fetched at the same time when opening a dataset
+const OPEN_CONCURRENCY: usize = 16;
+
+/// Number of batches a file that is read concurrently buffers ahead of the consumer
+const READ_AHEAD_BATCHES: usize = 1;
+
+/// A table stored as many Vortex files on an object store.
+///
+/// All files must have the same dtype, up to the nullability of their fields. A field that is
+/// nullable in any of the files is nullable in the dataset.
+///
+/// Datasets opened with [`VortexDataset::try_new_partitioned`] additionally have a non-nullable
+/// utf8 column for every hive style partition key in the file paths, which is produced as a
+/// [`ConstantArray`] in each batch.
+#[derive(Debug, Clone)]
+pub struct VortexDataset {
+    object_store: Arc<dyn ObjectStore>,
+    layout_serde: LayoutDeserializer,
+    files: Vec<DatasetFile>,
+    partition_keys: Vec<FieldName>,
+    file_dtype: DType,
+    dtype: DType,
+}
+
+#[derive(Debug, Clone)]
+struct DatasetFile {
+    path: Path,
+    size: u64,
+    row_count: u64,
+    partition_values: Vec<String>,
+}
+
+/// A file that's listed but whose footer hasn't been read yet
+struct ListedFile {
+    path: Path,
+    size: Option<u64>,
+    partition_values: Vec<String>,
+}
+
+impl ListedFile {
+    fn new(path: Path, size: Option<u64>) -> Self {
+        Self {
+            path,
+            size,
+            partition_values: Vec::new(),
+        }
+    }
+}
+
+impl VortexDataset {
+    /// Open the given files, in the order they are read.
+    pub async fn try_new(
+        object_store: Arc<dyn ObjectStore>,
+        paths: impl IntoIterator<Item = Path>,
+        layout_serde: LayoutDeserializer,
+    ) -> VortexResult<Self> {
+        let files = paths
+            .into_iter()
+            .map(|p| ListedFile::new(p, None))
+            .collect();
+        Self::open(object_store, files, Vec::new(), layout_serde).await
+    }
+
+    /// Open every file whose path matches the glob, in lexicographic order.
+    ///
+    /// `?` matches a single character and `*` any number of characters within one path segment,
+    /// while `**` also matches across segments.
+    pub async fn try_new_glob(
+        object_store: Arc<dyn ObjectStore>,
+        pattern: &str,
+        layout_serde: LayoutDeserializer,
+    ) -> VortexResult<Self> {
+        let files = list_glob(&object_store, pattern).await?;
+        Self::open(object_store, files, Vec::new(), layout_serde).await
+    }
+
+    /// Open every file matching the glob whose directories are hive style partitions, such as
+    /// `date=2024-10-01/region=eu/part-0.vortex`.
+    ///
+    /// The predicates of `partition_filter` that only reference partition keys are evaluated
+    /// against the paths, the footers of files that can't pass them are never read. Predicates on
+    /// any other column are ignored.
+    pub async fn try_new_partitioned(
+        object_store: Arc<dyn ObjectStore>,
+        pattern: &str,
+        partition_filter: Option<&RowFilter>,
+        layout_serde: LayoutDeserializer,
+    ) -> VortexResult<Self> {
+        let mut files = list_glob(&object_store, pattern).await?;
+        let mut partition_keys: Option<Vec<FieldName>> = None;
+        for file in files.iter_mut() {
+            let (keys, values) = hive_partitions(&file.path);
+            match partition_keys.as_ref() {
+                None => partition_keys = Some(keys),
+                Some(expected) if *expected != keys => vortex_bail!(
+                    "Partition keys of {} don't match the keys {:?} of the other files",
+                    file.path,
+                    expected
+                ),
+                Some(_) => {}
+            }
+            file.partition_values = values;
+        }
+        let partition_keys = partition_keys.unwrap_or_default();
+
+        let partition_filter = partition_filter
+            .map(|f| split_partition_filter(f, &partition_keys))
+            .transpose()?
+            .and_then(|(partition_filter, _)| partition_filter);
+        if let Some(filter) = partition_filter {
+            let selected = select_partitions(
+                &partition_keys,
+                files.iter().map(|f| f.partition_values.as_slice()),
+                &filter,
+            )?;
+            files = files
+                .into_iter()
+                .zip(selected)
+                .filter_map(|(file, selected)| selected.then_some(file))
+                .collect();
+        }
+        if files.is_empty() {
+            vortex_bail!("No partitions of {pattern} pass the filter");
+        }
+
+        Self::open(object_store, files, partition_keys, layout_serde).await
+    }
+
+    async fn open(
+        object_store: Arc<dyn ObjectStore>,
+        files: Vec<ListedFile>,
+        partition_keys: Vec<FieldName>,
+        layout_serde: LayoutDeserializer,
+    ) -> VortexResult<Self> {
+        let footers = stream::iter(files)
+            .map(|file| {
+                let object_store = object_store.clone();
+                let footer_reader = LayoutDescriptorReader::new(layout_serde.clone());
+                async move {
+                    let size = match file.size {
+                        Some(size) => size,
+                        None => object_store.head(&file.path).await?.size as u64,
+                    };
+                    let footer = footer_reader
+                        .read_footer(
+                            &ObjectStoreReadAt::new(object_store, file.path.clone()),
+                            size,
+                        )
+                        .await?;
+                    let file = DatasetFile {
+                        path: file.path,
+                        size,
+                        row_count: footer.row_count()?,
+                        partition_values: file.partition_values,
+                    };
+                    VortexResult::Ok((file, footer.dtype()?))
+                }
+            })
+            .buffered(OPEN_CONCURRENCY)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let mut files = Vec::with_capacity(footers.len());
+        let mut file_dtype: Option<DType> = None;
+        for (file, dtype) in footers {
+            file_dtype = Some(match file_dtype {
+                None => dtype,
+                Some(file_dtype) => match unify_dtypes(&file_dtype, &dtype) {
+                    Some(unified) => unified,
+                    None => vortex_bail!(
+                        "Dtype {dtype} of {} doesn't match the dataset dtype {file_dtype}",
+                        file.path
+                    ),
+                },
+            });
+            files.push(file);
+        }
+        let Some(file_dtype) = file_dtype else {
+            vortex_bail!("A dataset must consist of at least one file");
+        };
+
+        let dtype = if partition_keys.is_empty() {
+            file_dtype.clone()
+        } else {
+            let DType::Struct(file_struct, nullability) = &file_dtype else {
+                vortex_bail!(
+                    "Partitioned datasets must consist of struct arrays, found {file_dtype}"
+                )
+            };
+            if let Some(key) = partition_keys
+                .iter()
+                .find(|k| file_struct.find_name(k).is_some())
+            {
+                vortex_bail!("Partition key {key} is also a column of the files");
+            }
+            let names = file_struct
+                .names()
+                .iter()
+                .chain(partition_keys.iter())
+                .cloned()
+                .collect();
+            let dtypes = file_struct
+                .dtypes()
+                .iter()
+                .cloned()
+                .chain(
+                    partition_keys
+                        .iter()
+                        .map(|_| DType::Utf8(Nullability::NonNullable)),
+                )
+                .collect();
+            DType::Struct(StructDType::new(names, dtypes), *nullability)
+        };
+
+        Ok(Self {
+            object_store,
+            layout_serde,
+            files,
+            partition_keys,
+            file_dtype,
+            dtype,
+        })
+    }
+
+    pub fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    /// Paths of the files of the dataset, in the order they are read.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(|f| &f.path)
+    }
+
+    /// Names of the hive style partition columns, which follow the columns of the files.
+    pub fn partition_keys(&self) -> &[FieldName] {
+        &self.partition_keys
+    }
+
+    /// Total number of rows across all files
+    pub fn row_count(&self) -> u64 {
+        self.files.iter().map(|f| f.row_count).sum()
+    }
+
+    pub fn reader(&self) -> DatasetReaderBuilder {
+        DatasetReaderBuilder::new(self.clone())
+    }
+
+    /// Resolve a projection of the dataset into the projection of the files and where each of the
+    /// produced columns comes from.
+    fn split_projection(
+        &self,
+        projection: &Projection,
+    ) -> VortexResult<(Projection, Vec<OutputColumn>)> {
+        let file_fields = self.file_dtype.as_struct().map_or(0, |s| s.names().len());
+        let partition = |field: &Field| match field {
+            Field::Name(name) => self.partition_keys.iter().position(|k| **k == **name),
+            Field::Index(idx) => idx
+                .checked_sub(file_fields)
+                .filter(|idx| *idx < self.partition_keys.len()),
+        };
+
+        Ok(match projection {
+            Projection::All => (
+                Projection::All,
+                iter::repeat(OutputColumn::File)
+                    .take(file_fields)
+                    .chain((0..self.partition_keys.len()).map(OutputColumn::Partition))
+                    .collect(),
+            ),
+            Projection::Flat(fields) => {
+                let columns = fields
+                    .iter()
+                    .map(|f| partition(f).map_or(OutputColumn::File, OutputColumn::Partition))
+                    .collect();
+                let file_fields = fields.iter().filter(|f| partition(f).is_none()).cloned();
+                (Projection::Flat(file_fields.collect()), columns)
+            }
+            Projection::Nested(paths) if paths.iter().any(|path| path.path().is_empty()) => {
+                // The root path selects everything
+                self.split_projection(&Projection::All)?
+            }
+            Projection::Nested(paths) => {
+                // Nested projections produce columns in order of their first reference, like
+                // `Projection::columns`
+                let file_struct = self.file_dtype.as_struct();
+                let mut columns = Vec::new();
+                let mut seen_partitions = Vec::new();
+                let mut seen_files = Vec::new();
+                let mut file_paths = Vec::new();
+                for path in paths {
+                    let first = &path.path()[0];
+                    match partition(first) {
+                        Some(idx) if path.path().len() == 1 => {
+                            if !seen_partitions.contains(&idx) {
+                                seen_partitions.push(idx);
+                                columns.push(OutputColumn::Partition(idx));
+                            }
+                        }
+                        Some(_) => vortex_bail!("Partition column {path} has no nested fields"),
+                        None => {
+                            let idx = file_struct
+                                .map(|s| s.field_info(first).map(|info| info.index))
+                                .transpose()?;
+                            if !seen_files.contains(&idx) {
+                                seen_files.push(idx);
+                                columns.push(OutputColumn::File);
+                            }
+                            file_paths.push(path.clone());
+                        }
+                    }
+                }
+                (Projection::Nested(file_paths), columns)
+            }
+        })
+    }
+}
+
+/// Where a column of the batches of a partitioned dataset comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputColumn {
+    /// The next column of the batch read from the file
+    File,
+    /// The value of the partition key with this index
+    Partition(usize),
+}
+
+/// Reads a [`VortexDataset`] as a single [`ArrayStream`](vortex::stream::ArrayStream).
+///
+/// Batches never span files and are produced in file order.
+pub struct DatasetReaderBuilder {
+    dataset: VortexDataset,
+    projection: Option<Projection>,
+    indices: Option<Array>,
+    row_filter: Option<RowFilter>,
+    batch_size: Option<usize>,
+    concurrency: usize,
+}
+
+impl DatasetReaderBuilder {
+    pub fn new(dataset: VortexDataset) -> Self {
+        Self {
+            dataset,
+            projection: None,
+            indices: None,
+            row_filter: None,
+            batch_size: None,
+            concurrency: 1,
+        }
+    }
+
+    pub fn with_projection(mut self, projection: Projection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Only read the rows at the given positions, counting the rows of all files in order.
+    ///
+    /// Rows are produced in dataset order, once each, regardless of the order of the indices.
+    pub fn with_indices(mut self, array: Array) -> Self {
+        assert!(
+            array.dtype().is_int(),
+            "Mask arrays have to be integer arrays"
+        );
+        self.indices = Some(array);
+        self
+    }
+
+    /// Only produce the rows that pass the filter.
+    ///
+    /// Predicates on partition columns skip whole files, they can't be combined with file columns
+    /// in a single predicate.
+    pub fn with_row_filter(mut self, row_filter: RowFilter) -> Self {
+        self.row_filter = Some(row_filter);
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Number of files that are read at the same time, defaults to one.
+    ///
+    /// Files after the one whose batches are being produced only read ahead by a bounded number of
+    /// batches, so memory use doesn't grow with the size of the files.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn build(
+        self,
+    ) -> VortexResult<ArrayStreamAdapter<BoxStream<'static, VortexResult<Array>>>> {
+        let projection = self.projection.unwrap_or_default();
+        let dtype: DType = Schema::new(self.dataset.dtype.clone())
+            .project(projection.clone())?
+            .into();
+        let (file_projection, columns) = self.dataset.split_projection(&projection)?;
+        // Layouts can't produce batches without any columns, read the first one for the row count
+        let file_projection = if columns.contains(&OutputColumn::File) || columns.is_empty() {
+            file_projection
+        } else {
+            Projection::Flat(vec![Field::Index(0)])
+        };
+        let file_dtype: DType = Schema::new(self.dataset.file_dtype.clone())
+            .project(file_projection.clone())?
+            .into();
+
+        let (partition_filter, row_filter) = self
+            .row_filter
+            .map(|f| split_partition_filter(&f, &self.dataset.partition_keys))
+            .transpose()?
+            .unwrap_or_default();
+        let mut selected = match partition_filter {
+            Some(filter) => select_partitions(
+                &self.dataset.partition_keys,
+                self.dataset
+                    .files
+                    .iter()
+                    .map(|f| f.partition_values.as_slice()),
+                &filter,
+            )?,
+            None => vec![true; self.dataset.files.len()],
+        };
+        let masks = self
+            .indices
+            .map(|indices| file_masks(&self.dataset.files, indices))
+            .transpose()?;
+        if let Some(masks) = masks.as_ref() {
+            // Files without any selected rows aren't opened
+            for (selected, mask) in selected.iter_mut().zip(masks) {
+                *selected &= mask.is_some();
+            }
+        }
+
+        let object_store = self.dataset.object_store;
+        let layout_serde = self.dataset.layout_serde;
+        let batch_size = self.batch_size;
+        let partitioned = !self.dataset.partition_keys.is_empty();
+        let stream_dtype = dtype.clone();
+        let files = self
+            .dataset
+            .files
+            .into_iter()
+            .zip(masks.map_or_else(|| vec![None; selected.len()], |m| m))
+            .zip(selected)
+            .filter_map(|(file, selected)| selected.then_some(file))
+            .map(move |(file, mask)| {
+                let mut builder = LayoutReaderBuilder::new(
+                    ObjectStoreReadAt::new(object_store.clone(), file.path),
+                    layout_serde.clone(),
+                )
+                .with_size(file.size)
+                .with_projection(file_projection.clone());
+                if let Some(mask) = mask {
+                    builder = builder.with_row_mask(mask);
+                }
+                if let Some(row_filter) = row_filter.clone() {
+                    builder = builder.with_row_filter(row_filter);
+                }
+                if let Some(batch_size) = batch_size {
+                    builder = builder.with_batch_size(batch_size);
+                }
+                let file_dtype = file_dtype.clone();
+                let dtype = dtype.clone();
+                let columns = columns.clone();
+                let partition_values = file.partition_values;
+                async move {
+                    let batches = builder.build().await?;
+                    VortexResult::Ok(batches.and_then(move |batch| {
+                        futures::future::ready(widen_nullability(batch, &file_dtype).and_then(
+                            |batch| {
+                                if partitioned {
+                                    with_partitions(batch, &columns, &partition_values, &dtype)
+                                } else {
+                                    Ok(batch)
+                                }
+                            },
+                        ))
+                    }))
+                }
+            });
+
+        let batches = if self.concurrency == 1 {
+            stream::iter(files).then(|file| file).try_flatten().boxed()
+        } else {
+            // Every file forwards its batches into a bounded channel, the drivers of the next
+            // files run while the batches of the current file are consumed
+            let (receivers, drivers): (Vec<_>, Vec<_>) = files
+                .map(|file| {
+                    let (mut sender, receiver) = mpsc::channel(READ_AHEAD_BATCHES);
+                    let driver = async move {
+                        let mut batches = match file.await {
+                            Ok(batches) => batches,
+                            Err(e) => {
+                                let _ = sender.send(Err(e)).await;
+                                return;
+                            }
+                        };
+                        while let Some(batch) = batches.next().await {
+                            if sender.send(batch).await.is_err() {
+                                // The consumer went away
+                                return;
+                            }
+                        }
+                    };
+                    (receiver, driver)
+                })
+                .unzip();
+            let drivers = stream::iter(drivers)
+                .buffer_unordered(self.concurrency)
+                .filter_map(|()| futures::future::ready(None));
+            stream::select(stream::iter(receivers).flatten().map(Some), drivers)
+                .filter_map(futures::future::ready)
+                .boxed()
+        };
+        Ok(ArrayStreamAdapter::new(stream_dtype, batches))
+    }
+}
+
+/// Assemble a batch of a partitioned dataset from the batch of a file and the partition values of
+/// the file.
+fn with_partitions(
+    batch: Array,
+    columns: &[OutputColumn],
+    partition_values: &[String],
+    dtype: &DType,
+) -> VortexResult<Array> {
+    let DType::Struct(struct_dtype, _) = dtype else {
+        vortex_bail!("Partitioned datasets must consist of struct arrays, found {dtype}")
+    };
+    let batch = batch.into_struct()?;
+    let len = batch.len();
+    let mut file_columns = batch.children();
+    let fields = columns
+        .iter()
+        .map(|column| match column {
+            OutputColumn::File => file_columns
+                .next()
+                .ok_or_else(|| vortex_err!("Missing column in batch of {}", batch.dtype())),
+            OutputColumn::Partition(idx) => {
+                Ok(ConstantArray::new(partition_values[*idx].as_str(), len).into_array())
+            }
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
+    Ok(
+        StructArray::try_new(struct_dtype.names().clone(), fields, len, batch.validity())?
+            .into_array(),
+    )
+}
+
+/// Split a filter into the predicates that only reference partition keys and the predicates on
+/// the columns of the files.
+fn split_partition_filter(
+    filter: &RowFilter,
+    partition_keys: &[FieldName],
+) -> VortexResult<(Option<RowFilter>, Option<RowFilter>)> {
+    let is_partition = |field: &Field| match field {
+        Field::Name(name) => partition_keys.iter().any(|k| **k == **name),
+        Field::Index(_) => false,
+    };
+    // Partition keys are top level columns without nested fields
+    let is_partition_path = |path: &[Field]| matches!(path, [field] if is_partition(field));
+    let references_partition = |expr: &Arc<dyn VortexExpr>| {
+        let references = expr.references();
+        let partitions = references
+            .iter()
+            .filter(|path| is_partition_path(path))
+            .count();
+        (partitions > 0, partitions == references.len())
+    };
+
+    let (partition_filter, file_filter) =
+        filter.split(|expr| references_partition(expr) == (true, true));
+    if file_filter
+        .as_ref()
+        .is_some_and(|f| f.references().into_iter().any(is_partition_path))
+    {
+        vortex_bail!("Predicates can't reference both partition keys and file columns");
+    }
+    Ok((partition_filter, file_filter))
+}
+
+/// Evaluate a filter over partition keys against the partition values of each file.
+fn select_partitions<'a>(
+    partition_keys: &[FieldName],
+    partition_values: impl Iterator<Item = &'a [String]>,
+    filter: &RowFilter,
+) -> VortexResult<Vec<bool>> {
+    let partition_values = partition_values.collect::<Vec<_>>();
+    let columns = (0..partition_keys.len())
+        .map(|idx| {
+            VarBinArray::from_vec(
+                partition_values
+                    .iter()
+                    .map(|values| values[idx].as_str())
+                    .collect(),
+                DType::Utf8(Nullability::NonNullable),
+            )
+            .into_array()
+        })
+        .collect::<Vec<_>>();
+    let partitions = StructArray::try_new(
+        partition_keys.to_vec().into(),
+        columns,
+        partition_values.len(),
+        Validity::NonNullable,
+    )?;
+    let selected = filter.evaluate(partitions.as_ref())?.into_bool()?;
+    Ok(selected.boolean_buffer().iter().collect())
+}
+
+/// Keys and values of the `key=value` directories of the path.
+fn hive_partitions(path: &Path) -> (Vec<FieldName>, Vec<String>) {
+    let mut parts = path.parts().collect::<Vec<_>>();
+    // The last part is the name of the file
+    parts.pop();
+    parts
+        .iter()
+        .filter_map(|part| {
+            part.as_ref()
+                .split_once('=')
+                .map(|(key, value)| (FieldName::from(key), value.to_string()))
+        })
+        .unzip()
+}
+
+/// List the files matching the glob, in lexicographic order.
+async fn list_glob(
+    object_store: &Arc<dyn ObjectStore>,
+    pattern: &str,
+) -> VortexResult<Vec<ListedFile>> {
+    let prefix = glob_prefix(pattern).map(Path::from);
+    let mut files = object_store
+        .list(prefix.as_ref())
+        .try_filter(|meta| {
+            futures::future::ready(glob_match(
+                pattern.as_bytes(),
+                meta.location.as_ref().as_bytes(),
+            ))
+        })
+        .map_ok(|meta| ListedFile::new(meta.location, Some(meta.size as u64)))
+        .try_collect::<Vec<_>>()
+        .await?;
+    if files.is_empty() {
+        vortex_bail!("No files match {pattern}");
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+/// Split row indices of the whole dataset into a row mask per file, or `None` for files without any
+/// selected rows.
+fn file_masks(files: &[DatasetFile], indices: Array) -> VortexResult<Vec<Option<Array>>> {
+    let indices = try_cast(indices.into_primitive()?, &DType::IDX)?.into_primitive()?;
+    let mut indices = indices.maybe_null_slice::<u64>().to_vec();
+    indices.sort_unstable();
+
+    let row_count = files.iter().map(|f| f.row_count).sum::<u64>();
+    if let Some(&last) = indices.last() {
+        if last >= row_count {
+            vortex_bail!(OutOfBounds: last as usize, 0, row_count as usize);
+        }
+    }
+
+    let mut file_start = 0;
+    let mut remaining = indices.as_slice();
+    Ok(files
+        .iter()
+        .map(|file| {
+            let file_end = file_start + file.row_count;
+            let split = remaining.partition_point(|&i| i < file_end);
+            let (selected, rest) = remaining.split_at(split);
+            remaining = rest;
+
+            let mask = (!selected.is_empty()).then(|| {
+                let mut mask = BooleanBufferBuilder::new(file.row_count as usize);
+                mask.append_n(file.row_count as usize, false);
+                for &i in selected {
+                    mask.set_bit((i - file_start) as usize, true);
+                }
+                BoolArray::from(mask.finish()).into_array()
+            });
+            file_start = file_end;
+            mask
+        })
+        .collect())
+}
+
+/// The dtype that can hold the values of both dtypes, if they only differ in nullability.
+fn unify_dtypes(a: &DType, b: &DType) -> Option<DType> {
+    let nullability = Nullability::from(a.is_nullable() || b.is_nullable());
+    match (a, b) {
+        (DType::Struct(a_struct, _), DType::Struct(b_struct, _)) => {
+            if a_struct.names() != b_struct.names() {
+                return None;
+            }
+            let dtypes = a_struct
+                .dtypes()
+                .iter()
+                .zip(b_struct.dtypes().iter())
+                .map(|(a, b)| unify_dtypes(a, b))
+                .collect::<Option<Vec<_>>>()?;
+            Some(DType::Struct(
+                StructDType::new(a_struct.names().clone(), dtypes),
+                nullability,
+            ))
+        }
+        _ => a
+            .eq_ignore_nullability(b)
+            .then(|| a.with_nullability(nullability)),
+    }
+}
+
+/// Convert a batch of a file to the dataset dtype, which can only be more nullable.
+fn widen_nullability(array: Array, dtype: &DType) -> VortexResult<Array> {
+    if array.dtype() == dtype {
+        return Ok(array);
+    }
+
+    let widened = match (array.into_canonical()?, dtype) {
+        (Canonical::Struct(st), DType::Struct(struct_dtype, nullability)) => {
+            let fields = st
+                .children()
+                .zip(struct_dtype.dtypes().iter())
+                .map(|(field, field_dtype)| widen_nullability(field, field_dtype))
+                .collect::<VortexResult<Vec<_>>>()?;
+            let validity = match nullability {
+                Nullability::Nullable => st.validity().into_nullable(),
+                Nullability::NonNullable => Validity::NonNullable,
+            };
+            StructArray::try_new(st.names().clone(), fields, st.len(), validity)?.into_array()
+        }
+        (canonical, _) => Array::from_arrow(canonical.into_arrow()?, dtype.is_nullable()),
+    };
+    if widened.dtype() != dtype {
+        vortex_bail!(MismatchedTypes: dtype, widened.dtype());
+    }
+    Ok(widened)
+}
+
+/// The directory that contains every path matching the glob.
+fn glob_prefix(pattern: &str) -> Option<&str> {
+    let literal = pattern
+        .find(['*', '?'])
+        .map_or(pattern, |wildcard| &pattern[..wildcard]);
+    literal.rfind('/').map(|slash| &literal[..slash])
+}
+
+/// A single element of a glob pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GlobToken {
+    Byte(u8),
+    /// `?`, any byte except `/`
+    AnyByte,
+    /// `*`, any run of bytes without `/`
+    Star,
+    /// `**` not followed by `/`, any run of bytes
+    AnyPath,
+    /// `**/`, any number of whole directories
+    Dirs,
+}
+
+fn glob_tokens(pattern: &[u8]) -> Vec<GlobToken> {
+    let mut tokens = Vec::with_capacity(pattern.len());
+    let mut rest = pattern;
+    while let Some((&c, tail)) = rest.split_first() {
+        rest = match (c, tail) {
+            (b'*', [b'*', b'/', tail @ ..]) => {
+                tokens.push(GlobToken::Dirs);
+                tail
+            }
+            (b'*', [b'*', tail @ ..]) => {
+                tokens.push(GlobToken::AnyPath);
+                tail
+            }
+            (b'*', _) => {
+                tokens.push(GlobToken::Star);
+                tail
+            }
+            (b'?', _) => {
+                tokens.push(GlobToken::AnyByte);
+                tail
+            }
+            _ => {
+                tokens.push(GlobToken::Byte(c));
+                tail
+            }
+        };
+    }
+    tokens
+}
+
+/// Match a path against a glob by tracking every pattern position the path prefix can end at, which
+/// takes `O(pattern * path)` time regardless of the number of wildcards.
+fn glob_match(pattern: &[u8], path: &[u8]) -> bool {
+    let tokens = glob_tokens(pattern);
+    // `at[i]`: the prefix matched up to token `i`, `in_dirs[i]`: the prefix ends inside the
+    // directories matched by the `**/` at token `i`
+    let mut at = vec![false; tokens.len() + 1];
+    let mut in_dirs = vec![false; tokens.len()];
+    let mut next_at = at.clone();
+    let mut next_in_dirs = in_dirs.clone();
+
+    let skip_empty = |at: &mut [bool]| {
+        for (i, token) in tokens.iter().enumerate() {
+            if at[i]
+                && matches!(
+                    token,
+                    GlobToken::Star | GlobToken::AnyPath | GlobToken::Dirs
+                )
+            {
+                at[i + 1] = true;
+            }
+        }
+    };
+
+    at[0] = true;
+    skip_empty(&mut at);
+    for &c in path {
+        next_at.fill(false);
+        next_in_dirs.fill(false);
+        for (i, &token) in tokens.iter().enumerate() {
+            if at[i] {
+                match token {
+                    GlobToken::Byte(b) => next_at[i + 1] |= b == c,
+                    GlobToken::AnyByte => next_at[i + 1] |= c != b'/',
+                    GlobToken::Star => next_at[i] |= c != b'/',
+                    GlobToken::AnyPath => next_at[i] = true,
+                    GlobToken::Dirs => {
+                        next_in_dirs[i] = true;
+                        next_at[i + 1] |= c == b'/';
+                    }
+                }
+            }
+            if in_dirs[i] {
+                next_in_dirs[i] = true;
+                next_at[i + 1] |= c == b'/';
+            }
+        }
+        skip_empty(&mut next_at);
+        std::mem::swap(&mut at, &mut next_at);
+        std::mem::swap(&mut in_dirs, &mut next_in_dirs);
+        if !at.iter().chain(in_dirs.iter()).any(|&s| s) {
+            return false;
+        }
+    }
+    at[tokens.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use futures::StreamExt;
+    use itertools::Itertools;
+    use object_store::memory::InMemory;
+    use object_store::path::Path;
+    use object_store::ObjectStore;
+    use vortex::array::{Constant, PrimitiveArray, StructArray};
+    use vortex::compute::unary::scalar_at;
+    use vortex::stream::ArrayStream;
+    use vortex::validity::Validity;
+    use vortex::variants::StructArrayTrait;
+    use vortex::{Array, ArrayDType, ArrayDef, IntoArray, IntoArrayVariant};
+    use vortex_dtype::field::{Field, FieldPath};
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_expr::{BinaryExpr, Column, Literal, Operator, VortexExpr};
+    use vortex_scalar::Scalar;
+
+    use crate::io::{ObjectStoreExt, VortexWrite};
+    use crate::layouts::read::dataset::{glob_match, glob_prefix, VortexDataset};
+    use crate::layouts::{LayoutDeserializer, LayoutWriter, Projection, RowFilter};
+
+    async fn write_file(store: &Arc<dyn ObjectStore>, path: &str, array: Array) {
+        let path = Path::from(path);
+        let writer = store.vortex_writer(&path).await.unwrap();
+        let mut writer = LayoutWriter::new(writer)
+            .write_array_columns(array)
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+        writer.shutdown().await.unwrap();
+    }
+
+    fn numbers(range: std::ops::Range<u32>, validity: Validity) -> Array {
+        let values = PrimitiveArray::new(range.collect::<Vec<_>>().into(), PType::U32, validity);
+        StructArray::from_fields(&[("numbers", values.into_array())])
+            .unwrap()
+            .into_array()
+    }
+
+    async fn read_numbers(stream: impl ArrayStream + Unpin) -> Vec<u32> {
+        stream
+            .map(|batch| {
+                let numbers = batch
+                    .unwrap()
+                    .into_struct()
+                    .unwrap()
+                    .field(0)
+                    .unwrap()
+                    .into_primitive()
+                    .unwrap();
+                numbers.maybe_null_slice::<u32>().to_vec()
+            })
+            .concat()
+            .await
+    }
+
+    async fn dataset() -> VortexDataset {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        write_file(
+            &store,
+            "table/day=2/part-0.vortex",
+            numbers(20..30, Validity::NonNullable),
+        )
+        .await;
+        write_file(
+            &store,
+            "table/day=1/part-1.vortex",
+            numbers(10..20, Validity::AllValid),
+        )
+        .await;
+        write_file(
+            &store,
+            "table/day=1/part-0.vortex",
+            numbers(0..10, Validity::NonNullable),
+        )
+        .await;
+        write_file(
+            &store,
+            "other/part-0.vortex",
+            numbers(100..110, Validity::NonNullable),
+        )
+        .await;
+
+        VortexDataset::try_new_glob(store, "table/*/*.vortex", LayoutDeserializer::default())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn read_glob_in_path_order() {
+        let dataset = dataset().await;
+        assert_eq!(
+            dataset.paths().map(|p| p.to_string()).collect::<Vec<_>>(),
+            vec![
+                "table/day=1/part-0.vortex",
+                "table/day=1/part-1.vortex",
+                "table/day=2/part-0.vortex"
+            ]
+        );
+        assert_eq!(dataset.row_count(), 30);
+
+        let stream = dataset.reader().with_batch_size(4).build().unwrap();
+        assert_eq!(
+            stream.dtype().as_struct().unwrap().dtypes()[0],
+            DType::Primitive(PType::U32, Nullability::Nullable)
+        );
+        assert_eq!(read_numbers(stream).await, (0..30).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn filter_and_indices_across_files() {
+        let dataset = dataset().await;
+        for concurrency in [1, 3] {
+            let stream = dataset
+                .reader()
+                .with_projection(Projection::Flat(vec![Field::from("numbers")]))
+                .with_indices(PrimitiveArray::from(vec![29u64, 3, 12, 15, 8, 3]).into_array())
+                .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+                    Arc::new(Column::new(Field::from("numbers"))),
+                    Operator::Gt,
+                    Arc::new(Literal::new(5u32.into())),
+                ))))
+                .with_concurrency(concurrency)
+                .build()
+                .unwrap();
+            assert!(stream.dtype().is_struct());
+            assert_eq!(read_numbers(stream).await, vec![8, 12, 15, 29]);
+        }
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn mismatched_dtypes() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        write_file(&store, "a.vortex", numbers(0..10, Validity::NonNullable)).await;
+        write_file(
+            &store,
+            "b.vortex",
+            PrimitiveArray::from(vec![1i64, 2, 3]).into_array(),
+        )
+        .await;
+
+        assert!(VortexDataset::try_new(
+            store,
+            [Path::from("a.vortex"), Path::from("b.vortex")],
+            LayoutDeserializer::default()
+        )
+        .await
+        .is_err());
+    }
+
+    async fn partitioned_store() -> Arc<dyn ObjectStore> {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        write_file(
+            &store,
+            "table/date=2024-10-01/region=eu/part-0.vortex",
+            numbers(0..5, Validity::NonNullable),
+        )
+        .await;
+        write_file(
+            &store,
+            "table/date=2024-10-01/region=us/part-0.vortex",
+            numbers(5..10, Validity::NonNullable),
+        )
+        .await;
+        write_file(
+            &store,
+            "table/date=2024-10-02/region=eu/part-0.vortex",
+            numbers(10..15, Validity::NonNullable),
+        )
+        .await;
+        store
+    }
+
+    fn eq_filter(column: &str, value: Scalar) -> Arc<dyn VortexExpr> {
+        Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from(column))),
+            Operator::Eq,
+            Arc::new(Literal::new(value)),
+        ))
+    }
+
+    fn utf8_column(batch: &Array, idx: usize) -> Vec<String> {
+        let column = batch.clone().into_struct().unwrap().field(idx).unwrap();
+        (0..column.len())
+            .map(|i| String::try_from(&scalar_at(&column, i).unwrap()).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn partition_columns_from_paths() {
+        let dataset = VortexDataset::try_new_partitioned(
+            partitioned_store().await,
+            "table/**/*.vortex",
+            None,
+            LayoutDeserializer::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(dataset.partition_keys(), ["date".into(), "region".into()]);
+        assert_eq!(
+            dataset.dtype().as_struct().unwrap().names().as_ref(),
+            ["numbers".into(), "date".into(), "region".into()]
+        );
+
+        let batches = dataset
+            .reader()
+            .with_projection(Projection::Flat(vec![
+                Field::from("region"),
+                Field::from("numbers"),
+            ]))
+            .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+                eq_filter("date", "2024-10-01".into()),
+                Operator::And,
+                Arc::new(BinaryExpr::new(
+                    Arc::new(Column::new(Field::from("numbers"))),
+                    Operator::Gt,
+                    Arc::new(Literal::new(2u32.into())),
+                )),
+            ))))
+            .build()
+            .unwrap()
+            .map(|b| b.unwrap())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(batches.len(), 2);
+        assert!(batches[0]
+            .clone()
+            .into_struct()
+            .unwrap()
+            .field(0)
+            .unwrap()
+            .is_encoding(Constant::ID));
+        assert_eq!(
+            batches
+                .iter()
+                .flat_map(|b| utf8_column(b, 0))
+                .collect::<Vec<_>>(),
+            vec!["eu", "eu", "us", "us", "us", "us", "us"]
+        );
+        let numbers = batches
+            .into_iter()
+            .flat_map(|b| {
+                b.into_struct()
+                    .unwrap()
+                    .field(1)
+                    .unwrap()
+                    .into_primitive()
+                    .unwrap()
+                    .maybe_null_slice::<u32>()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, vec![3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn nested_projection_in_reference_order() {
+        let dataset = VortexDataset::try_new_partitioned(
+            partitioned_store().await,
+            "table/**/*.vortex",
+            None,
+            LayoutDeserializer::default(),
+        )
+        .await
+        .unwrap();
+
+        let batches = dataset
+            .reader()
+            .with_projection(Projection::Nested(vec![
+                FieldPath::from_name("region"),
+                FieldPath::from_name("numbers"),
+            ]))
+            .build()
+            .unwrap()
+            .map(|b| b.unwrap())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            batches[0].dtype().as_struct().unwrap().names().as_ref(),
+            ["region".into(), "numbers".into()]
+        );
+        assert_eq!(
+            batches
+                .iter()
+                .flat_map(|b| utf8_column(b, 0))
+                .collect::<Vec<_>>(),
+            [["eu"; 5], ["us"; 5], ["eu"; 5]].concat()
+        );
+        let numbers = batches
+            .into_iter()
+            .flat_map(|b| {
+                b.into_struct()
+                    .unwrap()
+                    .field(1)
+                    .unwrap()
+                    .into_primitive()
+                    .unwrap()
+                    .maybe_null_slice::<u32>()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, (0..15).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn prune_partitions_before_reading_footers() {
+        let store = partitioned_store().await;
+        // Opening fails if the footer of this file is read
+        store
+            .put(
+                &Path::from("table/date=2024-10-03/region=us/part-0.vortex"),
+                vec![0u8; 16].into(),
+            )
+            .await
+            .unwrap();
+        assert!(VortexDataset::try_new_partitioned(
+            store.clone(),
+            "table/**/*.vortex",
+            None,
+            LayoutDeserializer::default(),
+        )
+        .await
+        .is_err());
+
+        let dataset = VortexDataset::try_new_partitioned(
+            store,
+            "table/**/*.vortex",
+            Some(&RowFilter::new(eq_filter("region", "eu".into()))),
+            LayoutDeserializer::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(dataset.row_count(), 10);
+        let stream = dataset
+            .reader()
+            .with_projection(Projection::Flat(vec![Field::from("date")]))
+            .build()
+            .unwrap();
+        assert_eq!(
+            stream
+                .map(|b| utf8_column(&b.unwrap(), 0))
+                .concat()
+                .await
+                .into_iter()
+                .dedup()
+                .collect::<Vec<_>>(),
+            vec!["2024-10-01", "2024-10-02"]
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn mixed_partition_predicates() {
+        let dataset = VortexDataset::try_new_partitioned(
+            partitioned_store().await,
+            "table/**/*.vortex",
+            None,
+            LayoutDeserializer::default(),
+        )
+        .await
+        .unwrap();
+        assert!(dataset
+            .reader()
+            .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+                Arc::new(Column::new(Field::from("numbers"))),
+                Operator::Eq,
+                Arc::new(Column::new(Field::from("region"))),
+            ))))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn glob() {
+        assert!(!glob_match(b"a/**/b.vortex", b"a/xb.vortex"));
+        assert!(glob_match(b"a/*/c.vortex", b"a/b/c.vortex"));
+        assert!(!glob_match(b"a/*.vortex", b"a/b/c.vortex"));
+        assert!(glob_match(b"a/**/*.vortex", b"a/b/c/d.vortex"));
+        assert!(glob_match(b"a/**/*.vortex", b"a/d.vortex"));
+        assert!(glob_match(b"a/?.vortex", b"a/b.vortex"));
+        assert!(!glob_match(b"a/?.vortex", b"a/bc.vortex"));
+        assert!(glob_match(b"a/**", b"a/b/c.vortex"));
+        assert!(glob_match(b"**/c.vortex", b"c.vortex"));
+        assert!(!glob_match(b"a/**/c.vortex", b"a/bc.vortex"));
+        assert_eq!(glob_prefix("a/b/c*/*.vortex"), Some("a/b"));
+        assert_eq!(glob_prefix("*.vortex"), None);
+    }
+
+    #[test]
+    fn glob_many_wildcards() {
+        let pattern = "*a".repeat(32) + "b";
+        let path = "a".repeat(64);
+        assert!(!glob_match(pattern.as_bytes(), path.as_bytes()));
+        let pattern = "**/".repeat(32) + "b";
+        let path = "a/".repeat(64);
+        assert!(!glob_match(pattern.as_bytes(), path.as_bytes()));
+        assert!(glob_match(pattern.as_bytes(), (path + "b").as_bytes()));
+    }
+}
@@ -8,9 +8,10 @@ use vortex::stats::ArrayStatistics;
 use vortex::{Array, IntoArray, IntoArrayVariant};
 use vortex_dtype::field::Field;
 use vortex_error::{VortexExpect, VortexResult};
-use vortex_expr::{split_conjunction, VortexExpr};
+use vortex_expr::{split_conjunction, BinaryExpr, Operator, VortexExpr};
 
 use crate::layouts::null_as_false;
+use crate::layouts::pruning::PruningPredicate;
 
 #[derive(Debug, Clone)]
 pub struct RowFilter {
@@ -42,8 +43,34 @@ impl RowFilter {
         null_as_false(mask.into_bool()?)
     }
 
+    /// Predicate over chunk statistics that's true for chunks that can't pass this filter
+    pub(crate) fn pruning_predicate(&self) -> PruningPredicate {
+        let expr = self
+            .conjunction
+            .iter()
+            .cloned()
+            .reduce(|lhs, rhs| Arc::new(BinaryExpr::new(lhs, Operator::And, rhs)))
+            .vortex_expect("must have at least one predicate");
+        PruningPredicate::new(&expr)
+    }
+
+    /// Split the conjunction into the predicates that `select` accepts and the remaining ones.
+    pub(crate) fn split(
+        &self,
+        select: impl Fn(&Arc<dyn VortexExpr>) -> bool,
+    ) -> (Option<Self>, Option<Self>) {
+        let (selected, rest): (Vec<_>, Vec<_>) = self
+            .conjunction
+            .iter()
+            .cloned()
+            .partition(|expr| select(expr));
+        let from_conjunction =
+            |conjunction: Vec<_>| (!conjunction.is_empty()).then_some(Self { conjunction });
+        (from_conjunction(selected), from_conjunction(rest))
+    }
+
     /// Returns a set of all referenced fields in the underlying filter
-    pub fn references(&self) -> HashSet<&Field> {
+    pub fn references(&self) -> HashSet<&[Field]> {
         let mut set = HashSet::new();
         for expr in self.conjunction.iter() {
             expr.collect_references(&mut set);
@@ -71,11 +71,7 @@ impl LayoutDescriptor {
         scan: Scan,
         message_cache: RelativeLayoutCache,
     ) -> VortexResult<Box<dyn LayoutReader>> {
-        let start_offset = self.initial_read_layout_offset();
-        let end_offset = self.initial_read.len() - FOOTER_POSTSCRIPT_SIZE - EOF_SIZE;
-        let footer_bytes = self
-            .initial_read
-            .slice(start_offset + FLATBUFFER_SIZE_LENGTH..end_offset);
+        let footer_bytes = self.footer_bytes();
         let fb_footer = root::<footer::Footer>(&footer_bytes)?;
 
         let fb_layout = fb_footer
@@ -86,6 +82,18 @@ impl LayoutDescriptor {
             .read_layout(footer_bytes, loc, scan, message_cache)
     }
 
+    /// Number of rows in the file
+    pub fn row_count(&self) -> VortexResult<u64> {
+        Ok(root::<footer::Footer>(&self.footer_bytes())?.row_count())
+    }
+
+    fn footer_bytes(&self) -> Bytes {
+        let start_offset = self.initial_read_layout_offset();
+        let end_offset = self.initial_read.len() - FOOTER_POSTSCRIPT_SIZE - EOF_SIZE;
+        self.initial_read
+            .slice(start_offset + FLATBUFFER_SIZE_LENGTH..end_offset)
+    }
+
     pub fn dtype_bytes(&self) -> VortexResult<Bytes> {
         let start_offset = self.initial_read_schema_offset();
         let end_offset = self.initial_read_layout_offset();
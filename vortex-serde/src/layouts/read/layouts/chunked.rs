@@ -1,13 +1,17 @@
 use std::collections::VecDeque;
 
 use bytes::Bytes;
-use vortex_error::{vortex_err, VortexResult};
+use vortex::aliases::hash_set::HashSet;
+use vortex::{Array, IntoArrayVariant};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 use vortex_flatbuffers::footer;
+use vortex_schema::projection::Projection;
 
 use crate::layouts::read::buffered::BufferedReader;
 use crate::layouts::read::cache::RelativeLayoutCache;
 use crate::layouts::{
-    LayoutDeserializer, LayoutId, LayoutReader, LayoutSpec, ReadResult, Scan, CHUNKED_LAYOUT_ID,
+    LayoutDeserializer, LayoutId, LayoutReader, LayoutSpec, MetadataRead, ReadResult, Scan,
+    CHUNKED_LAYOUT_ID,
 };
 
 #[derive(Debug)]
@@ -48,6 +52,8 @@ pub struct ChunkedLayout {
     layout_builder: LayoutDeserializer,
     message_cache: RelativeLayoutCache,
     reader: Option<BufferedReader>,
+    metadata_layout: Option<Box<dyn LayoutReader>>,
+    metadata: Option<Array>,
 }
 
 impl ChunkedLayout {
@@ -65,6 +71,8 @@ impl ChunkedLayout {
             layout_builder,
             message_cache,
             reader: None,
+            metadata_layout: None,
+            metadata: None,
         }
     }
 
@@ -81,6 +89,53 @@ impl ChunkedLayout {
             .map(|b| b.bytes()[0] != 0)
             .unwrap_or(false)
     }
+
+    fn metadata_layout(&self) -> VortexResult<Box<dyn LayoutReader>> {
+        let metadata_fb = self
+            .flatbuffer()
+            .children()
+            .ok_or_else(|| vortex_err!("Missing children"))?
+            .get(0);
+        // Metadata has to be read in full, it describes every chunk of this layout
+        let metadata_scan = Scan {
+            indices: None,
+            projection: Projection::All,
+            filter: None,
+            batch_size: usize::MAX,
+            pruned_ranges: None,
+        };
+        self.layout_builder.read_layout(
+            self.fb_bytes.clone(),
+            metadata_fb._tab.loc(),
+            metadata_scan,
+            self.message_cache
+                .relative(0, self.message_cache.dtype().clone()),
+        )
+    }
+
+    /// Indices of child layouts whose rows have all been pruned
+    fn pruned_chunks(&self, metadata: &Array) -> VortexResult<HashSet<usize>> {
+        let Some(pruned_ranges) = self.scan.pruned_ranges.as_ref() else {
+            return Ok(HashSet::new());
+        };
+        let row_offsets = metadata
+            .with_dyn(|a| {
+                a.as_struct_array()
+                    .and_then(|s| s.field_by_name("row_offset"))
+            })
+            .ok_or_else(|| vortex_err!("Chunk metadata is missing row offsets"))?
+            .into_primitive()?;
+
+        // Pruned ranges are aligned to chunk boundaries, a chunk is pruned if it starts in one
+        Ok(row_offsets
+            .maybe_null_slice::<u64>()
+            .iter()
+            .enumerate()
+            .filter(|(_, &offset)| pruned_ranges.contains(offset))
+            // Metadata is the first child of this layout
+            .map(|(i, _)| i + 1)
+            .collect())
+    }
 }
 
 impl LayoutReader for ChunkedLayout {
@@ -88,6 +143,21 @@ impl LayoutReader for ChunkedLayout {
         if let Some(cr) = &mut self.reader {
             cr.read()
         } else {
+            let pruned_chunks = if self.scan.pruned_ranges.is_some() {
+                match self.read_metadata()? {
+                    Some(MetadataRead::ReadMore(messages)) => {
+                        return Ok(Some(ReadResult::ReadMore(messages)))
+                    }
+                    Some(MetadataRead::Batches(mut batches)) => match batches.pop().flatten() {
+                        Some(metadata) => self.pruned_chunks(&metadata)?,
+                        None => HashSet::new(),
+                    },
+                    None => HashSet::new(),
+                }
+            } else {
+                HashSet::new()
+            };
+
             let children = self
                 .flatbuffer()
                 .children()
@@ -96,6 +166,7 @@ impl LayoutReader for ChunkedLayout {
                 .enumerate()
                 // Skip over the metadata table of this layout
                 .skip(if self.has_metadata() { 1 } else { 0 })
+                .filter(|(i, _)| !pruned_chunks.contains(i))
                 .map(|(i, c)| {
                     self.layout_builder.read_layout(
                         self.fb_bytes.clone(),
@@ -110,4 +181,33 @@ impl LayoutReader for ChunkedLayout {
             self.read_next()
         }
     }
+
+    fn read_metadata(&mut self) -> VortexResult<Option<MetadataRead>> {
+        if !self.has_metadata() {
+            return Ok(None);
+        }
+
+        if let Some(metadata) = &self.metadata {
+            return Ok(Some(MetadataRead::Batches(vec![Some(metadata.clone())])));
+        }
+
+        if self.metadata_layout.is_none() {
+            self.metadata_layout = Some(self.metadata_layout()?);
+        }
+
+        match self
+            .metadata_layout
+            .as_mut()
+            .ok_or_else(|| vortex_err!("Metadata layout was just initialized"))?
+            .read_next()?
+        {
+            Some(ReadResult::ReadMore(messages)) => Ok(Some(MetadataRead::ReadMore(messages))),
+            Some(ReadResult::Batch(metadata)) => {
+                self.metadata = Some(metadata.clone());
+                self.metadata_layout = None;
+                Ok(Some(MetadataRead::Batches(vec![Some(metadata)])))
+            }
+            None => vortex_bail!("Chunk metadata layout didn't produce any data"),
+        }
+    }
 }
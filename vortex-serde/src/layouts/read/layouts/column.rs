@@ -1,9 +1,14 @@
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
 use bytes::Bytes;
 use flatbuffers::{ForwardsUOffset, Vector};
 use itertools::Itertools;
-use vortex_dtype::DType;
+use vortex::array::StructArray;
+use vortex::validity::Validity;
+use vortex::{Array, IntoArray, IntoArrayVariant};
+use vortex_dtype::field::Field;
+use vortex_dtype::{DType, FieldNames, Nullability};
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 use vortex_flatbuffers::footer as fb;
 use vortex_schema::projection::Projection;
@@ -11,7 +16,7 @@ use vortex_schema::projection::Projection;
 use crate::layouts::read::batch::BatchReader;
 use crate::layouts::read::cache::{LazyDeserializedDType, RelativeLayoutCache};
 use crate::layouts::read::context::{LayoutDeserializer, LayoutId, LayoutSpec};
-use crate::layouts::read::{LayoutReader, ReadResult, Scan};
+use crate::layouts::read::{LayoutReader, MetadataRead, ReadResult, Scan};
 use crate::layouts::COLUMN_LAYOUT_ID;
 
 #[derive(Debug)]
@@ -51,6 +56,7 @@ pub struct ColumnLayout {
     layout_builder: LayoutDeserializer,
     message_cache: RelativeLayoutCache,
     reader: Option<BatchReader>,
+    metadata_layouts: Option<Vec<Box<dyn LayoutReader>>>,
 }
 
 impl ColumnLayout {
@@ -68,6 +74,7 @@ impl ColumnLayout {
             layout_builder,
             message_cache,
             reader: None,
+            metadata_layouts: None,
         }
     }
 
@@ -83,12 +90,13 @@ impl ColumnLayout {
         idx: usize,
         children: Vector<ForwardsUOffset<fb::Layout>>,
         dtype: DType,
+        projection: Projection,
     ) -> VortexResult<Box<dyn LayoutReader>> {
         let layout = children.get(idx);
 
-        // TODO: Figure out complex nested schema projections
+        // Children of nested columns are projected by the child layout itself
         let mut child_scan = self.scan.clone();
-        child_scan.projection = Projection::All;
+        child_scan.projection = projection;
 
         self.layout_builder.read_layout(
             self.fb_bytes.clone(),
@@ -101,11 +109,71 @@ impl ColumnLayout {
         )
     }
 
-    pub fn lazy_dtype(&self) -> VortexResult<Arc<LazyDeserializedDType>> {
-        match &self.scan.projection {
-            Projection::All => Ok(self.message_cache.dtype().clone()),
-            Projection::Flat(p) => self.message_cache.dtype().project(p),
+    /// Whether the last child is the validity of this struct
+    fn has_validity(&self) -> bool {
+        self.flatbuffer()
+            .metadata()
+            .is_some_and(|b| b.bytes().first().is_some_and(|&v| v != 0))
+    }
+
+    fn validity_layout(&self) -> VortexResult<Option<Box<dyn LayoutReader>>> {
+        if !self.has_validity() {
+            return Ok(None);
         }
+
+        let fb_children = self
+            .flatbuffer()
+            .children()
+            .ok_or_else(|| vortex_err!("Missing children"))?;
+        self.read_child(
+            fb_children.len() - 1,
+            fb_children,
+            DType::Bool(Nullability::NonNullable),
+            Projection::All,
+        )
+        .map(Some)
+    }
+
+    fn child_layouts(&self) -> VortexResult<(FieldNames, Vec<Box<dyn LayoutReader>>)> {
+        let fb_children = self
+            .flatbuffer()
+            .children()
+            .ok_or_else(|| vortex_err!("Missing children"))?;
+        let field_count = fb_children.len() - usize::from(self.has_validity());
+
+        let lazy_dtype = self.message_cache.dtype();
+        let columns = match self
+            .scan
+            .projection
+            .columns(|f| lazy_dtype.resolve_field(f))?
+        {
+            Some(columns) => columns,
+            None => (0..field_count).map(|idx| (idx, Projection::All)).collect(),
+        };
+
+        let result_lazy_dtype = match &self.scan.projection {
+            Projection::All => lazy_dtype.clone(),
+            Projection::Flat(p) => lazy_dtype.project(p)?,
+            Projection::Nested(_) => lazy_dtype.project(
+                &columns
+                    .iter()
+                    .map(|(idx, _)| Field::from(*idx))
+                    .collect::<Vec<_>>(),
+            )?,
+        };
+        let DType::Struct(s, _) = result_lazy_dtype.value()? else {
+            vortex_bail!("DType was not a struct")
+        };
+
+        let child_layouts = columns
+            .into_iter()
+            .zip_eq(s.dtypes().iter().cloned())
+            .map(|((child_idx, projection), dtype)| {
+                self.read_child(child_idx, fb_children, dtype, projection)
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        Ok((s.names().clone(), child_layouts))
     }
 }
 
@@ -114,31 +182,81 @@ impl LayoutReader for ColumnLayout {
         if let Some(br) = &mut self.reader {
             br.read()
         } else {
-            let result_lazy_dtype = self.lazy_dtype()?;
-            let DType::Struct(s, _) = result_lazy_dtype.value()? else {
-                vortex_bail!("DType was not a struct")
-            };
-
-            let fb_children = self
-                .flatbuffer()
-                .children()
-                .ok_or_else(|| vortex_err!("Missing children"))?;
-
-            let child_layouts = match &self.scan.projection {
-                Projection::All => (0..fb_children.len())
-                    .zip_eq(s.dtypes().iter())
-                    .map(|(index, dtype)| self.read_child(index, fb_children, dtype.clone()))
-                    .collect::<VortexResult<Vec<_>>>()?,
-                Projection::Flat(proj) => proj
-                    .iter()
-                    .map(|f| result_lazy_dtype.resolve_field(f))
-                    .zip(s.dtypes().iter().cloned())
-                    .map(|(child_idx, dtype)| self.read_child(child_idx?, fb_children, dtype))
-                    .collect::<VortexResult<Vec<_>>>()?,
-            };
-
-            self.reader = Some(BatchReader::new(s.names().clone(), child_layouts));
+            let (names, child_layouts) = self.child_layouts()?;
+            let reader = BatchReader::new(names, child_layouts);
+            self.reader = Some(match self.validity_layout()? {
+                Some(validity) => reader.with_validity(validity),
+                None => reader,
+            });
             self.read_next()
         }
     }
+
+    fn read_metadata(&mut self) -> VortexResult<Option<MetadataRead>> {
+        if self.metadata_layouts.is_none() {
+            self.metadata_layouts = Some(self.child_layouts()?.1);
+        }
+
+        let mut messages = Vec::new();
+        let mut metadata = Vec::new();
+        for child in self
+            .metadata_layouts
+            .as_mut()
+            .ok_or_else(|| vortex_err!("Metadata layouts were just initialized"))?
+        {
+            match child.read_metadata()? {
+                Some(MetadataRead::ReadMore(m)) => messages.extend(m),
+                // Only a single metadata array can describe a column
+                Some(MetadataRead::Batches(mut b)) if b.len() == 1 => metadata.push(b.remove(0)),
+                // Nested columns are described by the chunks shared by all of their children
+                Some(MetadataRead::Batches(b)) => metadata.push(shared_chunks(b)?),
+                None => metadata.push(None),
+            }
+        }
+
+        if messages.is_empty() {
+            Ok(Some(MetadataRead::Batches(metadata)))
+        } else {
+            Ok(Some(MetadataRead::ReadMore(messages)))
+        }
+    }
+}
+
+/// Chunk metadata with the row offsets at which every one of the children starts a new chunk.
+///
+/// Returns `None` if any of the children doesn't describe its chunks.
+fn shared_chunks(children_metadata: Vec<Option<Array>>) -> VortexResult<Option<Array>> {
+    let Some(children_metadata) = children_metadata.into_iter().collect::<Option<Vec<_>>>() else {
+        return Ok(None);
+    };
+
+    let mut row_offsets: Option<BTreeSet<u64>> = None;
+    for metadata in children_metadata {
+        let offsets = metadata
+            .with_dyn(|a| {
+                a.as_struct_array()
+                    .and_then(|s| s.field_by_name("row_offset"))
+            })
+            .ok_or_else(|| vortex_err!("Chunk metadata is missing row offsets"))?
+            .into_primitive()?;
+        let offsets = offsets.maybe_null_slice::<u64>().iter().copied();
+        row_offsets = Some(match row_offsets {
+            None => offsets.collect(),
+            Some(shared) => offsets.filter(|o| shared.contains(o)).collect(),
+        });
+    }
+
+    row_offsets
+        .map(|offsets| {
+            let offsets = offsets.into_iter().collect::<Vec<_>>();
+            let len = offsets.len();
+            StructArray::try_new(
+                ["row_offset".into()].into(),
+                vec![offsets.into_array()],
+                len,
+                Validity::NonNullable,
+            )
+            .map(IntoArray::into_array)
+        })
+        .transpose()
 }
@@ -2,10 +2,14 @@ use std::cmp::min;
 use std::sync::Arc;
 
 use bytes::Bytes;
+use vortex::array::StructArray;
 use vortex::compute::slice;
-use vortex::{Array, Context};
-use vortex_error::{vortex_err, VortexResult, VortexUnwrap};
+use vortex::variants::StructArrayTrait;
+use vortex::{Array, ArrayDType, Context, IntoArray, IntoArrayVariant};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, VortexResult, VortexUnwrap};
 use vortex_flatbuffers::footer;
+use vortex_schema::projection::Projection;
 
 use crate::layouts::read::cache::RelativeLayoutCache;
 use crate::layouts::{
@@ -87,8 +91,39 @@ impl FlatLayout {
         while let Some(u) = array_reader.read(read_buf)? {
             read_buf = buf.split_to(u);
         }
-        array_reader.into_array(self.ctx.clone(), self.cache.dtype().value()?.clone())
+        let array =
+            array_reader.into_array(self.ctx.clone(), self.cache.dtype().value()?.clone())?;
+        match self.scan.projection {
+            Projection::All => Ok(array),
+            _ => project_array(array, &self.scan.projection),
+        }
+    }
+}
+
+/// Select fields of a struct array that was written as a single message
+fn project_array(array: Array, projection: &Projection) -> VortexResult<Array> {
+    let st = array.into_struct()?;
+    let DType::Struct(sdt, _) = st.dtype() else {
+        vortex_bail!("Can't project non struct array")
+    };
+    let Some(columns) = projection.columns(|f| sdt.field_info(f).map(|info| info.index))? else {
+        return Ok(st.into_array());
+    };
+
+    let mut names = Vec::with_capacity(columns.len());
+    let mut fields = Vec::with_capacity(columns.len());
+    for (idx, child) in columns {
+        let field = st
+            .field(idx)
+            .ok_or_else(|| vortex_err!("Struct array is missing field {idx}"))?;
+        names.push(sdt.names()[idx].clone());
+        fields.push(match child {
+            Projection::All => field,
+            _ => project_array(field, &child)?,
+        });
     }
+
+    StructArray::try_new(names.into(), fields, st.len(), st.validity()).map(IntoArray::into_array)
 }
 
 impl LayoutReader for FlatLayout {
@@ -12,6 +12,7 @@ use crate::layouts::{
     INLINE_SCHEMA_LAYOUT_ID,
 };
 use crate::stream_writer::ByteRange;
+use crate::FLATBUFFER_SIZE_LENGTH;
 
 #[derive(Debug)]
 pub struct InlineDTypeLayoutSpec;
@@ -81,7 +82,7 @@ impl InlineDTypeLayout {
 
     fn dtype(&self) -> VortexResult<DTypeReadResult> {
         if let Some(dt_bytes) = self.message_cache.get(&[0]) {
-            let msg = root::<message::Message>(&dt_bytes)?
+            let msg = root::<message::Message>(&dt_bytes[FLATBUFFER_SIZE_LENGTH..])?
                 .header_as_schema()
                 .ok_or_else(|| {
                     vortex_err!("Expected schema message; this was checked earlier in the function")
@@ -6,3 +6,4 @@ mod inline_dtype;
 pub use chunked::ChunkedLayoutSpec;
 pub use column::ColumnLayoutSpec;
 pub use flat::FlatLayoutSpec;
+pub use inline_dtype::InlineDTypeLayoutSpec;
@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::sync::Arc;
 
 use arrow_buffer::BooleanBuffer;
 use vortex::array::BoolArray;
@@ -10,28 +11,38 @@ mod batch;
 mod buffered;
 mod builder;
 mod cache;
+mod coalesce;
 mod context;
+mod dataset;
 mod filtering;
 mod footer;
 mod layouts;
 mod recordbatchreader;
+mod statistics;
 mod stream;
 
 pub use builder::LayoutReaderBuilder;
 pub use cache::LayoutMessageCache;
+pub use coalesce::CoalesceConfig;
 pub use context::*;
+#[cfg(feature = "object_store")]
+pub use dataset::{DatasetReaderBuilder, VortexDataset};
 pub use filtering::RowFilter;
-pub use footer::LayoutDescriptorReader;
+pub use footer::{LayoutDescriptor, LayoutDescriptorReader};
 pub use recordbatchreader::{AsyncRuntime, VortexRecordBatchReader};
+pub use statistics::read_column_statistics;
 pub use stream::LayoutBatchStream;
 pub use vortex_schema::projection::Projection;
 pub use vortex_schema::Schema;
 
+use crate::layouts::pruning::PrunedRanges;
 use crate::stream_writer::ByteRange;
 
 // Recommended read-size according to the AWS performance guide
 pub const INITIAL_READ_SIZE: usize = 8 * 1024 * 1024;
 pub const DEFAULT_BATCH_SIZE: usize = 65536;
+/// Number of batches that are filtered in the background while the next ones are read
+pub const DEFAULT_READ_AHEAD: usize = 4;
 
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -40,6 +51,7 @@ pub struct Scan {
     projection: Projection,
     filter: Option<RowFilter>,
     batch_size: usize,
+    pruned_ranges: Option<Arc<PrunedRanges>>,
 }
 
 /// Unique identifier for a message within a layout
@@ -53,6 +65,13 @@ pub enum ReadResult {
     Batch(Array),
 }
 
+#[derive(Debug)]
+pub enum MetadataRead {
+    ReadMore(Vec<Message>),
+    /// Metadata arrays of the layout, one per child for layouts that combine multiple children
+    Batches(Vec<Option<Array>>),
+}
+
 pub trait LayoutReader: Debug + Send {
     /// Reads the data from the underlying layout
     ///
@@ -63,12 +82,12 @@ pub trait LayoutReader: Debug + Send {
     /// The layout is finished reading when it returns None
     fn read_next(&mut self) -> VortexResult<Option<ReadResult>>;
 
-    // TODO(robert): Support stats pruning via planning. Requires propagating all the metadata
-    //  to top level and then pushing down the result of it
-    // Try to use metadata of the layout to perform pruning given the passed `Scan` object.
-    //
-    // The layout should perform any planning that's cheap and doesn't require reading the data.
-    // fn plan(&mut self, scan: Scan) -> VortexResult<Option<PlanResult>>;
+    /// Reads the metadata of the underlying layout, i.e. a table with a row per chunk of the layout
+    ///
+    /// Follows the same protocol as `read_next`, layouts without metadata return None.
+    fn read_metadata(&mut self) -> VortexResult<Option<MetadataRead>> {
+        Ok(None)
+    }
 }
 
 pub fn null_as_false(array: BoolArray) -> VortexResult<Array> {
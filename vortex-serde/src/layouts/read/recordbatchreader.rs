@@ -5,12 +5,10 @@ use arrow_array::{RecordBatch, RecordBatchReader};
 use arrow_schema::{ArrowError, SchemaRef};
 use futures::StreamExt;
 use vortex::arrow::infer_schema;
+use vortex::stream::ArrayStream;
 use vortex::Array;
 use vortex_error::{VortexError, VortexResult};
 
-use super::LayoutBatchStream;
-use crate::io::VortexReadAt;
-
 fn vortex_to_arrow_error(error: VortexError) -> ArrowError {
     ArrowError::ExternalError(Box::new(error))
 }
@@ -25,22 +23,21 @@ pub trait AsyncRuntime {
     fn block_on<F: Future>(&self, fut: F) -> F::Output;
 }
 
-pub struct VortexRecordBatchReader<'a, R, AR> {
-    stream: LayoutBatchStream<R>,
+/// Blocking reader of the record batches of an [`ArrayStream`], such as a
+/// [`LayoutBatchStream`](crate::layouts::LayoutBatchStream).
+pub struct VortexRecordBatchReader<'a, S, AR> {
+    stream: S,
     arrow_schema: SchemaRef,
     runtime: &'a AR,
 }
 
-impl<'a, R, AR> VortexRecordBatchReader<'a, R, AR>
+impl<'a, S, AR> VortexRecordBatchReader<'a, S, AR>
 where
-    R: VortexReadAt + Unpin + 'static,
+    S: ArrayStream + Unpin,
     AR: AsyncRuntime,
 {
-    pub fn try_new(
-        stream: LayoutBatchStream<R>,
-        runtime: &'a AR,
-    ) -> VortexResult<VortexRecordBatchReader<'a, R, AR>> {
-        let arrow_schema = Arc::new(infer_schema(stream.schema().dtype())?);
+    pub fn try_new(stream: S, runtime: &'a AR) -> VortexResult<VortexRecordBatchReader<'a, S, AR>> {
+        let arrow_schema = Arc::new(infer_schema(stream.dtype())?);
         Ok(VortexRecordBatchReader {
             stream,
             arrow_schema,
@@ -49,9 +46,9 @@ where
     }
 }
 
-impl<R, AR> Iterator for VortexRecordBatchReader<'_, R, AR>
+impl<S, AR> Iterator for VortexRecordBatchReader<'_, S, AR>
 where
-    R: VortexReadAt + Unpin + 'static,
+    S: ArrayStream + Unpin,
     AR: AsyncRuntime,
 {
     type Item = Result<RecordBatch, ArrowError>;
@@ -62,9 +59,9 @@ where
     }
 }
 
-impl<R, AR> RecordBatchReader for VortexRecordBatchReader<'_, R, AR>
+impl<S, AR> RecordBatchReader for VortexRecordBatchReader<'_, S, AR>
 where
-    R: VortexReadAt + Unpin + 'static,
+    S: ArrayStream + Unpin,
     AR: AsyncRuntime,
 {
     fn schema(&self) -> SchemaRef {
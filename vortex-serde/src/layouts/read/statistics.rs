@@ -0,0 +1,89 @@
+use std::sync::{Arc, RwLock};
+
+use vortex::compute::unary::scalar_at;
+use vortex::stats::{Stat, StatsSet};
+use vortex::Array;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_schema::projection::Projection;
+
+use crate::io::VortexReadAt;
+use crate::layouts::read::builder::read_chunk_metadata;
+use crate::layouts::read::cache::{LayoutMessageCache, LazyDeserializedDType};
+use crate::layouts::read::coalesce::CoalesceConfig;
+use crate::layouts::read::footer::LayoutDescriptor;
+
+/// Statistics that are aggregated from the chunk metadata into file level statistics
+const FILE_STATS: [Stat; 4] = [Stat::Min, Stat::Max, Stat::NullCount, Stat::TrueCount];
+
+/// Read the statistics of every top level column of a file from its chunk metadata.
+///
+/// A statistic is only present if it's known for every chunk of the column, columns without chunk
+/// metadata have empty statistics. Files whose top level dtype isn't a struct have no columns and
+/// therefore no statistics.
+pub async fn read_column_statistics<R: VortexReadAt>(
+    reader: R,
+    footer: &LayoutDescriptor,
+) -> VortexResult<Vec<StatsSet>> {
+    let Some(column_count) = footer.dtype()?.as_struct().map(|st| st.names().len()) else {
+        return Ok(Vec::new());
+    };
+
+    let footer_dtype = Arc::new(LazyDeserializedDType::from_bytes(
+        footer.dtype_bytes()?,
+        Projection::All,
+    ));
+    let coalesce = CoalesceConfig::for_reader(&reader);
+    let (_, metadata) = read_chunk_metadata(
+        reader,
+        footer,
+        footer_dtype,
+        Arc::new(RwLock::new(LayoutMessageCache::default())),
+        Projection::All,
+        coalesce,
+    )
+    .await?;
+
+    match metadata {
+        None => Ok(vec![StatsSet::new(); column_count]),
+        Some(metadata) => metadata
+            .into_iter()
+            .map(|m| {
+                m.map(|m| chunk_metadata_stats(&m))
+                    .unwrap_or_else(|| Ok(StatsSet::new()))
+            })
+            .collect(),
+    }
+}
+
+/// Merge the statistics of every chunk described by a chunk metadata array
+fn chunk_metadata_stats(metadata: &Array) -> VortexResult<StatsSet> {
+    let columns = metadata
+        .with_dyn(|a| {
+            a.as_struct_array().map(|st| {
+                FILE_STATS
+                    .iter()
+                    .filter_map(|stat| st.field_by_name(&stat.to_string()).map(|c| (*stat, c)))
+                    .collect::<Vec<_>>()
+            })
+        })
+        .ok_or_else(|| vortex_err!("Chunk metadata must be a struct array"))?;
+
+    let mut merged: Option<StatsSet> = None;
+    for chunk in 0..metadata.len() {
+        let mut chunk_stats = StatsSet::new();
+        for (stat, column) in &columns {
+            let value = scalar_at(column, chunk)?;
+            if value.is_valid() {
+                chunk_stats.set(*stat, value);
+            }
+        }
+        match merged.as_mut() {
+            None => merged = Some(chunk_stats),
+            Some(m) => {
+                m.merge(&chunk_stats);
+            }
+        }
+    }
+
+    Ok(merged.unwrap_or_default())
+}
@@ -1,33 +1,46 @@
 use std::pin::Pin;
 use std::sync::{Arc, RwLock};
-use std::task::{ready, Context, Poll};
+use std::task::{Context, Poll};
 
 use bytes::{Bytes, BytesMut};
+use futures::channel::oneshot;
 use futures::Stream;
 use futures_util::future::BoxFuture;
-use futures_util::{stream, FutureExt, StreamExt, TryStreamExt};
-use vortex::array::ChunkedArray;
-use vortex::compute::filter;
+use futures_util::stream::FuturesOrdered;
+use futures_util::{future, stream, FutureExt, StreamExt, TryStreamExt};
+use vortex::array::{BoolArray, ChunkedArray};
+use vortex::compute::{and, filter, slice};
 use vortex::stats::ArrayStatistics;
-use vortex::Array;
+use vortex::stream::ArrayStream;
+use vortex::{Array, IntoArray, IntoArrayVariant};
 use vortex_dtype::DType;
-use vortex_error::{vortex_err, vortex_panic, VortexError, VortexExpect, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexError, VortexResult};
 use vortex_schema::Schema;
 
 use crate::io::VortexReadAt;
 use crate::layouts::read::cache::LayoutMessageCache;
-use crate::layouts::read::{LayoutReader, MessageId, ReadResult, Scan};
-use crate::stream_writer::ByteRange;
+use crate::layouts::read::coalesce::{coalesce_ranges, CoalesceConfig};
+use crate::layouts::read::{
+    null_as_false, LayoutReader, Message, MessageId, ReadResult, RowFilter, Scan,
+    DEFAULT_READ_AHEAD,
+};
 
 pub struct LayoutBatchStream<R> {
     input: Option<R>,
-    layout_reader: Box<dyn LayoutReader>,
+    // Readers are moved to the thread pool while they decode
+    layout_reader: Option<Box<dyn LayoutReader>>,
     filter_reader: Option<Box<dyn LayoutReader>>,
+    has_filter: bool,
     scan: Scan,
     messages_cache: Arc<RwLock<LayoutMessageCache>>,
     state: StreamingState<R>,
     dtype: DType,
     cached_mask: Option<Array>,
+    remaining_rows: Option<usize>,
+    coalesce: CoalesceConfig,
+    row_mask: Option<Array>,
+    read_ahead: usize,
+    in_flight: FuturesOrdered<BoxFuture<'static, VortexResult<Array>>>,
 }
 
 impl<R: VortexReadAt> LayoutBatchStream<R> {
@@ -38,25 +51,70 @@ impl<R: VortexReadAt> LayoutBatchStream<R> {
         messages_cache: Arc<RwLock<LayoutMessageCache>>,
         dtype: DType,
         scan: Scan,
+        limit: Option<usize>,
     ) -> Self {
-        let state = if filter_reader.is_some() {
+        let state = if limit == Some(0) {
+            StreamingState::Finished
+        } else if filter_reader.is_some() {
             StreamingState::FilterInit
         } else {
             StreamingState::Init
         };
 
         LayoutBatchStream {
+            coalesce: CoalesceConfig::for_reader(&input),
             input: Some(input),
-            layout_reader,
+            layout_reader: Some(layout_reader),
+            has_filter: filter_reader.is_some(),
             filter_reader,
             scan,
             messages_cache,
             dtype,
             state,
             cached_mask: None,
+            remaining_rows: limit,
+            row_mask: None,
+            read_ahead: DEFAULT_READ_AHEAD,
+            in_flight: FuturesOrdered::new(),
         }
     }
 
+    /// Merge the byte ranges of nearby messages according to the given limits.
+    pub fn with_coalesce_config(mut self, coalesce: CoalesceConfig) -> Self {
+        self.coalesce = coalesce;
+        self
+    }
+
+    /// Maximum number of batches that are filtered in the background while the next ones are read.
+    ///
+    /// Batches are still produced in order.
+    pub fn with_read_ahead(mut self, read_ahead: usize) -> Self {
+        self.read_ahead = read_ahead.max(1);
+        self
+    }
+
+    /// Only emit rows selected by the mask, it has to cover all rows that aren't pruned by the scan.
+    pub(crate) fn with_row_mask(mut self, row_mask: Option<BoolArray>) -> Self {
+        self.row_mask = row_mask.map(IntoArray::into_array);
+        self
+    }
+
+    /// Split the row mask of the next `len` rows off the remaining mask
+    fn next_row_mask(&mut self, len: usize) -> VortexResult<Option<Array>> {
+        let Some(row_mask) = self.row_mask.take() else {
+            return Ok(None);
+        };
+        if row_mask.len() < len {
+            vortex_bail!(
+                "Row mask of {} rows is shorter than the batch of {} rows",
+                row_mask.len(),
+                len
+            );
+        }
+        self.row_mask = Some(slice(&row_mask, len, row_mask.len())?);
+        slice(&row_mask, 0, len).map(Some)
+    }
+
     pub fn schema(&self) -> Schema {
         Schema::new(self.dtype.clone())
     }
@@ -72,118 +130,233 @@ impl<R: VortexReadAt> LayoutBatchStream<R> {
     }
 }
 
+/// Filter and truncate a batch on the thread pool
+fn finish_batch(
+    batch: Array,
+    mask: Option<Array>,
+    len: Option<usize>,
+) -> BoxFuture<'static, VortexResult<Array>> {
+    let (tx, rx) = oneshot::channel();
+    rayon::spawn(move || {
+        let result = mask
+            .map(|m| filter(&batch, m))
+            .unwrap_or_else(|| Ok(batch))
+            .and_then(|b| match len {
+                Some(len) if len < b.len() => slice(&b, 0, len),
+                _ => Ok(b),
+            });
+        // The stream might have been dropped in the meantime
+        let _ = tx.send(result);
+    });
+    rx.map(|result| {
+        result.unwrap_or_else(|_| Err(vortex_err!("Batch was dropped before it was finished")))
+    })
+    .boxed()
+}
+
+/// Read the next batch or messages of a layout on the thread pool, as reading a batch decodes it.
+///
+/// Batches of the filter layout are evaluated into a mask right away.
+fn read_next(mut reader: Box<dyn LayoutReader>, filter: Option<RowFilter>) -> LayoutReadFuture {
+    let (tx, rx) = oneshot::channel();
+    rayon::spawn(move || {
+        let result = reader
+            .read_next()
+            .and_then(|read| match (read, filter) {
+                (Some(ReadResult::Batch(batch)), Some(filter)) => filter
+                    .evaluate(&batch)
+                    .map(|mask| Some(ReadResult::Batch(mask))),
+                (read, _) => Ok(read),
+            })
+            .map(|read| (reader, read));
+        // The stream might have been dropped in the meantime
+        let _ = tx.send(result);
+    });
+    rx.map(|result| {
+        result.unwrap_or_else(|_| Err(vortex_err!("Layout was dropped before it was read")))
+    })
+    .boxed()
+}
+
 type StreamStateFuture<R> = BoxFuture<'static, VortexResult<(R, Vec<(MessageId, Bytes)>)>>;
 
+type LayoutReadFuture =
+    BoxFuture<'static, VortexResult<(Box<dyn LayoutReader>, Option<ReadResult>)>>;
+
 #[derive(Default)]
 enum StreamingState<R> {
     #[default]
     Init,
     FilterInit,
+    Decoding(LayoutReadFuture),
+    FilterDecoding(LayoutReadFuture),
     Reading(StreamStateFuture<R>),
     FilterReading(StreamStateFuture<R>),
-    Decoding(Array),
+    Decoded(Array),
+    Finished,
     Error,
 }
 
+impl<R: VortexReadAt + Unpin + Send + 'static> ArrayStream for LayoutBatchStream<R> {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+}
+
 impl<R: VortexReadAt + Unpin + Send + 'static> Stream for LayoutBatchStream<R> {
     type Item = VortexResult<Array>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         loop {
-            match &mut self.state {
-                StreamingState::Init => {
-                    if let Some(read) = self.layout_reader.read_next()? {
-                        match read {
-                            ReadResult::ReadMore(messages) => {
-                                let reader = self.input.take().ok_or_else(|| {
-                                    vortex_err!("Invalid state transition - reader dropped")
-                                })?;
-                                let read_future = read_ranges(reader, messages).boxed();
-                                self.state = StreamingState::Reading(read_future);
-                            }
-                            ReadResult::Batch(a) => self.state = StreamingState::Decoding(a),
-                        }
-                    } else {
-                        return Poll::Ready(None);
-                    }
+            // Yield batches in order, reading ahead until enough of them are in flight
+            if !self.in_flight.is_empty() {
+                let done = matches!(self.state, StreamingState::Finished | StreamingState::Error);
+                if done || self.in_flight.len() >= self.read_ahead {
+                    return self.in_flight.poll_next_unpin(cx);
                 }
-                StreamingState::FilterInit => {
-                    if let Some(read) = self
-                        .filter_reader
-                        .as_mut()
-                        .vortex_expect("Can't filter without reader")
-                        .read_next()?
-                    {
-                        match read {
-                            ReadResult::ReadMore(messages) => {
-                                let reader = self.input.take().ok_or_else(|| {
-                                    vortex_err!("Invalid state transition - reader dropped")
-                                })?;
-                                let read_future = read_ranges(reader, messages).boxed();
-                                self.state = StreamingState::FilterReading(read_future);
-                            }
-                            ReadResult::Batch(a) => {
-                                let mask = self
-                                    .scan
-                                    .filter
-                                    .as_ref()
-                                    .vortex_expect("Cant filter without filter")
-                                    .evaluate(&a)?;
-                                self.cached_mask = Some(mask);
-                                self.state = StreamingState::Init;
-                            }
-                        }
-                    } else {
-                        return Poll::Ready(None);
-                    }
+                if let Poll::Ready(batch) = self.in_flight.poll_next_unpin(cx) {
+                    return Poll::Ready(batch);
                 }
-                StreamingState::Decoding(arr) => {
-                    let mut batch = arr.clone();
-
-                    if let Some(mask) = self.cached_mask.take() {
-                        if mask.statistics().compute_true_count().unwrap_or_default() == 0 {
-                            self.state = StreamingState::Init;
-                            continue;
-                        }
+            }
 
-                        batch = filter(batch, mask)?;
-                    }
+            if matches!(self.state, StreamingState::Finished | StreamingState::Error) {
+                return Poll::Ready(None);
+            }
 
-                    let goto_state = if self.filter_reader.is_some() {
-                        StreamingState::FilterInit
-                    } else {
-                        StreamingState::Init
-                    };
-                    self.state = goto_state;
-                    return Poll::Ready(Some(Ok(batch)));
+            match self.poll_state(cx) {
+                Ok(Poll::Ready(())) => {}
+                Ok(Poll::Pending) => return Poll::Pending,
+                Err(e) => {
+                    // Batches that are already in flight come before the error
+                    self.state = StreamingState::Error;
+                    self.in_flight.push_back(future::ready(Err(e)).boxed());
                 }
-                StreamingState::Reading(f) => match ready!(f.poll_unpin(cx)) {
-                    Ok((input, messages)) => {
-                        self.store_messages(messages);
-                        self.input = Some(input);
+            }
+        }
+    }
+}
 
-                        self.state = StreamingState::Init
+impl<R: VortexReadAt + Unpin + Send + 'static> LayoutBatchStream<R> {
+    /// Advance the state machine by one transition
+    fn poll_state(&mut self, cx: &mut Context<'_>) -> VortexResult<Poll<()>> {
+        match &mut self.state {
+            StreamingState::Init => {
+                let reader = self
+                    .layout_reader
+                    .take()
+                    .ok_or_else(|| vortex_err!("Invalid state transition - layout dropped"))?;
+                self.state = StreamingState::Decoding(read_next(reader, None));
+            }
+            StreamingState::FilterInit => {
+                let reader = self
+                    .filter_reader
+                    .take()
+                    .ok_or_else(|| vortex_err!("Invalid state transition - filter dropped"))?;
+                let filter = self
+                    .scan
+                    .filter
+                    .clone()
+                    .ok_or_else(|| vortex_err!("Can't filter without filter"))?;
+                self.state = StreamingState::FilterDecoding(read_next(reader, Some(filter)));
+            }
+            StreamingState::Decoding(f) => {
+                let (reader, read) = match f.poll_unpin(cx) {
+                    Poll::Ready(result) => result?,
+                    Poll::Pending => return Ok(Poll::Pending),
+                };
+                self.layout_reader = Some(reader);
+                self.state = match read {
+                    Some(ReadResult::ReadMore(messages)) => {
+                        StreamingState::Reading(self.read_messages(messages)?)
+                    }
+                    Some(ReadResult::Batch(a)) => StreamingState::Decoded(a),
+                    None => StreamingState::Finished,
+                };
+            }
+            StreamingState::FilterDecoding(f) => {
+                let (reader, read) = match f.poll_unpin(cx) {
+                    Poll::Ready(result) => result?,
+                    Poll::Pending => return Ok(Poll::Pending),
+                };
+                self.filter_reader = Some(reader);
+                self.state = match read {
+                    Some(ReadResult::ReadMore(messages)) => {
+                        StreamingState::FilterReading(self.read_messages(messages)?)
                     }
-                    Err(e) => {
-                        self.state = StreamingState::Error;
-                        return Poll::Ready(Some(Err(e)));
+                    Some(ReadResult::Batch(mask)) => {
+                        self.cached_mask = Some(mask);
+                        StreamingState::Init
                     }
-                },
-                StreamingState::FilterReading(f) => match ready!(f.poll_unpin(cx)) {
-                    Ok((input, messages)) => {
-                        self.store_messages(messages);
-                        self.input = Some(input);
+                    None => StreamingState::Finished,
+                };
+            }
+            StreamingState::Decoded(arr) => {
+                let batch = arr.clone();
+                let next_state = if self.has_filter {
+                    StreamingState::FilterInit
+                } else {
+                    StreamingState::Init
+                };
 
-                        self.state = StreamingState::FilterInit
+                let mask = match (self.cached_mask.take(), self.next_row_mask(batch.len())?) {
+                    (Some(filter_mask), Some(row_mask)) => {
+                        Some(null_as_false(and(filter_mask, row_mask)?.into_bool()?)?)
                     }
-                    Err(e) => {
-                        self.state = StreamingState::Error;
-                        return Poll::Ready(Some(Err(e)));
+                    (filter_mask, row_mask) => filter_mask.or(row_mask),
+                };
+                let mut len = batch.len();
+                if let Some(mask) = mask.as_ref() {
+                    len = mask.statistics().compute_true_count().unwrap_or_default();
+                    if len == 0 {
+                        self.state = next_state;
+                        return Ok(Poll::Ready(()));
                     }
-                },
-                StreamingState::Error => return Poll::Ready(None),
+                }
+
+                // Rows past the limit are known before the batch is filtered
+                let truncate = self.remaining_rows.map(|remaining| remaining.min(len));
+                if let Some(remaining) = self.remaining_rows {
+                    self.remaining_rows = Some(remaining - remaining.min(len));
+                }
+
+                let finished = finish_batch(batch, mask, truncate);
+                self.in_flight.push_back(finished);
+                self.state = if self.remaining_rows == Some(0) {
+                    // Stop before any further layouts are fetched
+                    StreamingState::Finished
+                } else {
+                    next_state
+                };
+            }
+            StreamingState::Reading(f) => {
+                let (input, messages) = match f.poll_unpin(cx) {
+                    Poll::Ready(result) => result?,
+                    Poll::Pending => return Ok(Poll::Pending),
+                };
+                self.store_messages(messages);
+                self.input = Some(input);
+                self.state = StreamingState::Init;
+            }
+            StreamingState::FilterReading(f) => {
+                let (input, messages) = match f.poll_unpin(cx) {
+                    Poll::Ready(result) => result?,
+                    Poll::Pending => return Ok(Poll::Pending),
+                };
+                self.store_messages(messages);
+                self.input = Some(input);
+                self.state = StreamingState::FilterInit;
             }
+            StreamingState::Finished | StreamingState::Error => {}
         }
+        Ok(Poll::Ready(()))
+    }
+
+    fn read_messages(&mut self, messages: Vec<Message>) -> VortexResult<StreamStateFuture<R>> {
+        let reader = self
+            .input
+            .take()
+            .ok_or_else(|| vortex_err!("Invalid state transition - reader dropped"))?;
+        Ok(read_ranges(reader, messages, self.coalesce).boxed())
     }
 }
 
@@ -203,25 +376,26 @@ impl<R: VortexReadAt + Unpin + Send + 'static> LayoutBatchStream<R> {
     }
 }
 
-async fn read_ranges<R: VortexReadAt>(
+pub(crate) async fn read_ranges<R: VortexReadAt>(
     reader: R,
-    ranges: Vec<(MessageId, ByteRange)>,
+    ranges: Vec<Message>,
+    coalesce: CoalesceConfig,
 ) -> VortexResult<(R, Vec<(MessageId, Bytes)>)> {
-    stream::iter(ranges.into_iter())
-        .map(|(id, range)| {
-            let mut buf = BytesMut::with_capacity(range.len());
-            unsafe { buf.set_len(range.len()) }
+    stream::iter(coalesce_ranges(ranges, coalesce))
+        .map(|read| {
+            let mut buf = BytesMut::with_capacity(read.range.len());
+            unsafe { buf.set_len(read.range.len()) }
 
-            let read_ft = reader.read_at_into(range.begin, buf);
+            let read_ft = reader.read_at_into(read.range.begin, buf);
 
             read_ft.map(|result| {
                 result
-                    .map(|res| (id, res.freeze()))
+                    .map(|res| read.split(res.freeze()))
                     .map_err(VortexError::from)
             })
         })
         .buffered(10)
-        .try_collect()
+        .try_collect::<Vec<_>>()
         .await
-        .map(|b| (reader, b))
+        .map(|b| (reader, b.into_iter().flatten().collect()))
 }
@@ -1,20 +1,31 @@
 #![allow(clippy::panic)]
 
 use std::iter;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+use arrow_buffer::i256;
+use bytes::BytesMut;
 use futures::StreamExt;
 use vortex::accessor::ArrayAccessor;
-use vortex::array::{ChunkedArray, PrimitiveArray, StructArray, VarBinArray};
+use vortex::array::{
+    BoolArray, ChunkedArray, DecimalArray, ListArray, PrimitiveArray, StructArray, VarBinArray,
+};
+use vortex::compute::unary::scalar_at;
 use vortex::validity::Validity;
 use vortex::variants::StructArrayTrait;
-use vortex::{ArrayDType, IntoArray, IntoArrayVariant};
-use vortex_dtype::field::Field;
-use vortex_dtype::{DType, Nullability, PType, StructDType};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::field::{Field, FieldPath};
+use vortex_dtype::{DType, DecimalMetadata, Nullability, PType, StructDType};
 use vortex_expr::{BinaryExpr, Column, Literal, Operator};
+use vortex_scalar::Scalar;
 
+use crate::io::VortexReadAt;
 use crate::layouts::write::LayoutWriter;
-use crate::layouts::{LayoutDeserializer, LayoutReaderBuilder, Projection, RowFilter};
+use crate::layouts::{
+    read_column_statistics, CoalesceConfig, LayoutDescriptorReader, LayoutDeserializer,
+    LayoutReaderBuilder, Projection, RowFilter,
+};
 
 #[tokio::test]
 #[cfg_attr(miri, ignore)]
@@ -487,3 +498,910 @@ async fn filter_and() {
         vec![25, 31]
     );
 }
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn filter_prunes_chunks() {
+    let numbers =
+        ChunkedArray::from_iter((0..3u32).map(|i| {
+            PrimitiveArray::from((i * 10..(i + 1) * 10).collect::<Vec<_>>()).into_array()
+        }))
+        .into_array();
+    let strings = ChunkedArray::from_iter((0..3).map(|i| {
+        VarBinArray::from((0..10).map(|j| format!("{i}_{j}")).collect::<Vec<_>>()).into_array()
+    }))
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers), ("strings", strings)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let array = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("numbers"))),
+            Operator::Gte,
+            Arc::new(Literal::new(25u32.into())),
+        ))))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+
+    assert_eq!(
+        array
+            .field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u32>(),
+        (25..30).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        array
+            .field(1)
+            .unwrap()
+            .into_varbinview()
+            .unwrap()
+            .with_iterator(|iter| iter
+                .flatten()
+                .map(|s| unsafe { String::from_utf8_unchecked(s.to_vec()) })
+                .collect::<Vec<_>>())
+            .unwrap(),
+        (5..10).map(|j| format!("2_{j}")).collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn filter_on_chunk_bounds() {
+    // Chunks [0, 10), [10, 20) and [20, 30)
+    let numbers =
+        ChunkedArray::from_iter((0..3u32).map(|i| {
+            PrimitiveArray::from((i * 10..(i + 1) * 10).collect::<Vec<_>>()).into_array()
+        }))
+        .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let read = |operator: Operator, value: u32| {
+        let reads = Arc::new(AtomicUsize::new(0));
+        let reader = CountingReadAt {
+            bytes: written.clone(),
+            reads: reads.clone(),
+        };
+        let builder = LayoutReaderBuilder::new(reader, LayoutDeserializer::default())
+            .with_coalesce_config(CoalesceConfig::new(0, 0))
+            .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+                Arc::new(Column::new(Field::from("numbers"))),
+                operator,
+                Arc::new(Literal::new(Scalar::from(value))),
+            ))));
+        async move {
+            let array = builder.build().await.unwrap().read_all().await.unwrap();
+            let numbers = array
+                .with_dyn(|a| a.as_struct_array_unchecked().field(0))
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u32>()
+                .to_vec();
+            (numbers, reads.load(Ordering::Relaxed))
+        }
+    };
+
+    let (_, all_reads) = read(Operator::Gte, 0).await;
+
+    // 19 is the max of the middle chunk, only the first chunk can be skipped
+    let (gte, gte_reads) = read(Operator::Gte, 19).await;
+    assert_eq!(gte, (19..30).collect::<Vec<_>>());
+    assert!(gte_reads < all_reads, "{gte_reads} >= {all_reads}");
+
+    // 10 is the min of the middle chunk, only the last chunk can be skipped
+    let (lte, lte_reads) = read(Operator::Lte, 10).await;
+    assert_eq!(lte, (0..=10).collect::<Vec<_>>());
+    assert!(lte_reads < all_reads, "{lte_reads} >= {all_reads}");
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn read_with_limit() {
+    let numbers =
+        ChunkedArray::from_iter((0..3u32).map(|i| {
+            PrimitiveArray::from((i * 10..(i + 1) * 10).collect::<Vec<_>>()).into_array()
+        }))
+        .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let batches = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_batch_size(4)
+        .with_limit(10)
+        .build()
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(
+        batches
+            .iter()
+            .map(|b| b.as_ref().unwrap().len())
+            .collect::<Vec<_>>(),
+        vec![4, 4, 2]
+    );
+
+    let filtered = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("numbers"))),
+            Operator::Gte,
+            Arc::new(Literal::new(8u32.into())),
+        ))))
+        .with_limit(13)
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    assert_eq!(
+        filtered
+            .field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u32>(),
+        (8..21).collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn write_read_list_column() {
+    let lists = ListArray::try_new(
+        PrimitiveArray::from(vec![1i32, 2, 3, 4, 5, 6]).into_array(),
+        PrimitiveArray::from(vec![0u32, 2, 2, 5, 6]).into_array(),
+        Validity::from(vec![true, false, true, true]),
+    )
+    .unwrap()
+    .into_array();
+    let numbers = PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array();
+
+    let st = StructArray::from_fields(&[("lists", lists), ("numbers", numbers)]).unwrap();
+    let buf = Vec::new();
+    let mut writer = LayoutWriter::new(buf);
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let mut stream = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_projection(Projection::new([0]))
+        .build()
+        .await
+        .unwrap();
+    let mut batches = vec![];
+    while let Some(array) = stream.next().await {
+        batches.push(array.unwrap());
+    }
+    assert_eq!(batches.len(), 1);
+
+    let lists = batches[0]
+        .with_dyn(|a| a.as_struct_array_unchecked().field(0))
+        .unwrap()
+        .into_list()
+        .unwrap();
+    let values = (0..lists.len())
+        .map(|i| {
+            let scalar = scalar_at(lists.as_ref(), i).unwrap();
+            scalar
+                .is_valid()
+                .then(|| Vec::<i32>::try_from(&scalar).unwrap())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        vec![Some(vec![1, 2]), None, Some(vec![3, 4, 5]), Some(vec![6])]
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn filter_decimal_column() {
+    let metadata = DecimalMetadata::try_new(30, 2).unwrap();
+    let prices = DecimalArray::from_i128(
+        [150i128, 99, -1_000_000_000_000_000_000_000, 101],
+        Validity::from(vec![true, true, true, false]),
+        metadata,
+    )
+    .unwrap();
+    let numbers = PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array();
+
+    let st =
+        StructArray::from_fields(&[("price", Array::from(prices)), ("numbers", numbers)]).unwrap();
+    let buf = Vec::new();
+    let mut writer = LayoutWriter::new(buf);
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let mut stream = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("price"))),
+            Operator::Gt,
+            Arc::new(Literal::new(
+                Scalar::decimal(i256::from_i128(100), metadata, Nullability::Nullable).unwrap(),
+            )),
+        ))))
+        .build()
+        .await
+        .unwrap();
+    let mut batches = vec![];
+    while let Some(array) = stream.next().await {
+        batches.push(array.unwrap());
+    }
+    assert_eq!(batches.len(), 1);
+
+    let prices = DecimalArray::try_from(
+        batches[0]
+            .with_dyn(|a| a.as_struct_array_unchecked().field(0))
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(prices.unscaled_i256().unwrap(), vec![i256::from_i128(150)]);
+    assert_eq!(prices.decimal_metadata(), metadata);
+}
+
+/// Counts the number of reads issued against the wrapped bytes
+struct CountingReadAt {
+    bytes: Vec<u8>,
+    reads: Arc<AtomicUsize>,
+}
+
+impl VortexReadAt for CountingReadAt {
+    async fn read_at_into(&self, pos: u64, buffer: BytesMut) -> std::io::Result<BytesMut> {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        self.bytes.read_at_into(pos, buffer).await
+    }
+
+    async fn size(&self) -> u64 {
+        self.bytes.len() as u64
+    }
+}
+
+/// Fails every read once the budget of successful reads is used up
+struct FailingReadAt {
+    bytes: Vec<u8>,
+    budget: Arc<AtomicUsize>,
+}
+
+impl VortexReadAt for FailingReadAt {
+    async fn read_at_into(&self, pos: u64, buffer: BytesMut) -> std::io::Result<BytesMut> {
+        if self
+            .budget
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |b| b.checked_sub(1))
+            .is_err()
+        {
+            return Err(std::io::Error::other("read budget exhausted"));
+        }
+        self.bytes.read_at_into(pos, buffer).await
+    }
+
+    async fn size(&self) -> u64 {
+        self.bytes.len() as u64
+    }
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn read_error_follows_earlier_batches() {
+    let numbers =
+        ChunkedArray::from_iter((0..10u32).map(|c| {
+            PrimitiveArray::from((c * 10..(c + 1) * 10).collect::<Vec<_>>()).into_array()
+        }))
+        .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let budget = Arc::new(AtomicUsize::new(usize::MAX));
+    let stream = |budget: Arc<AtomicUsize>| {
+        LayoutReaderBuilder::new(
+            FailingReadAt {
+                bytes: written.clone(),
+                budget,
+            },
+            LayoutDeserializer::default(),
+        )
+        .with_coalesce_config(CoalesceConfig::new(0, 0))
+        .with_batch_size(10)
+        .with_read_ahead(8)
+        .build()
+    };
+    let full = stream(budget.clone()).await.unwrap();
+    let before = budget.load(Ordering::Relaxed);
+    assert!(full.collect::<Vec<_>>().await.iter().all(|b| b.is_ok()));
+    let data_reads = before - budget.load(Ordering::Relaxed);
+
+    // Fail the last read of the data
+    let failing = stream(budget.clone()).await.unwrap();
+    budget.store(data_reads - 1, Ordering::Relaxed);
+    let results = failing.collect::<Vec<_>>().await;
+
+    // Every batch read before the failure is produced, then the error, then the stream ends
+    let (error, batches) = results.split_last().unwrap();
+    assert!(error.is_err());
+    assert!(!batches.is_empty());
+    let numbers = batches
+        .iter()
+        .flat_map(|b| {
+            b.as_ref()
+                .unwrap()
+                .clone()
+                .into_struct()
+                .unwrap()
+                .field(0)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u32>()
+                .to_vec()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(numbers, (0..numbers.len() as u32).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn coalesced_reads() {
+    let columns = (0..10)
+        .map(|i| {
+            (
+                format!("col{i}"),
+                ChunkedArray::from_iter([
+                    PrimitiveArray::from(vec![i; 4]).into_array(),
+                    PrimitiveArray::from(vec![i + 1; 4]).into_array(),
+                ])
+                .into_array(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let st = StructArray::from_fields(
+        &columns
+            .iter()
+            .map(|(name, array)| (name.as_str(), array.clone()))
+            .collect::<Vec<_>>(),
+    )
+    .unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut results = Vec::new();
+    for coalesce in [
+        CoalesceConfig::new(0, 0),
+        CoalesceConfig::new(1024, 1024 * 1024),
+    ] {
+        let reads = Arc::new(AtomicUsize::new(0));
+        let reader = CountingReadAt {
+            bytes: written.clone(),
+            reads: reads.clone(),
+        };
+        let array = LayoutReaderBuilder::new(reader, LayoutDeserializer::default())
+            .with_coalesce_config(coalesce)
+            .build()
+            .await
+            .unwrap()
+            .read_all()
+            .await
+            .unwrap()
+            .into_struct()
+            .unwrap();
+        let values = array
+            .children()
+            .map(|c| {
+                c.into_primitive()
+                    .unwrap()
+                    .maybe_null_slice::<i32>()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        results.push((reads.load(Ordering::Relaxed), values));
+    }
+
+    let (uncoalesced_reads, uncoalesced) = &results[0];
+    let (coalesced_reads, coalesced) = &results[1];
+    assert_eq!(coalesced, uncoalesced);
+    assert_eq!(coalesced[3], vec![3, 3, 3, 3, 4, 4, 4, 4]);
+    assert!(
+        coalesced_reads < uncoalesced_reads,
+        "{coalesced_reads} >= {uncoalesced_reads}"
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn read_with_row_mask() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from((0u32..4).collect::<Vec<_>>()).into_array(),
+        PrimitiveArray::from((4u32..8).collect::<Vec<_>>()).into_array(),
+        PrimitiveArray::from((8u32..12).collect::<Vec<_>>()).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+    let mask = || {
+        BoolArray::from((0..12).map(|i| [1, 9, 10].contains(&i)).collect::<Vec<_>>()).into_array()
+    };
+
+    let read = |mask: Option<Array>, filter: Option<RowFilter>| {
+        let reads = Arc::new(AtomicUsize::new(0));
+        let reader = CountingReadAt {
+            bytes: written.clone(),
+            reads: reads.clone(),
+        };
+        let mut builder = LayoutReaderBuilder::new(reader, LayoutDeserializer::default())
+            .with_coalesce_config(CoalesceConfig::new(0, 0))
+            .with_batch_size(3);
+        if let Some(mask) = mask {
+            builder = builder.with_row_mask(mask);
+        }
+        if let Some(filter) = filter {
+            builder = builder.with_row_filter(filter);
+        }
+        async move {
+            let array = builder.build().await.unwrap().read_all().await.unwrap();
+            let numbers = array
+                .with_dyn(|a| a.as_struct_array_unchecked().field(0))
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u32>()
+                .to_vec();
+            (numbers, reads.load(Ordering::Relaxed))
+        }
+    };
+
+    let (all, _) = read(None, None).await;
+    assert_eq!(all, (0u32..12).collect::<Vec<_>>());
+
+    let (selected, all_selected_reads) =
+        read(Some(BoolArray::from(vec![true; 12]).into_array()), None).await;
+    assert_eq!(selected, all);
+
+    let (masked, masked_reads) = read(Some(mask()), None).await;
+    assert_eq!(masked, vec![1, 9, 10]);
+    // The middle chunk has no selected rows and is never fetched
+    assert_eq!(masked_reads, all_selected_reads - 1);
+
+    let (filtered, _) = read(
+        Some(mask()),
+        Some(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("numbers"))),
+            Operator::Gte,
+            Arc::new(Literal::new(Scalar::from(10u32))),
+        )))),
+    )
+    .await;
+    assert_eq!(filtered, vec![10]);
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn read_nested_projection() {
+    let nested = |offset: i32| {
+        StructArray::from_fields(&[
+            (
+                "x",
+                PrimitiveArray::from((offset..offset + 4).collect::<Vec<_>>()).into_array(),
+            ),
+            (
+                "y",
+                VarBinArray::from(vec!["a", "b", "c", "d"]).into_array(),
+            ),
+        ])
+        .unwrap()
+        .into_array()
+    };
+    let nested_dtype = nested(0).dtype().clone();
+    let st = StructArray::from_fields(&[
+        (
+            "numbers",
+            PrimitiveArray::from((0u32..8).collect::<Vec<_>>()).into_array(),
+        ),
+        (
+            "nested",
+            ChunkedArray::try_new(vec![nested(0), nested(4)], nested_dtype)
+                .unwrap()
+                .into_array(),
+        ),
+    ])
+    .unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut results = Vec::new();
+    for projection in [
+        Projection::Nested(vec![
+            FieldPath::from(vec![Field::from("nested"), Field::from("x")]),
+            FieldPath::from(Field::from(0)),
+        ]),
+        Projection::Nested(vec![FieldPath::from(Field::from("nested"))]),
+    ] {
+        let reads = Arc::new(AtomicUsize::new(0));
+        let reader = CountingReadAt {
+            bytes: written.clone(),
+            reads: reads.clone(),
+        };
+        let array = LayoutReaderBuilder::new(reader, LayoutDeserializer::default())
+            .with_projection(projection)
+            .with_coalesce_config(CoalesceConfig::new(0, 0))
+            .build()
+            .await
+            .unwrap()
+            .read_all()
+            .await
+            .unwrap();
+        results.push((reads.load(Ordering::Relaxed), array));
+    }
+
+    let (nested_reads, projected) = &results[0];
+    let (full_reads, full) = &results[1];
+    let projected = projected.clone().into_struct().unwrap();
+    assert_eq!(
+        projected.names().as_ref(),
+        &["nested".into(), "numbers".into()]
+    );
+    let nested = projected.field(0).unwrap().into_struct().unwrap();
+    assert_eq!(nested.names().as_ref(), &["x".into()]);
+    assert_eq!(
+        nested
+            .field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<i32>(),
+        (0..8).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        projected
+            .field(1)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u32>(),
+        (0..8).collect::<Vec<_>>()
+    );
+
+    let full = full.clone().into_struct().unwrap();
+    let nested = full.field(0).unwrap().into_struct().unwrap();
+    assert_eq!(nested.names().as_ref(), &["x".into(), "y".into()]);
+    assert_eq!(
+        nested
+            .field(1)
+            .unwrap()
+            .into_varbinview()
+            .unwrap()
+            .with_iterator(|iter| iter
+                .map(|s| s.map(|s| String::from_utf8(s.to_vec()).unwrap()))
+                .collect::<Vec<_>>())
+            .unwrap(),
+        ["a", "b", "c", "d", "a", "b", "c", "d"]
+            .map(|s| Some(s.to_string()))
+            .to_vec()
+    );
+    // Messages of the unselected nested field are never fetched
+    assert!(nested_reads < full_reads, "{nested_reads} >= {full_reads}");
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn read_nested_projection_of_nullable_struct() {
+    let nested = StructArray::try_new(
+        ["x".into(), "y".into()].into(),
+        vec![
+            PrimitiveArray::from(vec![1i64, 2, 3]).into_array(),
+            PrimitiveArray::from(vec![4i64, 5, 6]).into_array(),
+        ],
+        3,
+        Validity::from(vec![true, false, true]),
+    )
+    .unwrap();
+    let st = StructArray::from_fields(&[("nested", nested.into_array())]).unwrap();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let array = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_projection(Projection::Nested(vec![FieldPath::from(vec![
+            Field::from("nested"),
+            Field::from("y"),
+        ])]))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+
+    let nested = array.field(0).unwrap();
+    assert_eq!(
+        nested.dtype(),
+        &DType::Struct(
+            StructDType::new(
+                ["y".into()].into(),
+                vec![DType::Primitive(PType::I64, Nullability::NonNullable)]
+            ),
+            Nullability::Nullable
+        )
+    );
+    assert!(!scalar_at(&nested, 1).unwrap().is_valid());
+    assert_eq!(
+        nested
+            .into_struct()
+            .unwrap()
+            .field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<i64>(),
+        &[4, 5, 6]
+    );
+
+    // The value left in the null row doesn't match the filter
+    let filtered = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new_nested(FieldPath::from(vec![
+                Field::from("nested"),
+                Field::from("y"),
+            ]))),
+            Operator::Gte,
+            Arc::new(Literal::new(Scalar::from(5i64))),
+        ))))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    assert_eq!(
+        filtered
+            .field(0)
+            .unwrap()
+            .into_struct()
+            .unwrap()
+            .field(1)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<i64>(),
+        &[6]
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn write_read_nullable_top_level_struct() {
+    let chunk = |validity: Vec<bool>| {
+        StructArray::try_new(
+            ["numbers".into(), "strings".into()].into(),
+            vec![
+                PrimitiveArray::from(vec![1u32, 2, 3]).into_array(),
+                VarBinArray::from(vec!["a", "b", "c"]).into_array(),
+            ],
+            3,
+            Validity::from(validity),
+        )
+        .unwrap()
+        .into_array()
+    };
+    let first = chunk(vec![true, false, true]);
+    let dtype = first.dtype().clone();
+    let array = ChunkedArray::try_new(vec![first, chunk(vec![false, true, true])], dtype.clone())
+        .unwrap()
+        .into_array();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(array)
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let read = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap();
+    assert_eq!(read.dtype(), &dtype);
+    assert_eq!(
+        (0..read.len())
+            .map(|i| scalar_at(&read, i).unwrap().is_valid())
+            .collect::<Vec<_>>(),
+        vec![true, false, true, false, true, true]
+    );
+
+    let projected = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_projection(Projection::new([1]))
+        .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("numbers"))),
+            Operator::Gt,
+            Arc::new(Literal::new(Scalar::from(1u32))),
+        ))))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap();
+    assert_eq!(projected.dtype().nullability(), Nullability::Nullable);
+    assert_eq!(
+        (0..projected.len())
+            .map(|i| scalar_at(&projected, i).unwrap().is_valid())
+            .collect::<Vec<_>>(),
+        vec![false, true, true, true]
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn write_read_non_struct_array() {
+    let array = ChunkedArray::from_iter([
+        PrimitiveArray::from((0i64..5).collect::<Vec<_>>()).into_array(),
+        PrimitiveArray::from((5i64..10).collect::<Vec<_>>()).into_array(),
+    ])
+    .into_array();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(array)
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let read = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(read.maybe_null_slice::<i64>(), (0..10).collect::<Vec<_>>());
+
+    let masked = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_row_mask(
+            BoolArray::from((0..10).map(|i| i % 4 == 3).collect::<Vec<_>>()).into_array(),
+        )
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(masked.maybe_null_slice::<i64>(), &[3, 7]);
+
+    let footer = LayoutDescriptorReader::new(LayoutDeserializer::default())
+        .read_footer(&written, written.len() as u64)
+        .await
+        .unwrap();
+    assert!(read_column_statistics(written.clone(), &footer)
+        .await
+        .unwrap()
+        .is_empty());
+
+    assert!(
+        LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+            .with_projection(Projection::new([0]))
+            .build()
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn read_ahead_preserves_order() {
+    let columns = (0..50)
+        .map(|i| {
+            (
+                format!("col{i}"),
+                ChunkedArray::from_iter((0..10).map(|c| {
+                    PrimitiveArray::from(
+                        (c * 100..(c + 1) * 100).map(|v| v * i).collect::<Vec<_>>(),
+                    )
+                    .into_array()
+                }))
+                .into_array(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let st = StructArray::from_fields(
+        &columns
+            .iter()
+            .map(|(name, array)| (name.as_str(), array.clone()))
+            .collect::<Vec<_>>(),
+    )
+    .unwrap();
+    let dtype = st.dtype().clone();
+    let written = LayoutWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    for read_ahead in [1, 8] {
+        let batches = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+            .with_batch_size(100)
+            .with_read_ahead(read_ahead)
+            .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+                Arc::new(Column::new(Field::from("col1"))),
+                Operator::Gte,
+                Arc::new(Literal::new(Scalar::from(250i32))),
+            ))))
+            .with_limit(600)
+            .build()
+            .await
+            .unwrap()
+            .collect::<Vec<_>>()
+            .await;
+        let array = ChunkedArray::try_new(
+            batches.into_iter().map(|b| b.unwrap()).collect(),
+            dtype.clone(),
+        )
+        .unwrap()
+        .into_struct()
+        .unwrap();
+        for (i, column) in array.children().enumerate() {
+            assert_eq!(
+                column.into_primitive().unwrap().maybe_null_slice::<i32>(),
+                (250..850).map(|v| v * i as i32).collect::<Vec<_>>(),
+                "column {i} with read ahead of {read_ahead}"
+            );
+        }
+    }
+}
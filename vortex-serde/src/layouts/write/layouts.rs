@@ -37,12 +37,15 @@ impl Layout {
         }
     }
 
-    pub fn column(children: Vec<Layout>) -> Self {
+    /// Create a column layout with a child for every field of a struct.
+    ///
+    /// has_validity indicates whether the last child is a layout containing the validity of the struct.
+    pub fn column(children: Vec<Layout>, has_validity: bool) -> Self {
         Self {
             id: COLUMN_LAYOUT_ID,
             buffers: None,
             children: Some(children),
-            metadata: None,
+            metadata: Some(Bytes::copy_from_slice(&[has_validity as u8])),
         }
     }
 
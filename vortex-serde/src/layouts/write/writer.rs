@@ -3,14 +3,17 @@ use std::{io, mem};
 
 use flatbuffers::FlatBufferBuilder;
 use futures::{Stream, TryStreamExt};
-use vortex::array::{ChunkedArray, StructArray};
+use vortex::array::{ChunkedArray, ConstantArray, StructArray};
+use vortex::stats::{ArrayStatistics, Stat, StatsSet};
 use vortex::stream::ArrayStream;
-use vortex::validity::Validity;
-use vortex::{Array, ArrayDType, IntoArray};
+use vortex::validity::{ArrayValidity, Validity};
+use vortex::variants::StructArrayTrait;
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
 use vortex_buffer::io_buf::IoBuf;
-use vortex_dtype::DType;
+use vortex_dtype::{DType, Nullability, PType};
 use vortex_error::{vortex_bail, vortex_err, VortexExpect, VortexResult};
 use vortex_flatbuffers::WriteFlatBuffer;
+use vortex_scalar::Scalar;
 
 use crate::io::VortexWrite;
 use crate::layouts::write::footer::{Footer, Postscript};
@@ -19,6 +22,12 @@ use crate::layouts::{EOF_SIZE, MAGIC_BYTES, VERSION};
 use crate::stream_writer::ByteRange;
 use crate::MessageWriter;
 
+/// DType of the columns that hold the validity of nullable structs
+const VALIDITY_DTYPE: DType = DType::Bool(Nullability::NonNullable);
+
+/// Statistics persisted in the chunk metadata of every column, if the column's dtype supports them
+const PRUNING_STATS: [Stat; 4] = [Stat::Min, Stat::Max, Stat::NullCount, Stat::TrueCount];
+
 pub struct LayoutWriter<W> {
     msgs: MessageWriter<W>,
 
@@ -65,9 +74,10 @@ impl<W: VortexWrite> LayoutWriter<W> {
         }
 
         while let Some(columns) = array_stream.try_next().await? {
-            let st = StructArray::try_from(&columns)?;
-            self.row_count += st.len() as u64;
-            for (i, field) in st.children().enumerate() {
+            self.row_count += columns.len() as u64;
+            let mut leaves = Vec::new();
+            leaf_columns(columns, &mut leaves)?;
+            for (i, field) in leaves.into_iter().enumerate() {
                 if let Ok(chunked_array) = ChunkedArray::try_from(field.clone()) {
                     self.write_column_chunks(chunked_array.array_stream(), i)
                         .await?
@@ -87,6 +97,7 @@ impl<W: VortexWrite> LayoutWriter<W> {
     {
         let mut row_offsets: Vec<u64> = Vec::new();
         let mut byte_offsets = vec![self.msgs.tell()];
+        let mut chunk_stats = Vec::new();
 
         let mut n_rows_written = match self.column_chunks.get(column_idx) {
             None => {
@@ -102,6 +113,7 @@ impl<W: VortexWrite> LayoutWriter<W> {
         while let Some(chunk) = stream.try_next().await? {
             n_rows_written += chunk.len() as u64;
             row_offsets.push(n_rows_written);
+            chunk_stats.push(compute_pruning_stats(&chunk));
             self.msgs.write_batch(chunk).await?;
             byte_offsets.push(self.msgs.tell());
         }
@@ -109,9 +121,13 @@ impl<W: VortexWrite> LayoutWriter<W> {
         if let Some(batches) = self.column_chunks.get_mut(column_idx) {
             batches.row_offsets.extend(row_offsets);
             batches.batch_byte_offsets.push(byte_offsets);
+            batches.chunk_stats.extend(chunk_stats);
         } else {
-            self.column_chunks
-                .push(BatchOffsets::new(row_offsets, vec![byte_offsets]));
+            self.column_chunks.push(BatchOffsets::new(
+                row_offsets,
+                vec![byte_offsets],
+                chunk_stats,
+            ));
         }
 
         Ok(())
@@ -119,7 +135,14 @@ impl<W: VortexWrite> LayoutWriter<W> {
 
     async fn write_metadata_arrays(&mut self) -> VortexResult<Layout> {
         let mut column_layouts = Vec::with_capacity(self.column_chunks.len());
-        for mut chunk in mem::take(&mut self.column_chunks) {
+        let mut column_dtypes = Vec::new();
+        if let Some(dtype) = self.dtype.as_ref() {
+            leaf_dtypes(dtype, &mut column_dtypes);
+        }
+        for (mut chunk, dtype) in mem::take(&mut self.column_chunks)
+            .into_iter()
+            .zip(column_dtypes)
+        {
             let mut chunks: VecDeque<Layout> = chunk
                 .batch_byte_offsets
                 .iter()
@@ -135,12 +158,17 @@ impl<W: VortexWrite> LayoutWriter<W> {
 
             assert_eq!(chunks.len(), chunk.row_offsets.len());
 
-            let metadata_array = StructArray::try_new(
-                ["row_offset".into()].into(),
-                vec![chunk.row_offsets.into_array()],
-                len,
-                Validity::NonNullable,
-            )?;
+            let mut names = vec!["row_offset".into()];
+            let mut fields = vec![chunk.row_offsets.into_array()];
+            for stat in PRUNING_STATS {
+                if let Some(stat_array) = stat_array(&chunk.chunk_stats, stat, &dtype)? {
+                    names.push(stat.to_string().into());
+                    fields.push(stat_array);
+                }
+            }
+
+            let metadata_array =
+                StructArray::try_new(names.into(), fields, len, Validity::NonNullable)?;
 
             let dtype_begin = self.msgs.tell();
             self.msgs.write_dtype(metadata_array.dtype()).await?;
@@ -153,7 +181,11 @@ impl<W: VortexWrite> LayoutWriter<W> {
             column_layouts.push(Layout::chunked(chunks.into(), true));
         }
 
-        Ok(Layout::column(column_layouts))
+        let dtype = self
+            .dtype
+            .as_ref()
+            .ok_or_else(|| vortex_err!("Schema should be written by now"))?;
+        nest_layouts(dtype, &mut column_layouts.into_iter())
     }
 
     async fn write_footer(&mut self, footer: Footer) -> VortexResult<Postscript> {
@@ -200,17 +232,158 @@ async fn write_fb_raw<W: VortexWrite, F: WriteFlatBuffer>(mut writer: W, fb: F)
     Ok(writer)
 }
 
+/// Whether the fields of a struct column are written as separate columns, such that they can be
+/// projected without reading their siblings.
+///
+/// The validity of nullable structs is written as an additional boolean column.
+fn is_split_struct(dtype: &DType) -> bool {
+    matches!(dtype, DType::Struct(sdt, _) if !sdt.names().is_empty())
+}
+
+/// Split a column into the arrays that are written as separate columns, in depth first order.
+fn leaf_columns(column: Array, leaves: &mut Vec<Array>) -> VortexResult<()> {
+    match column.dtype() {
+        DType::Struct(sdt, n) if is_split_struct(column.dtype()) => {
+            for (idx, dtype) in sdt.dtypes().iter().enumerate() {
+                leaf_columns(struct_field(&column, idx, dtype)?, leaves)?;
+            }
+            if *n == Nullability::Nullable {
+                leaves.push(struct_validity(&column)?);
+            }
+        }
+        _ => leaves.push(column),
+    }
+    Ok(())
+}
+
+/// Get a field of a struct column, keeping the chunks of the column.
+fn struct_field(column: &Array, idx: usize, dtype: &DType) -> VortexResult<Array> {
+    if let Ok(chunked) = ChunkedArray::try_from(column) {
+        let chunks = chunked
+            .chunks()
+            .map(|chunk| struct_field(&chunk, idx, dtype))
+            .collect::<VortexResult<Vec<_>>>()?;
+        return ChunkedArray::try_new(chunks, dtype.clone()).map(IntoArray::into_array);
+    }
+
+    column
+        .clone()
+        .into_struct()?
+        .field(idx)
+        .ok_or_else(|| vortex_err!("Struct array is missing field {idx}"))
+}
+
+/// Get the validity of a struct column as a boolean array, keeping the chunks of the column.
+fn struct_validity(column: &Array) -> VortexResult<Array> {
+    if let Ok(chunked) = ChunkedArray::try_from(column) {
+        let chunks = chunked
+            .chunks()
+            .map(|chunk| struct_validity(&chunk))
+            .collect::<VortexResult<Vec<_>>>()?;
+        return ChunkedArray::try_new(chunks, VALIDITY_DTYPE).map(IntoArray::into_array);
+    }
+
+    Ok(column
+        .clone()
+        .into_struct()?
+        .logical_validity()
+        .into_array())
+}
+
+/// DTypes of the columns written by [`leaf_columns`].
+fn leaf_dtypes(dtype: &DType, leaves: &mut Vec<DType>) {
+    match dtype {
+        DType::Struct(sdt, n) if is_split_struct(dtype) => {
+            for child in sdt.dtypes().iter() {
+                leaf_dtypes(child, leaves);
+            }
+            if *n == Nullability::Nullable {
+                leaves.push(VALIDITY_DTYPE);
+            }
+        }
+        _ => leaves.push(dtype.clone()),
+    }
+}
+
+/// Rebuild the layout of a column from the layouts of the columns written by [`leaf_columns`].
+fn nest_layouts(dtype: &DType, leaves: &mut impl Iterator<Item = Layout>) -> VortexResult<Layout> {
+    match dtype {
+        DType::Struct(sdt, n) if is_split_struct(dtype) => {
+            let mut children = sdt
+                .dtypes()
+                .iter()
+                .map(|child| nest_layouts(child, leaves))
+                .collect::<VortexResult<Vec<_>>>()?;
+            let has_validity = *n == Nullability::Nullable;
+            if has_validity {
+                children.push(nest_layouts(&VALIDITY_DTYPE, leaves)?);
+            }
+            Ok(Layout::column(children, has_validity))
+        }
+        // Columns are only missing if no arrays were written at all
+        _ => Ok(leaves
+            .next()
+            .unwrap_or_else(|| Layout::chunked(Vec::new(), false))),
+    }
+}
+
+fn compute_pruning_stats(chunk: &Array) -> StatsSet {
+    let mut stats = StatsSet::new();
+    for stat in PRUNING_STATS {
+        if let Some(value) = chunk.statistics().compute(stat) {
+            stats.set(stat, value);
+        }
+    }
+    stats
+}
+
+/// Collect a single statistic of all chunks of a column into an array, returns `None` if the
+/// statistic doesn't apply to the column's dtype.
+fn stat_array(chunk_stats: &[StatsSet], stat: Stat, dtype: &DType) -> VortexResult<Option<Array>> {
+    let stat_dtype = match stat {
+        Stat::Min | Stat::Max => match dtype {
+            DType::Bool(_) | DType::Primitive(..) | DType::Utf8(_) | DType::Binary(_) => {
+                dtype.as_nullable()
+            }
+            _ => return Ok(None),
+        },
+        Stat::TrueCount if !matches!(dtype, DType::Bool(_)) => return Ok(None),
+        _ => DType::Primitive(PType::U64, Nullability::Nullable),
+    };
+
+    let chunks = chunk_stats
+        .iter()
+        .map(|s| {
+            s.get(stat)
+                .filter(|v| v.is_valid())
+                .map(|v| v.cast(&stat_dtype))
+                .unwrap_or_else(|| Ok(Scalar::null(stat_dtype.clone())))
+                .map(|v| ConstantArray::new(v, 1).into_array())
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
+
+    ChunkedArray::try_new(chunks, stat_dtype)?
+        .into_canonical()
+        .map(|c| Some(Array::from(c)))
+}
+
 #[derive(Clone, Debug)]
 pub struct BatchOffsets {
     pub row_offsets: Vec<u64>,
     pub batch_byte_offsets: Vec<Vec<u64>>,
+    pub chunk_stats: Vec<StatsSet>,
 }
 
 impl BatchOffsets {
-    pub fn new(row_offsets: Vec<u64>, batch_byte_offsets: Vec<Vec<u64>>) -> Self {
+    pub fn new(
+        row_offsets: Vec<u64>,
+        batch_byte_offsets: Vec<Vec<u64>>,
+        chunk_stats: Vec<StatsSet>,
+    ) -> Self {
         Self {
             row_offsets,
             batch_byte_offsets,
+            chunk_stats,
         }
     }
 }
@@ -1,7 +1,9 @@
+pub use compression::BufferCompression;
 use message_reader::*;
 use message_writer::*;
 
 pub mod chunked_reader;
+mod compression;
 mod dtype_reader;
 pub mod io;
 pub mod layouts;
@@ -11,6 +11,7 @@ use vortex_dtype::DType;
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 use vortex_flatbuffers::message as fb;
 
+use crate::compression::decompress;
 use crate::io::VortexRead;
 
 pub const FLATBUFFER_SIZE_LENGTH: usize = 4;
@@ -293,9 +294,13 @@ impl ArrayBufferReader {
                         // Strip off any padding from the previous buffer
                         bytes.advance(buffer.padding() as usize);
 
-                        Buffer::from(data_buffer)
+                        decompress(
+                            Buffer::from(data_buffer),
+                            buffer.compression(),
+                            buffer.uncompressed_length(),
+                        )
                     })
-                    .collect::<Vec<_>>();
+                    .collect::<VortexResult<Vec<_>>>()?;
 
                 self.buffers = buffers;
                 self.state = ReadState::Finished;
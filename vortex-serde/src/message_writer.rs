@@ -1,13 +1,13 @@
 use std::io;
 
 use flatbuffers::FlatBufferBuilder;
-use itertools::Itertools;
 use vortex::Array;
 use vortex_buffer::io_buf::IoBuf;
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
 use vortex_flatbuffers::WriteFlatBuffer;
 
+use crate::compression::{BufferCompression, CompressedBuffer};
 use crate::io::VortexWrite;
 use crate::messages::{IPCBatch, IPCMessage, IPCPage, IPCSchema};
 use crate::ALIGNMENT;
@@ -19,6 +19,7 @@ pub struct MessageWriter<W> {
     write: W,
     pos: u64,
     alignment: usize,
+    compression: BufferCompression,
 
     scratch: Option<Vec<u8>>,
 }
@@ -30,10 +31,17 @@ impl<W: VortexWrite> MessageWriter<W> {
             write,
             pos: 0,
             alignment: ALIGNMENT,
+            compression: BufferCompression::default(),
             scratch: Some(Vec::new()),
         }
     }
 
+    /// Compress the buffers of every subsequently written batch with the given codec.
+    pub fn with_compression(mut self, compression: BufferCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
     pub fn into_inner(self) -> W {
         self.write
     }
@@ -49,24 +57,23 @@ impl<W: VortexWrite> MessageWriter<W> {
     }
 
     pub async fn write_batch(&mut self, chunk: Array) -> io::Result<()> {
-        let buffer_offsets = chunk.all_buffer_offsets(self.alignment);
+        let buffers = chunk
+            .depth_first_traversal()
+            .flat_map(|data| data.into_buffer().into_iter())
+            .map(|buffer| self.compression.compress(buffer))
+            .collect::<io::Result<Vec<_>>>()?;
 
         // Serialize the Chunk message.
-        self.write_message(IPCMessage::Batch(IPCBatch(&chunk)))
+        self.write_message(IPCMessage::Batch(IPCBatch(&chunk, &buffers)))
             .await?;
 
-        // Keep track of the offset to add padding after each buffer.
-        let mut current_offset = 0;
-        for (buffer, &buffer_end) in chunk
-            .depth_first_traversal()
-            .flat_map(|data| data.into_buffer().into_iter())
-            .zip_eq(buffer_offsets.iter().skip(1))
-        {
+        // Pad each buffer to the alignment, matching the offsets recorded in the message.
+        for CompressedBuffer { buffer, .. } in buffers {
             let buffer_len = buffer.len();
             self.write_all(buffer).await?;
-            let padding = (buffer_end as usize) - current_offset - buffer_len;
-            self.write_all(&ZEROS[0..padding]).await?;
-            current_offset = buffer_end as usize;
+            let aligned_size = (buffer_len + (self.alignment - 1)) & !(self.alignment - 1);
+            self.write_all(ZEROS.slice_owned(0..aligned_size - buffer_len))
+                .await?;
         }
 
         Ok(())
@@ -80,7 +87,7 @@ impl<W: VortexWrite> MessageWriter<W> {
 
         let aligned_size = (buffer_len + (self.alignment - 1)) & !(self.alignment - 1);
         let padding = aligned_size - buffer_len;
-        self.write_all(&ZEROS[0..padding]).await?;
+        self.write_all(ZEROS.slice_owned(0..padding)).await?;
 
         Ok(())
     }
@@ -115,7 +122,7 @@ impl<W: VortexWrite> MessageWriter<W> {
             .write_all(buffer.slice_owned(buffer_begin..buffer_end))
             .await?
             .into_inner();
-        self.write_all(&ZEROS[0..padding_bytes]).await?;
+        self.write_all(ZEROS.slice_owned(0..padding_bytes)).await?;
 
         assert_eq!(self.pos % self.alignment as u64, 0);
 
@@ -5,9 +5,9 @@ use vortex::{flatbuffers as fba, Array};
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
 use vortex_error::VortexExpect as _;
-use vortex_flatbuffers::message::Compression;
 use vortex_flatbuffers::{message as fb, FlatBufferRoot, WriteFlatBuffer};
 
+use crate::compression::CompressedBuffer;
 use crate::ALIGNMENT;
 
 pub enum IPCMessage<'a> {
@@ -17,7 +17,8 @@ pub enum IPCMessage<'a> {
 }
 
 pub struct IPCSchema<'a>(pub &'a DType);
-pub struct IPCBatch<'a>(pub &'a Array);
+/// A batch message for an array, along with its (possibly compressed) buffers in depth-first order.
+pub struct IPCBatch<'a>(pub &'a Array, pub &'a [CompressedBuffer]);
 pub struct IPCArray<'a>(pub &'a Array, usize);
 pub struct IPCPage<'a>(pub &'a Buffer);
 
@@ -72,20 +73,20 @@ impl WriteFlatBuffer for IPCBatch<'_> {
 
         let length = array_data.len() as u64;
 
-        // Walk the ColumnData depth-first to compute the buffer offsets.
+        // Compute the buffer offsets from the buffers as they are written to the stream.
         let mut buffers = vec![];
         let mut offset = 0;
 
-        for array_data in array_data.depth_first_traversal() {
-            if let Some(buffer) = array_data.buffer() {
-                let aligned_size = (buffer.len() + (ALIGNMENT - 1)) & !(ALIGNMENT - 1);
-                buffers.push(fb::Buffer::new(
-                    offset as u64,
-                    (aligned_size - buffer.len()) as u16,
-                    Compression::None,
-                ));
-                offset += aligned_size;
-            }
+        for compressed in self.1 {
+            let buffer_len = compressed.buffer.len();
+            let aligned_size = (buffer_len + (ALIGNMENT - 1)) & !(ALIGNMENT - 1);
+            buffers.push(fb::Buffer::new(
+                offset as u64,
+                (aligned_size - buffer_len) as u16,
+                compressed.compression,
+                compressed.uncompressed_length,
+            ));
+            offset += aligned_size;
         }
         let buffers = Some(fbb.create_vector(&buffers));
 
@@ -9,7 +9,7 @@ use vortex_dtype::DType;
 use vortex_error::VortexResult;
 
 use crate::io::VortexWrite;
-use crate::MessageWriter;
+use crate::{BufferCompression, MessageWriter};
 
 #[cfg(test)]
 mod tests;
@@ -30,6 +30,12 @@ impl<W: VortexWrite> StreamArrayWriter<W> {
         }
     }
 
+    /// Compress the buffers of every written array chunk with the given codec.
+    pub fn with_compression(mut self, compression: BufferCompression) -> Self {
+        self.msgs = self.msgs.with_compression(compression);
+        self
+    }
+
     pub fn array_layouts(&self) -> &[ArrayLayout] {
         &self.array_layouts
     }
@@ -97,7 +103,7 @@ impl<W: VortexWrite> StreamArrayWriter<W> {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct ByteRange {
     pub begin: u64,
     pub end: u64,
@@ -3,13 +3,14 @@ use std::sync::Arc;
 
 use arrow_array::cast::AsArray as _;
 use arrow_array::types::Int32Type;
-use arrow_array::PrimitiveArray;
+use arrow_array::{ListArray, PrimitiveArray};
 use vortex::arrow::FromArrowArray;
 use vortex::stream::ArrayStreamExt;
 use vortex::{Array, Context, IntoCanonical};
 
 use crate::stream_reader::StreamArrayReader;
 use crate::stream_writer::StreamArrayWriter;
+use crate::BufferCompression;
 
 #[tokio::test]
 async fn broken_data() {
@@ -34,3 +35,69 @@ async fn broken_data() {
     let round_tripped = arr.into_canonical().unwrap().into_arrow().unwrap();
     assert_eq!(&arrow_arr, round_tripped.as_primitive::<Int32Type>());
 }
+
+#[tokio::test]
+async fn list_roundtrip() {
+    let arrow_arr = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(3), None, Some(5)]),
+    ]);
+    let vortex_arr = Array::from_arrow(&arrow_arr, true);
+    let written = StreamArrayWriter::new(Vec::new())
+        .write_array(vortex_arr)
+        .await
+        .unwrap()
+        .into_inner();
+    let reader = StreamArrayReader::try_new(Cursor::new(written), Arc::new(Context::default()))
+        .await
+        .unwrap();
+    let arr = reader
+        .load_dtype()
+        .await
+        .unwrap()
+        .into_array_stream()
+        .collect_chunked()
+        .await
+        .unwrap();
+    let round_tripped = arr.into_canonical().unwrap().into_arrow().unwrap();
+    assert_eq!(&arrow_arr, round_tripped.as_list::<i32>());
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn compressed_roundtrip() {
+    let arrow_arr: PrimitiveArray<Int32Type> = (0..10_000)
+        .map(|i| (i % 3 != 0).then_some(i % 17))
+        .collect();
+    for compression in [BufferCompression::Lz4, BufferCompression::Zstd(0)] {
+        let vortex_arr = Array::from_arrow(&arrow_arr, true);
+        let uncompressed = StreamArrayWriter::new(Vec::new())
+            .write_array(vortex_arr.clone())
+            .await
+            .unwrap()
+            .into_inner();
+        let written = StreamArrayWriter::new(Vec::new())
+            .with_compression(compression)
+            .write_array(vortex_arr)
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(written.len() < uncompressed.len());
+
+        let reader = StreamArrayReader::try_new(Cursor::new(written), Arc::new(Context::default()))
+            .await
+            .unwrap();
+        let arr = reader
+            .load_dtype()
+            .await
+            .unwrap()
+            .into_array_stream()
+            .collect_chunked()
+            .await
+            .unwrap();
+        let round_tripped = arr.into_canonical().unwrap().into_arrow().unwrap();
+        assert_eq!(&arrow_arr, round_tripped.as_primitive::<Int32Type>());
+    }
+}